@@ -1,4 +1,4 @@
-use atoi_simd::{parse, parse_prefix};
+use atoi_simd::{parse, parse_bin, parse_hex, parse_oct, parse_prefix, parse_radix};
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
 };
@@ -505,6 +505,65 @@ fn benchmark_group_max_20(
     func(bench_group, &str);
 }
 
+fn bench_radix_64(bench_group: &mut BenchmarkGroup<WallTime>, val: u64) {
+    let hex = format!("{:x}", val);
+    let oct = format!("{:o}", val);
+    let bin = format!("{:b}", val);
+    let base36 = {
+        let mut v = val;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(std::char::from_digit((v % 36) as u32, 36).unwrap());
+            v /= 36;
+            if v == 0 {
+                break;
+            }
+        }
+        digits.into_iter().rev().collect::<String>()
+    };
+
+    bench_group.bench_with_input(
+        BenchmarkId::new("parse_hex u64", hex.len()),
+        &hex,
+        |b, val| b.iter(|| parse_hex::<u64, false, false>(val.as_bytes()).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("str hex u64", hex.len()),
+        &hex,
+        |b, val| b.iter(|| u64::from_str_radix(val, 16).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("parse_oct u64", oct.len()),
+        &oct,
+        |b, val| b.iter(|| parse_oct::<u64, false, false>(val.as_bytes()).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("str oct u64", oct.len()),
+        &oct,
+        |b, val| b.iter(|| u64::from_str_radix(val, 8).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("parse_bin u64", bin.len()),
+        &bin,
+        |b, val| b.iter(|| parse_bin::<u64, false, false>(val.as_bytes()).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("str bin u64", bin.len()),
+        &bin,
+        |b, val| b.iter(|| u64::from_str_radix(val, 2).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("parse_radix 36 u64", base36.len()),
+        &base36,
+        |b, val| b.iter(|| parse_radix::<u64, 36, false, false>(val.as_bytes()).unwrap()),
+    );
+    bench_group.bench_with_input(
+        BenchmarkId::new("str radix 36 u64", base36.len()),
+        &base36,
+        |b, val| b.iter(|| u64::from_str_radix(val, 36).unwrap()),
+    );
+}
+
 fn benchmark(c: &mut Criterion) {
     {
         let mut bench_group = c.benchmark_group("benchmark 32");
@@ -565,6 +624,12 @@ fn benchmark(c: &mut Criterion) {
     bench_prefix_128(&mut bench_group, &str);
 
     bench_group.finish();
+
+    let mut bench_group = c.benchmark_group("benchmark radix");
+    for val in [0xFF, 0xFFFF, 0xFFFF_FFFF, 0xFFFF_FFFF_FFFF, u64::MAX] {
+        bench_radix_64(&mut bench_group, val);
+    }
+    bench_group.finish();
 }
 
 criterion_group!(benches, benchmark);
@@ -0,0 +1,498 @@
+#ifndef ATOI_SIMD_H
+#define ATOI_SIMD_H
+
+/* Generated by cbindgen from src/lib.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The number is parsed
+ */
+#define ATOI_SIMD_OK 0
+
+/**
+ * The input is empty
+ */
+#define ATOI_SIMD_EMPTY 1
+
+/**
+ * The number is too long
+ */
+#define ATOI_SIMD_SIZE 2
+
+/**
+ * The value doesn't fit in the integer, `consumed` is the index of the digit out of range
+ */
+#define ATOI_SIMD_OVERFLOW 3
+
+/**
+ * The input contains an invalid char, `consumed` is its index
+ */
+#define ATOI_SIMD_INVALID 4
+
+/**
+ * The value is out of the range of the bounded parsing
+ */
+#define ATOI_SIMD_OUT_OF_RANGE 5
+
+/**
+ * The value is zero, for the non-zero integers
+ */
+#define ATOI_SIMD_ZERO 6
+
+/**
+ * The value has a fractional part
+ */
+#define ATOI_SIMD_NOT_INTEGRAL 7
+
+/**
+ * The number is not in the canonical form
+ */
+#define ATOI_SIMD_NON_CANONICAL 8
+
+/**
+ * The input or the value pointer is null
+ */
+#define ATOI_SIMD_NULL_POINTER 9
+
+/**
+ * An error of a newer `atoi_simd` without its own code yet, `consumed` is its offset (or 0)
+ */
+#define ATOI_SIMD_UNKNOWN 10
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_u8(const uint8_t *s, size_t len, uint8_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_u8(const uint8_t *s, size_t len, uint8_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_u8(const char *s, uint8_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_u8(const char *s, uint8_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_u16(const uint8_t *s, size_t len, uint16_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_u16(const uint8_t *s, size_t len, uint16_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_u16(const char *s, uint16_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_u16(const char *s, uint16_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_u32(const uint8_t *s, size_t len, uint32_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_u32(const uint8_t *s, size_t len, uint32_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_u32(const char *s, uint32_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_u32(const char *s, uint32_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_u64(const uint8_t *s, size_t len, uint64_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_u64(const uint8_t *s, size_t len, uint64_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_u64(const char *s, uint64_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_u64(const char *s, uint64_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_usize(const uint8_t *s, size_t len, size_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_usize(const uint8_t *s, size_t len, size_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_usize(const char *s, size_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_usize(const char *s, size_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_i8(const uint8_t *s, size_t len, int8_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_i8(const uint8_t *s, size_t len, int8_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_i8(const char *s, int8_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_i8(const char *s, int8_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_i16(const uint8_t *s, size_t len, int16_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_i16(const uint8_t *s, size_t len, int16_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_i16(const char *s, int16_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_i16(const char *s, int16_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_i32(const uint8_t *s, size_t len, int32_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_i32(const uint8_t *s, size_t len, int32_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_i32(const char *s, int32_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_i32(const char *s, int32_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_i64(const uint8_t *s, size_t len, int64_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_i64(const uint8_t *s, size_t len, int64_t *value, size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_i64(const char *s, int64_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_i64(const char *s, int64_t *value, size_t *consumed);
+
+/**
+ * Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_isize(const uint8_t *s, size_t len, ptrdiff_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_isize(const uint8_t *s,
+                                     size_t len,
+                                     ptrdiff_t *value,
+                                     size_t *consumed);
+
+/**
+ * Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_cstr_isize(const char *s, ptrdiff_t *value, size_t *consumed);
+
+/**
+ * Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+ * with the '-' sign for the signed integers.
+ *
+ * # Safety
+ *
+ * `s` must be a valid NUL-terminated string, `value` must be valid,
+ * `consumed` must be null or valid.
+ */
+int32_t atoi_simd_parse_prefix_cstr_isize(const char *s, ptrdiff_t *value, size_t *consumed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* ATOI_SIMD_H */
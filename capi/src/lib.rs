@@ -0,0 +1,203 @@
+//! C ABI for `atoi_simd`, the header is `include/atoi_simd.h`.
+//!
+//! Every function returns a status code (`ATOI_SIMD_OK` or one of the errors),
+//! writes the value on success, and the consumed length to `consumed` (if it's not null):
+//! the length of the number on success, the index of the invalid char for `ATOI_SIMD_INVALID`,
+//! the index of the digit out of range for `ATOI_SIMD_OVERFLOW`,
+//! the size of the number for `ATOI_SIMD_SIZE`, and 0 for the other errors.
+//!
+//! The functions cover the 8 to 64-bit integers, `size_t` and `ptrdiff_t`:
+//! `u128`/`i128` have no portable C type, so they are not exported.
+
+use atoi_simd::AtoiSimdError;
+use std::{ffi::CStr, os::raw::c_char, slice};
+
+/// The number is parsed
+pub const ATOI_SIMD_OK: i32 = 0;
+/// The input is empty
+pub const ATOI_SIMD_EMPTY: i32 = 1;
+/// The number is too long
+pub const ATOI_SIMD_SIZE: i32 = 2;
+/// The value doesn't fit in the integer, `consumed` is the index of the digit out of range
+pub const ATOI_SIMD_OVERFLOW: i32 = 3;
+/// The input contains an invalid char, `consumed` is its index
+pub const ATOI_SIMD_INVALID: i32 = 4;
+/// The value is out of the range of the bounded parsing
+pub const ATOI_SIMD_OUT_OF_RANGE: i32 = 5;
+/// The value is zero, for the non-zero integers
+pub const ATOI_SIMD_ZERO: i32 = 6;
+/// The value has a fractional part
+pub const ATOI_SIMD_NOT_INTEGRAL: i32 = 7;
+/// The number is not in the canonical form
+pub const ATOI_SIMD_NON_CANONICAL: i32 = 8;
+/// The input or the value pointer is null
+pub const ATOI_SIMD_NULL_POINTER: i32 = 9;
+/// An error of a newer `atoi_simd` without its own code yet, `consumed` is its offset (or 0)
+pub const ATOI_SIMD_UNKNOWN: i32 = 10;
+
+/// Status code and the consumed length of the error
+fn error_code(e: &AtoiSimdError<'_>) -> (i32, usize) {
+    match *e {
+        AtoiSimdError::Empty => (ATOI_SIMD_EMPTY, 0),
+        AtoiSimdError::Size(len, _) => (ATOI_SIMD_SIZE, len),
+        AtoiSimdError::Overflow(_, index, _) => (ATOI_SIMD_OVERFLOW, index),
+        AtoiSimdError::OutOfRange(_) => (ATOI_SIMD_OUT_OF_RANGE, 0),
+        AtoiSimdError::Zero(_) => (ATOI_SIMD_ZERO, 0),
+        AtoiSimdError::NotIntegral(_) => (ATOI_SIMD_NOT_INTEGRAL, 0),
+        AtoiSimdError::NonCanonical(_, _) => (ATOI_SIMD_NON_CANONICAL, 0),
+        AtoiSimdError::Invalid64(_, index, _) | AtoiSimdError::Invalid128(_, index, _) => {
+            (ATOI_SIMD_INVALID, index)
+        }
+        // `AtoiSimdError` is `non_exhaustive`, the errors added later have no codes yet
+        _ => (ATOI_SIMD_UNKNOWN, e.offset().unwrap_or(0)),
+    }
+}
+
+/// # Safety
+///
+/// `s` must be valid for `len` bytes, it can be null only if `len` is 0
+unsafe fn bytes<'a>(s: *const u8, len: usize) -> Option<&'a [u8]> {
+    match (s.is_null(), len) {
+        (_, 0) => Some(&[]),
+        (true, _) => None,
+        (false, len) => Some(slice::from_raw_parts(s, len)),
+    }
+}
+
+/// # Safety
+///
+/// `s` must be null or a valid NUL-terminated string
+unsafe fn c_bytes<'a>(s: *const c_char) -> Option<&'a [u8]> {
+    if s.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(s).to_bytes())
+}
+
+/// Writes the result, returns the status code
+///
+/// # Safety
+///
+/// `value` must be valid, `consumed` must be null or valid
+unsafe fn finish<T>(
+    res: Result<(T, usize), AtoiSimdError<'_>>,
+    value: *mut T,
+    consumed: *mut usize,
+) -> i32 {
+    let (status, len) = match res {
+        Ok((v, len)) => {
+            *value = v;
+            (ATOI_SIMD_OK, len)
+        }
+        Err(e) => error_code(&e),
+    };
+    if !consumed.is_null() {
+        *consumed = len;
+    }
+    status
+}
+
+macro_rules! capi_impl {
+    ($($t:ty, $parse:ident, $parse_prefix:ident, $parse_cstr:ident, $parse_prefix_cstr:ident;)*) => {$(
+        /// Parses the `len` bytes at `s`, with the '-' sign for the signed integers.
+        ///
+        /// # Safety
+        ///
+        /// `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+        /// `consumed` must be null or valid.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse(
+            s: *const u8,
+            len: usize,
+            value: *mut $t,
+            consumed: *mut usize,
+        ) -> i32 {
+            match bytes(s, len) {
+                Some(s) if !value.is_null() => finish(
+                    atoi_simd::parse::<$t, false, false>(s).map(|v| (v, s.len())),
+                    value,
+                    consumed,
+                ),
+                _ => ATOI_SIMD_NULL_POINTER,
+            }
+        }
+
+        /// Parses the number at the beginning of the `len` bytes at `s` until an invalid char,
+        /// with the '-' sign for the signed integers.
+        ///
+        /// # Safety
+        ///
+        /// `s` must be valid for `len` bytes (or null if `len` is 0), `value` must be valid,
+        /// `consumed` must be null or valid.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_prefix(
+            s: *const u8,
+            len: usize,
+            value: *mut $t,
+            consumed: *mut usize,
+        ) -> i32 {
+            match bytes(s, len) {
+                Some(s) if !value.is_null() => {
+                    finish(atoi_simd::parse_prefix::<$t, false, false>(s), value, consumed)
+                }
+                _ => ATOI_SIMD_NULL_POINTER,
+            }
+        }
+
+        /// Parses the NUL-terminated string `s`, with the '-' sign for the signed integers.
+        ///
+        /// # Safety
+        ///
+        /// `s` must be a valid NUL-terminated string, `value` must be valid,
+        /// `consumed` must be null or valid.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_cstr(
+            s: *const c_char,
+            value: *mut $t,
+            consumed: *mut usize,
+        ) -> i32 {
+            match c_bytes(s) {
+                Some(s) if !value.is_null() => finish(
+                    atoi_simd::parse::<$t, false, false>(s).map(|v| (v, s.len())),
+                    value,
+                    consumed,
+                ),
+                _ => ATOI_SIMD_NULL_POINTER,
+            }
+        }
+
+        /// Parses the number at the beginning of the NUL-terminated string `s` until an invalid char,
+        /// with the '-' sign for the signed integers.
+        ///
+        /// # Safety
+        ///
+        /// `s` must be a valid NUL-terminated string, `value` must be valid,
+        /// `consumed` must be null or valid.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_prefix_cstr(
+            s: *const c_char,
+            value: *mut $t,
+            consumed: *mut usize,
+        ) -> i32 {
+            match c_bytes(s) {
+                Some(s) if !value.is_null() => {
+                    finish(atoi_simd::parse_prefix::<$t, false, false>(s), value, consumed)
+                }
+                _ => ATOI_SIMD_NULL_POINTER,
+            }
+        }
+    )*};
+}
+
+capi_impl!(
+    u8, atoi_simd_parse_u8, atoi_simd_parse_prefix_u8, atoi_simd_parse_cstr_u8, atoi_simd_parse_prefix_cstr_u8;
+    u16, atoi_simd_parse_u16, atoi_simd_parse_prefix_u16, atoi_simd_parse_cstr_u16, atoi_simd_parse_prefix_cstr_u16;
+    u32, atoi_simd_parse_u32, atoi_simd_parse_prefix_u32, atoi_simd_parse_cstr_u32, atoi_simd_parse_prefix_cstr_u32;
+    u64, atoi_simd_parse_u64, atoi_simd_parse_prefix_u64, atoi_simd_parse_cstr_u64, atoi_simd_parse_prefix_cstr_u64;
+    usize, atoi_simd_parse_usize, atoi_simd_parse_prefix_usize, atoi_simd_parse_cstr_usize, atoi_simd_parse_prefix_cstr_usize;
+    i8, atoi_simd_parse_i8, atoi_simd_parse_prefix_i8, atoi_simd_parse_cstr_i8, atoi_simd_parse_prefix_cstr_i8;
+    i16, atoi_simd_parse_i16, atoi_simd_parse_prefix_i16, atoi_simd_parse_cstr_i16, atoi_simd_parse_prefix_cstr_i16;
+    i32, atoi_simd_parse_i32, atoi_simd_parse_prefix_i32, atoi_simd_parse_cstr_i32, atoi_simd_parse_prefix_cstr_i32;
+    i64, atoi_simd_parse_i64, atoi_simd_parse_prefix_i64, atoi_simd_parse_cstr_i64, atoi_simd_parse_prefix_cstr_i64;
+    isize, atoi_simd_parse_isize, atoi_simd_parse_prefix_isize, atoi_simd_parse_cstr_isize, atoi_simd_parse_prefix_cstr_isize;
+);
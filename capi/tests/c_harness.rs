@@ -0,0 +1,56 @@
+//! Builds the static library and `tests/harness.c` with the C compiler (`CC` or `cc`) against it, and runs it
+
+#![cfg(unix)]
+
+use std::{env, path::Path, process::Command};
+
+#[test]
+fn test_c_harness() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let tmp = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    // `cargo test` doesn't build the staticlib, and the target dir of the test is locked by it
+    let target_dir = tmp.join("capi");
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let mut build = Command::new(env!("CARGO"));
+    build
+        .args(["build", "--lib", "--manifest-path"])
+        .arg(manifest.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir);
+    if profile == "release" {
+        build.arg("--release");
+    }
+    assert!(
+        build.status().unwrap().success(),
+        "static library build failed"
+    );
+    let lib = target_dir.join(profile).join("libatoi_simd_capi.a");
+    assert!(lib.exists(), "no static library: {}", lib.display());
+    let harness = tmp.join("harness");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".into());
+    let status = Command::new(cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg(manifest.join("tests/harness.c"))
+        .arg(&lib)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&harness)
+        .status()
+        .unwrap();
+    assert!(status.success(), "C harness build failed");
+
+    let output = Command::new(&harness).output().unwrap();
+    assert!(
+        output.status.success(),
+        "C harness failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,137 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "atoi_simd.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+    do {                                                               \
+        if (!(cond)) {                                                 \
+            fprintf(stderr, "%s:%d: failed: %s\n", __FILE__, __LINE__, \
+                    #cond);                                            \
+            failures++;                                                \
+        }                                                              \
+    } while (0)
+
+static void test_parse(void) {
+    int64_t i64 = 0;
+    size_t consumed = 0;
+    const char *s = "-9223372036854775808";
+    CHECK(atoi_simd_parse_i64((const uint8_t *)s, strlen(s), &i64, &consumed) == ATOI_SIMD_OK);
+    CHECK(i64 == INT64_MIN);
+    CHECK(consumed == strlen(s));
+
+    uint64_t u64 = 0;
+    s = "18446744073709551615";
+    CHECK(atoi_simd_parse_u64((const uint8_t *)s, strlen(s), &u64, &consumed) == ATOI_SIMD_OK);
+    CHECK(u64 == UINT64_MAX);
+
+    uint8_t u8 = 0;
+    CHECK(atoi_simd_parse_u8((const uint8_t *)"255", 3, &u8, NULL) == ATOI_SIMD_OK);
+    CHECK(u8 == 255);
+    int8_t i8 = 0;
+    CHECK(atoi_simd_parse_i8((const uint8_t *)"-128", 4, &i8, NULL) == ATOI_SIMD_OK);
+    CHECK(i8 == -128);
+    uint16_t u16 = 0;
+    CHECK(atoi_simd_parse_u16((const uint8_t *)"65535", 5, &u16, NULL) == ATOI_SIMD_OK);
+    CHECK(u16 == 65535);
+    int16_t i16 = 0;
+    CHECK(atoi_simd_parse_i16((const uint8_t *)"-32768", 6, &i16, NULL) == ATOI_SIMD_OK);
+    CHECK(i16 == -32768);
+    uint32_t u32 = 0;
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"4294967295", 10, &u32, NULL) == ATOI_SIMD_OK);
+    CHECK(u32 == UINT32_MAX);
+    int32_t i32 = 0;
+    CHECK(atoi_simd_parse_i32((const uint8_t *)"-2147483648", 11, &i32, NULL) == ATOI_SIMD_OK);
+    CHECK(i32 == INT32_MIN);
+
+    size_t size = 0;
+    char buf[32];
+    snprintf(buf, sizeof(buf), "%zu", SIZE_MAX);
+    CHECK(atoi_simd_parse_cstr_usize(buf, &size, &consumed) == ATOI_SIMD_OK);
+    CHECK(size == SIZE_MAX);
+    CHECK(consumed == strlen(buf));
+    ptrdiff_t diff = 0;
+    snprintf(buf, sizeof(buf), "%td", PTRDIFF_MIN);
+    CHECK(atoi_simd_parse_cstr_isize(buf, &diff, NULL) == ATOI_SIMD_OK);
+    CHECK(diff == PTRDIFF_MIN);
+    CHECK(atoi_simd_parse_prefix_isize((const uint8_t *)"-42,", 4, &diff, &consumed) == ATOI_SIMD_OK);
+    CHECK(diff == -42);
+    CHECK(consumed == 3);
+}
+
+static void test_errors(void) {
+    uint32_t u32 = 7;
+    size_t consumed = 100;
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"", 0, &u32, &consumed) == ATOI_SIMD_EMPTY);
+    CHECK(consumed == 0);
+    CHECK(atoi_simd_parse_u32(NULL, 0, &u32, &consumed) == ATOI_SIMD_EMPTY);
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"12x4", 4, &u32, &consumed) == ATOI_SIMD_INVALID);
+    CHECK(consumed == 2);
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"4294967296", 10, &u32, &consumed) == ATOI_SIMD_OVERFLOW);
+    CHECK(consumed == 9);
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"1-", 2, &u32, &consumed) == ATOI_SIMD_INVALID);
+    CHECK(consumed == 1);
+    // the value is not written on errors
+    CHECK(u32 == 7);
+
+    uint8_t u8 = 0;
+    CHECK(atoi_simd_parse_u8((const uint8_t *)"256", 3, &u8, &consumed) == ATOI_SIMD_OVERFLOW);
+    CHECK(consumed == 2);
+    int8_t i8 = 0;
+    CHECK(atoi_simd_parse_i8((const uint8_t *)"-129", 4, &i8, &consumed) == ATOI_SIMD_OVERFLOW);
+    CHECK(consumed == 3);
+    CHECK(atoi_simd_parse_i8((const uint8_t *)"-12x", 4, &i8, &consumed) == ATOI_SIMD_INVALID);
+    CHECK(consumed == 3);
+    uint64_t u64 = 0;
+    const char *s = "1111111111111111111111111111111111111111111111111111111111111111111111";
+    CHECK(atoi_simd_parse_u64((const uint8_t *)s, strlen(s), &u64, &consumed) == ATOI_SIMD_SIZE);
+
+    CHECK(atoi_simd_parse_u32(NULL, 1, &u32, &consumed) == ATOI_SIMD_NULL_POINTER);
+    CHECK(atoi_simd_parse_u32((const uint8_t *)"1", 1, NULL, &consumed) == ATOI_SIMD_NULL_POINTER);
+    CHECK(atoi_simd_parse_cstr_u32(NULL, &u32, &consumed) == ATOI_SIMD_NULL_POINTER);
+
+    // the errors of a newer version have their own code, not ATOI_SIMD_INVALID
+    CHECK(ATOI_SIMD_UNKNOWN != ATOI_SIMD_INVALID);
+    CHECK(ATOI_SIMD_UNKNOWN > ATOI_SIMD_NULL_POINTER);
+}
+
+static void test_prefix(void) {
+    int32_t i32 = 0;
+    size_t consumed = 0;
+    const char *s = "-123, 456";
+    CHECK(atoi_simd_parse_prefix_i32((const uint8_t *)s, strlen(s), &i32, &consumed) == ATOI_SIMD_OK);
+    CHECK(i32 == -123);
+    CHECK(consumed == 4);
+
+    uint64_t u64 = 0;
+    CHECK(atoi_simd_parse_prefix_cstr_u64("42abc", &u64, &consumed) == ATOI_SIMD_OK);
+    CHECK(u64 == 42);
+    CHECK(consumed == 2);
+    CHECK(atoi_simd_parse_prefix_cstr_u64("abc", &u64, &consumed) == ATOI_SIMD_EMPTY);
+}
+
+static void test_cstr(void) {
+    int16_t i16 = 0;
+    size_t consumed = 0;
+    CHECK(atoi_simd_parse_cstr_i16("-1234", &i16, &consumed) == ATOI_SIMD_OK);
+    CHECK(i16 == -1234);
+    CHECK(consumed == 5);
+    CHECK(atoi_simd_parse_cstr_i16("12 ", &i16, &consumed) == ATOI_SIMD_INVALID);
+    CHECK(consumed == 2);
+    CHECK(atoi_simd_parse_cstr_i16("", &i16, &consumed) == ATOI_SIMD_EMPTY);
+}
+
+int main(void) {
+    test_parse();
+    test_errors();
+    test_prefix();
+    test_cstr();
+    if (failures != 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
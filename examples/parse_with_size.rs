@@ -0,0 +1,25 @@
+//! Parses the last argument with `parse_with` and `parse_prefix_with`, the options are the other arguments.
+//!
+//! The size of its release binary is the compile size of them, checked in the CI:
+//! `cargo build --release --example parse_with_size && size target/release/examples/parse_with_size`
+
+use atoi_simd::{parse_prefix_with, parse_with, ParseOptions, Separator};
+use std::env;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let flag = |name: &str| args.iter().any(|a| a == name);
+    let separator = args
+        .iter()
+        .find_map(|a| a.strip_prefix("--separator="))
+        .and_then(|sep| Separator::from_byte(*sep.as_bytes().first()?));
+    let opts = ParseOptions::new()
+        .skip_zeroes(flag("--skip-zeroes"))
+        .skip_plus(flag("--skip-plus"))
+        .trim(flag("--trim"))
+        .separator(separator)
+        .strict(flag("--strict"));
+    let s = args.last().map_or("", String::as_str);
+    println!("{:?}", parse_with::<u64>(&opts, s.as_bytes()));
+    println!("{:?}", parse_prefix_with::<i32>(&opts, s.as_bytes()));
+}
@@ -0,0 +1,159 @@
+use crate::{
+    linker::{len_sign, ParsePos},
+    AtoiSimdError,
+};
+use alloc::vec::Vec;
+use debug_unsafe::slice::SliceGetter;
+
+/// Max count of the decimal digits that always fit in `u64`
+const CHUNK_LEN: usize = 19;
+
+const POW10: [u64; CHUNK_LEN + 1] = {
+    let mut pow = [1; CHUNK_LEN + 1];
+    let mut i = 1;
+    while i < pow.len() {
+        pow[i] = pow[i - 1] * 10;
+        i += 1;
+    }
+    pow
+};
+
+/// Arbitrary-precision integer parsed by `parse_big`.
+///
+/// `limbs` are little-endian `u64` limbs of the magnitude without the trailing (most significant) zero limbs,
+/// so zero is an empty `Vec` (and it's never negative).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigLimbs {
+    pub neg: bool,
+    pub limbs: Vec<u64>,
+}
+
+/// `limbs = limbs * mul + add`
+#[inline(always)]
+fn mul_add(limbs: &mut Vec<u64>, mul: u64, add: u64) {
+    let mut carry = add;
+    for limb in limbs.iter_mut() {
+        let v = *limb as u128 * mul as u128 + carry as u128;
+        *limb = v as u64;
+        carry = (v >> 64) as u64;
+    }
+    if carry != 0 {
+        limbs.push(carry);
+    }
+}
+
+/// Parses the digits by 19-digit chunks with the `u64` parser.
+/// Returns the value and the count of the parsed digits.
+/// Stops at the first invalid char for `prefix`, else it's an error (with the index in the whole input).
+#[inline(always)]
+fn parse_big_impl<const SKIP_PLUS: bool>(
+    s: &[u8],
+    prefix: bool,
+) -> Result<(BigLimbs, usize), AtoiSimdError<'_>> {
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, true)?;
+    let digits = s.get_safe_unchecked(sign..);
+    let mut limbs = Vec::with_capacity(digits.len() / CHUNK_LEN / 2 + 1);
+    let mut start = 0;
+    let mut end = match digits.len() % CHUNK_LEN {
+        0 => CHUNK_LEN,
+        first => first,
+    };
+    while start < digits.len() {
+        let chunk = digits.get_safe_unchecked(start..end);
+        let (v, len) = match u64::atoi_simd_parse_pos::<false>(chunk) {
+            Ok(v) => (v, chunk.len()),
+            Err(AtoiSimdError::Invalid64(v, i, _)) => (v, i),
+            Err(AtoiSimdError::Invalid128(v, i, _)) => (v as u64, i),
+            Err(_) => (0, 0),
+        };
+        mul_add(&mut limbs, POW10[len], v);
+        if len != chunk.len() {
+            let index = start + len;
+            if index == 0 {
+                return Err(AtoiSimdError::Empty);
+            }
+            if !prefix {
+                return Err(AtoiSimdError::Invalid64(
+                    limbs.first().copied().unwrap_or(0),
+                    sign + index,
+                    s,
+                ));
+            }
+            return Ok((
+                BigLimbs {
+                    neg: neg && !limbs.is_empty(),
+                    limbs,
+                },
+                sign + index,
+            ));
+        }
+        start = end;
+        end += CHUNK_LEN;
+    }
+    if digits.is_empty() {
+        return Err(AtoiSimdError::Empty);
+    }
+    Ok((
+        BigLimbs {
+            neg: neg && !limbs.is_empty(),
+            limbs,
+        },
+        s.len(),
+    ))
+}
+
+#[inline(always)]
+pub(crate) fn parse_big<const SKIP_PLUS: bool>(s: &[u8]) -> Result<BigLimbs, AtoiSimdError<'_>> {
+    parse_big_impl::<SKIP_PLUS>(s, false).map(|(v, _)| v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_big<const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(BigLimbs, usize), AtoiSimdError<'_>> {
+    parse_big_impl::<SKIP_PLUS>(s, true)
+}
+
+#[cfg(feature = "num-bigint")]
+mod num {
+    use super::*;
+    use num_bigint::{BigInt, BigUint, Sign};
+
+    impl From<BigLimbs> for BigUint {
+        /// The magnitude, without the sign
+        #[inline]
+        fn from(v: BigLimbs) -> Self {
+            let digits = v
+                .limbs
+                .iter()
+                .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+                .collect();
+            BigUint::new(digits)
+        }
+    }
+
+    impl From<BigLimbs> for BigInt {
+        #[inline]
+        fn from(v: BigLimbs) -> Self {
+            let sign = if v.neg { Sign::Minus } else { Sign::Plus };
+            BigInt::from_biguint(sign, v.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mul_add() {
+        let mut limbs = Vec::new();
+        mul_add(&mut limbs, 10, 0);
+        assert!(limbs.is_empty());
+        mul_add(&mut limbs, 10, u64::MAX);
+        assert_eq!(limbs, [u64::MAX]);
+        mul_add(&mut limbs, 1 << 32, 0);
+        assert_eq!(limbs, [u64::MAX << 32, u32::MAX as u64]);
+        assert_eq!(POW10[CHUNK_LEN], 10_000_000_000_000_000_000);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{AtoiSimdError, CanonicalRule, Parse};
+
+/// Returns the digits after the '-' sign, if any
+#[inline(always)]
+fn split_sign(s: &[u8]) -> (bool, &[u8]) {
+    match s {
+        [b'-', digits @ ..] => (true, digits),
+        _ => (false, s),
+    }
+}
+
+/// Rejects the '+' sign and the zeroes at the beginning before parsing,
+/// the parser accepts them (and can stop at its max length on the zeroes)
+#[inline(always)]
+fn check_prefix(s: &[u8]) -> Result<(), AtoiSimdError<'_>> {
+    match split_sign(s) {
+        (false, [b'+', ..]) => Err(AtoiSimdError::NonCanonical(CanonicalRule::PlusSign, s)),
+        (_, [b'0', c, ..]) if c.is_ascii_digit() => {
+            Err(AtoiSimdError::NonCanonical(CanonicalRule::LeadingZero, s))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rejects the parsed "-0" without ALLOW_NEG_ZERO
+#[inline(always)]
+fn check_neg_zero<const ALLOW_NEG_ZERO: bool>(s: &[u8]) -> Result<(), AtoiSimdError<'_>> {
+    match split_sign(s) {
+        (true, [b'0', ..]) if !ALLOW_NEG_ZERO => {
+            Err(AtoiSimdError::NonCanonical(CanonicalRule::NegativeZero, s))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[inline(always)]
+pub(crate) fn parse_canonical<T: Parse, const ALLOW_NEG_ZERO: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    check_prefix(s)?;
+    let v = T::atoi_simd_parse::<false, false>(s)?;
+    check_neg_zero::<ALLOW_NEG_ZERO>(s)?;
+    Ok(v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_canonical<T: Parse, const ALLOW_NEG_ZERO: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    check_prefix(s)?;
+    let res = T::atoi_simd_parse_prefix::<false, false>(s)?;
+    check_neg_zero::<ALLOW_NEG_ZERO>(s)?;
+    Ok(res)
+}
@@ -0,0 +1,201 @@
+//! C `strtol` family compatible parsing, with the semantics of glibc (in the "C" locale).
+//!
+//! `long` and `long long` are `i64` here, like on the LP64 platforms.
+//!
+//! ```
+//! use atoi_simd::compat::{strtol, strtoul, Errno, Strtol};
+//!
+//! assert_eq!(strtol(b"  -0x1Fz", 0), Strtol { value: -0x1F, end: 7, errno: None });
+//! assert_eq!(strtol(b"99999999999999999999", 10).errno, Some(Errno::Range));
+//! assert_eq!(strtoul(b"-1", 10).value, u64::MAX);
+//! ```
+
+use crate::{whitespace::whitespace_len, AtoiSimdError, ParseRadixPos};
+use debug_unsafe::slice::SliceGetter;
+
+/// The `errno` value set by the conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Errno {
+    /// `ERANGE`, the value is out of range, it's clamped
+    Range,
+    /// `EINVAL`, the base is not supported
+    Invalid,
+}
+
+/// The result of the conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Strtol<T> {
+    /// The converted value, 0 if there is no number
+    pub value: T,
+    /// The offset of the first unparsed char (`*endptr`), 0 if there is no number
+    pub end: usize,
+    /// The `errno` value, `None` if it's not set
+    pub errno: Option<Errno>,
+}
+
+/// Length of the C `isspace` chars at the beginning, it's the ASCII whitespace and `\x0B`
+#[inline(always)]
+fn space_len(s: &[u8]) -> usize {
+    let mut len = whitespace_len(s);
+    while s.get(len) == Some(&b'\x0B') {
+        len += 1 + whitespace_len(s.get_safe_unchecked(len + 1..));
+    }
+    len
+}
+
+#[inline(always)]
+fn digits_len(s: &[u8], base: u32) -> usize {
+    s.iter()
+        .take_while(|&&c| (c as char).is_digit(base))
+        .count()
+}
+
+/// Parses the magnitude in `RADIX` with the SIMD parser.
+/// Returns the magnitude, the length of the digits, and if it doesn't fit in `u64`.
+#[inline(always)]
+fn magnitude<const RADIX: u32>(s: &[u8]) -> (u64, usize, bool) {
+    match u64::atoi_simd_parse_prefix_radix_pos::<RADIX, true>(s) {
+        Ok((mag, len)) => (mag, len, false),
+        Err(AtoiSimdError::Empty) => (0, 0, false),
+        // all of the digits are consumed anyway
+        Err(_) => (u64::MAX, digits_len(s, RADIX), true),
+    }
+}
+
+/// Parses the magnitude in the other bases
+#[cold]
+fn magnitude_slow(s: &[u8], base: u32) -> (u64, usize, bool) {
+    let len = digits_len(s, base);
+    let mag = s
+        .get_safe_unchecked(..len)
+        .iter()
+        .try_fold(0_u64, |mag, &c| {
+            mag.checked_mul(base as u64)?
+                .checked_add((c as char).to_digit(base)? as u64)
+        });
+    match mag {
+        Some(mag) => (mag, len, false),
+        None => (u64::MAX, len, true),
+    }
+}
+
+/// Parses the number like `strtoull`, returns `None` if there is no number.
+/// Returns the magnitude, if it's negative, the end of the number and if the magnitude doesn't fit in `u64`.
+#[inline(always)]
+fn strto(s: &[u8], base: u32) -> Option<(u64, bool, usize, bool)> {
+    let mut i = space_len(s);
+    let (sign, neg) = match s.get(i) {
+        Some(b'-') => (1, true),
+        Some(b'+') => (1, false),
+        _ => (0, false),
+    };
+    i += sign;
+
+    let rest = s.get_safe_unchecked(i..);
+    // the prefix is a part of the number only with a hex digit after it
+    let hex = matches!(rest, [b'0', b'x' | b'X', c, ..] if c.is_ascii_hexdigit());
+    let base = match base {
+        0 if hex => 16,
+        0 if rest.first() == Some(&b'0') => 8,
+        0 => 10,
+        base => base,
+    };
+    if base == 16 && hex {
+        i += 2;
+    }
+
+    let digits = s.get_safe_unchecked(i..);
+    let (mag, len, overflow) = match base {
+        10 => magnitude::<10>(digits),
+        16 => magnitude::<16>(digits),
+        8 => magnitude::<8>(digits),
+        base => magnitude_slow(digits, base),
+    };
+    if len == 0 {
+        return None;
+    }
+    Some((mag, neg, i + len, overflow))
+}
+
+#[inline(always)]
+fn check_base<T: Default>(base: u32) -> Result<(), Strtol<T>> {
+    if base == 1 || base > 36 {
+        return Err(Strtol {
+            value: T::default(),
+            end: 0,
+            errno: Some(Errno::Invalid),
+        });
+    }
+    Ok(())
+}
+
+/// Converts the number at the beginning of `s` to `long`, like C `strtol`.
+///
+/// Skips the whitespace (C `isspace`) and parses the optional '+' or '-' sign, then the digits in `base` (2..=36).
+/// If `base` is 0, it's detected by the prefix: `0x` or `0X` for 16, `0` for 8, 10 otherwise.
+/// The `0x` prefix is also allowed with the base 16.
+/// Out of range values are clamped to `i64::MIN`/`i64::MAX` with `Errno::Range`.
+#[inline]
+pub fn strtol(s: &[u8], base: u32) -> Strtol<i64> {
+    if let Err(res) = check_base(base) {
+        return res;
+    }
+    match strto(s, base) {
+        None => Strtol {
+            value: 0,
+            end: 0,
+            errno: None,
+        },
+        Some((mag, neg, end, overflow)) => {
+            let (value, errno) = match (neg, overflow) {
+                (false, false) if mag <= i64::MAX as u64 => (mag as i64, None),
+                (false, _) => (i64::MAX, Some(Errno::Range)),
+                (true, false) if mag <= i64::MIN.unsigned_abs() => {
+                    ((mag as i64).wrapping_neg(), None)
+                }
+                (true, _) => (i64::MIN, Some(Errno::Range)),
+            };
+            Strtol { value, end, errno }
+        }
+    }
+}
+
+/// Converts the number at the beginning of `s` to `long long`, like C `strtoll`, the same as `strtol`
+#[inline]
+pub fn strtoll(s: &[u8], base: u32) -> Strtol<i64> {
+    strtol(s, base)
+}
+
+/// Converts the number at the beginning of `s` to `unsigned long`, like C `strtoul`.
+///
+/// The same syntax as `strtol`, but a negative value is negated modulo 2^64 (so "-1" is `u64::MAX`),
+/// and out of range values are clamped to `u64::MAX` with `Errno::Range`.
+#[inline]
+pub fn strtoul(s: &[u8], base: u32) -> Strtol<u64> {
+    if let Err(res) = check_base(base) {
+        return res;
+    }
+    match strto(s, base) {
+        None => Strtol {
+            value: 0,
+            end: 0,
+            errno: None,
+        },
+        Some((_, _, end, true)) => Strtol {
+            value: u64::MAX,
+            end,
+            errno: Some(Errno::Range),
+        },
+        Some((mag, neg, end, false)) => Strtol {
+            value: if neg { mag.wrapping_neg() } else { mag },
+            end,
+            errno: None,
+        },
+    }
+}
+
+/// Converts the number at the beginning of `s` to `unsigned long long`, like C `strtoull`, the same as `strtoul`
+#[inline]
+pub fn strtoull(s: &[u8], base: u32) -> Strtol<u64> {
+    strtoul(s, base)
+}
@@ -0,0 +1,187 @@
+use crate::{
+    linker::{len_sign, ParseOverflow, ParsePos},
+    AtoiSimdError, Sign,
+};
+use debug_unsafe::slice::SliceGetter;
+
+/// Max `SCALE`, so `10^SCALE` fits in `u128`
+const MAX_SCALE: u32 = 38;
+
+/// Policy for the fractional digits beyond `SCALE` in `parse_decimal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Extra digits are an error (at the first extra digit)
+    Reject,
+    /// Extra digits are dropped (rounding toward zero)
+    Truncate,
+    /// Rounds to the nearest, ties to even (banker's rounding)
+    HalfEven,
+    /// Rounds to the nearest, ties away from zero
+    HalfUp,
+}
+
+impl Rounding {
+    /// If the magnitude with the last kept digit `last` must be rounded up,
+    /// `dropped` are the extra digits (at least one)
+    #[inline(always)]
+    fn round_up(self, last: u128, dropped: &[u8]) -> bool {
+        let first = *dropped.get_safe_unchecked(0);
+        match self {
+            Rounding::Reject | Rounding::Truncate => false,
+            Rounding::HalfUp => first >= b'5',
+            Rounding::HalfEven => {
+                first > b'5'
+                    || (first == b'5'
+                        && (last & 1 == 1
+                            || dropped.get_safe_unchecked(1..).iter().any(|&c| c != b'0')))
+            }
+        }
+    }
+}
+
+/// The overflow of the number after the `sign` char, at the digit which makes the scaled magnitude
+/// bigger than `max`, or at the end of the number if it's the rounding
+#[cold]
+fn overflow<const SCALE: u32>(s: &[u8], sign: usize, neg: bool, max: u128) -> AtoiSimdError<'_> {
+    let mut mag = 0_u128;
+    let mut scale = SCALE;
+    let mut frac = false;
+    let mut index = sign;
+    for &c in s.get_safe_unchecked(sign..) {
+        match c {
+            b'.' if !frac => frac = true,
+            b'0'..=b'9' if !frac || scale > 0 => {
+                scale -= frac as u32;
+                mag = match mag
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add((c & 0xF) as u128))
+                {
+                    Some(m) => m,
+                    None => break,
+                };
+                if mag
+                    .checked_mul(10_u128.pow(scale))
+                    .map_or(true, |m| m > max)
+                {
+                    break;
+                }
+            }
+            // the dropped digits
+            b'0'..=b'9' => {}
+            _ => break,
+        }
+        index += 1;
+    }
+    let sign = if neg { Sign::Neg } else { Sign::Pos };
+    AtoiSimdError::Overflow(sign, index, s)
+}
+
+#[inline(always)]
+fn check_scale<const SCALE: u32>() {
+    assert!(SCALE <= MAX_SCALE, "SCALE must be less than or equal to 38");
+}
+
+/// Parses `[sign]digits[.digits]` into the magnitude scaled by `10^SCALE`.
+/// Returns the value and the length of the number (with the sign).
+/// Errors are relative to the whole input.
+#[inline(always)]
+fn parse_decimal_impl<'a, T: ParseOverflow, const SCALE: u32, const SKIP_PLUS: bool>(
+    s: &'a [u8],
+    rounding: Rounding,
+) -> Result<(T, usize, u128), AtoiSimdError<'a>> {
+    check_scale::<SCALE>();
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, T::MAX_NEG != 0)?;
+    let max = if neg { T::MAX_NEG } else { T::MAX_POS };
+    let scale = 10_u128.pow(SCALE);
+
+    let rest = s.get_safe_unchecked(sign..);
+    let (int, mut len) = match u128::atoi_simd_parse_prefix_pos::<true>(rest) {
+        Ok(v) => v,
+        Err(AtoiSimdError::Empty) => (0, 0),
+        Err(_) => return Err(overflow::<SCALE>(s, sign, neg, max)),
+    };
+    let mut mag = int
+        .checked_mul(scale)
+        .ok_or_else(|| overflow::<SCALE>(s, sign, neg, max))?;
+
+    let (dot, frac) = match rest.get(len..) {
+        Some([b'.', frac @ ..]) => (true, frac),
+        _ => (false, &[][..]),
+    };
+    let frac_len = frac.iter().take_while(|c| c.is_ascii_digit()).count();
+    if frac_len != 0 {
+        let kept_len = frac_len.min(SCALE as usize);
+        // empty with SCALE == 0
+        let kept = u128::atoi_simd_parse_pos::<false>(frac.get_safe_unchecked(..kept_len))
+            .unwrap_or_default();
+        mag = mag
+            .checked_add(kept * 10_u128.pow(SCALE - kept_len as u32))
+            .ok_or_else(|| overflow::<SCALE>(s, sign, neg, max))?;
+        if frac_len > kept_len {
+            if rounding == Rounding::Reject {
+                let index = sign + len + 1 + kept_len;
+                return Err(T::atoi_simd_invalid(mag.min(max), index, s));
+            }
+            let dropped = frac.get_safe_unchecked(kept_len..frac_len);
+            if rounding.round_up(mag, dropped) {
+                mag = mag
+                    .checked_add(1)
+                    .ok_or_else(|| overflow::<SCALE>(s, sign, neg, max))?;
+            }
+        }
+        len += 1 + frac_len;
+    } else if len == 0 {
+        return Err(AtoiSimdError::Empty);
+    } else {
+        // an empty fractional part, like `3.`
+        len += dot as usize;
+    }
+
+    if mag > max {
+        return Err(overflow::<SCALE>(s, sign, neg, max));
+    }
+    Ok((T::atoi_simd_from_magnitude(mag, neg), sign + len, mag))
+}
+
+#[inline(always)]
+pub(crate) fn parse_decimal<T: ParseOverflow, const SCALE: u32, const SKIP_PLUS: bool>(
+    s: &[u8],
+    rounding: Rounding,
+) -> Result<T, AtoiSimdError<'_>> {
+    let (v, len, mag) = parse_decimal_impl::<T, SCALE, SKIP_PLUS>(s, rounding)?;
+    if len != s.len() {
+        return Err(T::atoi_simd_invalid(mag, len, s));
+    }
+    Ok(v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_decimal<T: ParseOverflow, const SCALE: u32, const SKIP_PLUS: bool>(
+    s: &[u8],
+    rounding: Rounding,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_decimal_impl::<T, SCALE, SKIP_PLUS>(s, rounding).map(|(v, len, _)| (v, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_up() {
+        for (last, dropped, half_even, half_up) in [
+            (0, "4", false, false),
+            (0, "5", false, true),
+            (1, "5", true, true),
+            (0, "50", false, true),
+            (0, "501", true, true),
+            (2, "6", true, true),
+            (3, "49999", false, false),
+        ] {
+            let dropped = dropped.as_bytes();
+            assert_eq!(Rounding::HalfEven.round_up(last, dropped), half_even);
+            assert_eq!(Rounding::HalfUp.round_up(last, dropped), half_up);
+            assert!(!Rounding::Truncate.round_up(last, dropped));
+        }
+    }
+}
@@ -1,14 +1,309 @@
 use ::core::fmt;
 
+/// The rule of the canonical decimal form violated by the input, see `parse_canonical`
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum CanonicalRule {
+    /// The '+' sign
+    PlusSign,
+    /// Zeroes at the beginning of a non-zero value, or more than one zero
+    LeadingZero,
+    /// The negative zero ("-0")
+    NegativeZero,
+}
+
+/// The sign of the overflowed value
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The value is too big
+    Pos,
+    /// The value is too small (negative)
+    Neg,
+}
+
+/// Max length of the input in the error messages and in `OwnedError`
+const SNIPPET_LEN: usize = 48;
+
+/// Kinds of `AtoiSimdError`, like `core::num::IntErrorKind`
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input is empty (or has no digits)
+    Empty,
+    /// The input contains an invalid char
+    InvalidDigit,
+    /// The value is too big for the integer
+    PosOverflow,
+    /// The value is too small for the integer
+    NegOverflow,
+    /// The number is too long
+    TooLong,
+    /// The value is out of the range of the bounded parsing
+    OutOfRange,
+    /// The value is zero, for the `NonZero*` integers
+    Zero,
+    /// The value has a fractional part
+    NotIntegral,
+    /// The number is not in the canonical form
+    NonCanonical(CanonicalRule),
+}
+
+/// The parsing error, its indices are in the whole input of the entry point
+/// (they count the sign and the skipped zeroes)
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum AtoiSimdError<'a> {
     Empty,
+    /// The number is too long, with its length
     Size(usize, &'a [u8]),
-    Overflow(&'a [u8]),
+    /// The value doesn't fit in the integer, with the index of the digit which makes it out of range
+    /// (or the end of the number, if it's out of range after the scaling or the rounding)
+    Overflow(Sign, usize, &'a [u8]),
+    /// The value is out of the `MIN..=MAX` range of the bounded parsing
+    OutOfRange(&'a [u8]),
+    /// The value is zero, for the `NonZero*` integers
+    Zero(&'a [u8]),
+    /// The value has a fractional part, for `parse_integral`
+    NotIntegral(&'a [u8]),
+    /// The number is not in the canonical form, for `parse_canonical`
+    NonCanonical(CanonicalRule, &'a [u8]),
+    /// The input contains an invalid char, with the value before it and its index
     Invalid64(u64, usize, &'a [u8]),
+    /// The input contains an invalid char, with the value before it and its index
     Invalid128(u128, usize, &'a [u8]),
 }
 
+impl<'a> AtoiSimdError<'a> {
+    /// Moves the error index by `offset` (like a skipped sign), and replaces the input
+    #[inline]
+    pub(crate) fn with_offset(self, offset: usize, input: &'a [u8]) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Size(len, _) => Self::Size(len + offset, input),
+            Self::Overflow(sign, index, _) => Self::Overflow(sign, index + offset, input),
+            Self::OutOfRange(_) => Self::OutOfRange(input),
+            Self::Zero(_) => Self::Zero(input),
+            Self::NotIntegral(_) => Self::NotIntegral(input),
+            Self::NonCanonical(rule, _) => Self::NonCanonical(rule, input),
+            Self::Invalid64(res, index, _) => Self::Invalid64(res, index + offset, input),
+            Self::Invalid128(res, index, _) => Self::Invalid128(res, index + offset, input),
+        }
+    }
+
+    /// The overflow of the number in `s` (the digits in `radix`),
+    /// at the digit which makes the magnitude bigger than `max`
+    #[cold]
+    pub(crate) fn overflow_radix(sign: Sign, s: &'a [u8], radix: u32, max: u128) -> Self {
+        let mut mag = 0_u128;
+        let mut index = 0;
+        for &c in s {
+            let digit = match (c as char).to_digit(radix) {
+                Some(d) => d as u128,
+                None => break,
+            };
+            match mag
+                .checked_mul(radix as u128)
+                .and_then(|m| m.checked_add(digit))
+            {
+                Some(m) if m <= max => mag = m,
+                _ => break,
+            }
+            index += 1;
+        }
+        Self::Overflow(sign, index, s)
+    }
+
+    /// The overflow of the decimal number in `s`, see `overflow_radix`
+    #[cold]
+    pub(crate) fn overflow(sign: Sign, s: &'a [u8], max: u128) -> Self {
+        Self::overflow_radix(sign, s, 10, max)
+    }
+
+    /// Marks the overflow as negative, for the parsers of the absolute value
+    #[inline]
+    pub(crate) fn neg(self) -> Self {
+        match self {
+            Self::Overflow(_, index, input) => Self::Overflow(Sign::Neg, index, input),
+            e => e,
+        }
+    }
+
+    /// The kind of the error
+    #[inline]
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            Self::Empty => ErrorKind::Empty,
+            Self::Size(_, _) => ErrorKind::TooLong,
+            Self::Overflow(Sign::Pos, _, _) => ErrorKind::PosOverflow,
+            Self::Overflow(Sign::Neg, _, _) => ErrorKind::NegOverflow,
+            Self::OutOfRange(_) => ErrorKind::OutOfRange,
+            Self::Zero(_) => ErrorKind::Zero,
+            Self::NotIntegral(_) => ErrorKind::NotIntegral,
+            Self::NonCanonical(rule, _) => ErrorKind::NonCanonical(rule),
+            Self::Invalid64(_, _, _) | Self::Invalid128(_, _, _) => ErrorKind::InvalidDigit,
+        }
+    }
+
+    /// The input of the error (empty for `Empty`)
+    #[inline]
+    pub fn input(&self) -> &'a [u8] {
+        match *self {
+            Self::Empty => &[],
+            Self::Size(_, input)
+            | Self::Overflow(_, _, input)
+            | Self::OutOfRange(input)
+            | Self::Zero(input)
+            | Self::NotIntegral(input)
+            | Self::NonCanonical(_, input)
+            | Self::Invalid64(_, _, input)
+            | Self::Invalid128(_, _, input) => input,
+        }
+    }
+
+    /// The byte offset of the error in the input (counting the sign): the index of the invalid char,
+    /// of the digit which makes the value overflow, or the length of the too long number
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::Size(len, _) => Some(len),
+            Self::Overflow(_, index, _)
+            | Self::Invalid64(_, index, _)
+            | Self::Invalid128(_, index, _) => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Converts to an error without the borrowed input, with a copy of up to 48 bytes of it around the offset
+    #[inline]
+    pub fn into_owned(self) -> OwnedError {
+        let input = self.input();
+        OwnedError::new(self.kind(), self.offset(), input.len(), |i| input[i])
+    }
+}
+
+/// `AtoiSimdError` without the borrowed input (it's `'static`, `Send` and `Sync`),
+/// with a copy of up to 48 bytes of it around the offset of the error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OwnedError {
+    kind: ErrorKind,
+    offset: Option<usize>,
+    snippet: [u8; SNIPPET_LEN],
+    snippet_start: usize,
+    snippet_len: u8,
+    input_len: usize,
+}
+
+impl OwnedError {
+    /// The error with a snippet of the input of `input_len` units, `unit` returns them as bytes
+    #[inline]
+    fn new(
+        kind: ErrorKind,
+        offset: Option<usize>,
+        input_len: usize,
+        unit: impl Fn(usize) -> u8,
+    ) -> Self {
+        let len = input_len.min(SNIPPET_LEN);
+        // centered on the offset, but inside the input
+        let start = offset
+            .map_or(0, |o| o.saturating_sub(SNIPPET_LEN / 2))
+            .min(input_len - len);
+        let mut snippet = [0; SNIPPET_LEN];
+        for (i, b) in snippet[..len].iter_mut().enumerate() {
+            *b = unit(start + i);
+        }
+        OwnedError {
+            kind,
+            offset,
+            snippet,
+            snippet_start: start,
+            snippet_len: len as u8,
+            input_len,
+        }
+    }
+
+    /// Converts the error of a UTF-16 parser (like `parse_utf16`) of the input `s` to an error
+    /// with a copy of up to 48 units of it around the offset, narrowed to bytes (0xFF above it)
+    #[inline]
+    pub fn from_utf16(e: AtoiSimdError<'_>, s: &[u16]) -> Self {
+        // without the input, like `AtoiSimdError::Empty`
+        let s = if e == AtoiSimdError::Empty { &[] } else { s };
+        Self::new(e.kind(), e.offset(), s.len(), |i| {
+            u8::try_from(s[i]).unwrap_or(u8::MAX)
+        })
+    }
+
+    /// The kind of the error
+    #[inline]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The byte offset of the error (in code units for the UTF-16 input), the same as `AtoiSimdError::offset`
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Up to 48 bytes of the input around the offset (from the beginning without it),
+    /// the UTF-16 code units are narrowed to bytes (0xFF above it)
+    #[inline]
+    pub fn snippet(&self) -> &[u8] {
+        &self.snippet[..self.snippet_len as usize]
+    }
+
+    /// The index of the first byte (or code unit) of the snippet in the input
+    #[inline]
+    pub fn snippet_start(&self) -> usize {
+        self.snippet_start
+    }
+
+    /// The length of the whole input (in code units for the UTF-16 input)
+    #[inline]
+    pub fn input_len(&self) -> usize {
+        self.input_len
+    }
+}
+
+impl From<AtoiSimdError<'_>> for OwnedError {
+    #[inline]
+    fn from(e: AtoiSimdError<'_>) -> Self {
+        e.into_owned()
+    }
+}
+
+impl fmt::Display for OwnedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ErrorKind::Empty => return write!(f, "atoi_simd string is empty"),
+            ErrorKind::InvalidDigit => write!(f, "atoi_simd invalid digit")?,
+            ErrorKind::PosOverflow => write!(f, "atoi_simd positive overflow")?,
+            ErrorKind::NegOverflow => write!(f, "atoi_simd negative overflow")?,
+            ErrorKind::TooLong => write!(f, "atoi_simd wrong size")?,
+            ErrorKind::OutOfRange => write!(f, "atoi_simd out of range")?,
+            ErrorKind::Zero => write!(f, "atoi_simd zero value for a non-zero integer")?,
+            ErrorKind::NotIntegral => write!(f, "atoi_simd value is not integral")?,
+            ErrorKind::NonCanonical(rule) => {
+                write!(f, "atoi_simd non-canonical form ({:?})", rule)?
+            }
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at: {}", offset)?;
+        }
+        if self.snippet_start != 0 {
+            write!(
+                f,
+                ", input from {}: {:X?}",
+                self.snippet_start,
+                self.snippet()
+            )
+        } else {
+            write!(f, ", input: {:X?}", self.snippet())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for OwnedError {}
+
 impl fmt::Display for AtoiSimdError<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -18,28 +313,62 @@ impl fmt::Display for AtoiSimdError<'_> {
                     f,
                     "atoi_simd wrong size: {} input: {:X?}",
                     len,
-                    &input[..input.len().min(48)]
+                    &input[..input.len().min(SNIPPET_LEN)]
+                )
+            }
+            Self::Overflow(sign, index, input) => {
+                write!(
+                    f,
+                    "atoi_simd {} overflow at index: {}, input: {:X?}",
+                    match sign {
+                        Sign::Pos => "positive",
+                        Sign::Neg => "negative",
+                    },
+                    index,
+                    &input[..input.len().min(SNIPPET_LEN)]
+                )
+            }
+            Self::OutOfRange(input) => {
+                write!(
+                    f,
+                    "atoi_simd out of range, input: {:X?}",
+                    &input[..input.len().min(SNIPPET_LEN)]
+                )
+            }
+            Self::Zero(input) => {
+                write!(
+                    f,
+                    "atoi_simd zero value for a non-zero integer, input: {:X?}",
+                    &input[..input.len().min(SNIPPET_LEN)]
+                )
+            }
+            Self::NotIntegral(input) => {
+                write!(
+                    f,
+                    "atoi_simd value is not integral, input: {:X?}",
+                    &input[..input.len().min(SNIPPET_LEN)]
                 )
             }
-            Self::Overflow(input) => {
+            Self::NonCanonical(rule, input) => {
                 write!(
                     f,
-                    "atoi_simd overflow, input: {:X?}",
-                    &input[..input.len().min(48)]
+                    "atoi_simd non-canonical form ({:?}), input: {:X?}",
+                    rule,
+                    &input[..input.len().min(SNIPPET_LEN)]
                 )
             }
             Self::Invalid64(res, index, input) => {
                 write!(
                     f,
                     "atoi_simd invalid at index: {} it must contain only digits, starting with: {}  input: {:X?}",
-                    index, res, &input[..input.len().min(48)]
+                    index, res, &input[..input.len().min(SNIPPET_LEN)]
                 )
             }
             Self::Invalid128(res, index, input) => {
                 write!(
                     f,
                     "atoi_simd invalid at index: {} it must contain only digits, starting with: {} input: {:X?}",
-                    index, res, &input[..input.len().min(48)]
+                    index, res, &input[..input.len().min(SNIPPET_LEN)]
                 )
             }
         }
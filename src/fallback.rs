@@ -2,7 +2,7 @@
 
 use crate::{
     short::{parse_short_neg, parse_short_pos},
-    AtoiSimdError,
+    AtoiSimdError, Sign,
 };
 use ::core::convert::TryInto;
 use debug_unsafe::slice::SliceGetter;
@@ -203,6 +203,8 @@ pub(crate) fn parse_fb_pos<const MAX: u64, const SKIP_ZEROES: bool>(
 ) -> Result<(u64, usize), AtoiSimdError<'_>> {
     debug_assert!(MAX < i64::MAX as u64);
 
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     let (val, len) = loop {
         match parse_16_by_8::<SKIP_ZEROES>(s) {
@@ -223,7 +225,7 @@ pub(crate) fn parse_fb_pos<const MAX: u64, const SKIP_ZEROES: bool>(
         }
     };
     if val > MAX {
-        return Err(AtoiSimdError::Overflow(s));
+        return Err(AtoiSimdError::overflow(Sign::Pos, input, MAX as u128));
     }
 
     Ok((val, (len + skipped) as usize))
@@ -236,6 +238,8 @@ pub(crate) fn parse_fb_neg<const MIN: i64, const SKIP_ZEROES: bool>(
     debug_assert!(MIN > i64::MIN);
     debug_assert!(MIN < 0);
 
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     let (val, len) = loop {
         match parse_16_by_8::<SKIP_ZEROES>(s) {
@@ -257,7 +261,11 @@ pub(crate) fn parse_fb_neg<const MIN: i64, const SKIP_ZEROES: bool>(
     };
     let val = -(val as i64);
     if val < MIN {
-        return Err(AtoiSimdError::Overflow(s));
+        return Err(AtoiSimdError::overflow(
+            Sign::Neg,
+            input,
+            MIN.unsigned_abs() as u128,
+        ));
     }
 
     Ok((val, (len + skipped) as usize))
@@ -269,6 +277,8 @@ pub(crate) fn parse_fb_64_pos<const MAX: u64, const LEN_MORE: u32, const SKIP_ZE
 ) -> Result<(u64, usize), AtoiSimdError<'_>> {
     debug_assert!(MAX >= i64::MAX as u64);
 
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     loop {
         let (val, len, zeroes) = match parse_16_by_8::<SKIP_ZEROES>(s) {
@@ -297,12 +307,12 @@ pub(crate) fn parse_fb_64_pos<const MAX: u64, const LEN_MORE: u32, const SKIP_ZE
                 s = s.get_safe_unchecked((zeroes as usize)..);
                 continue;
             }
-            return Err(AtoiSimdError::Size((len + 16 + skipped) as usize, s));
+            return Err(AtoiSimdError::Size((len + 16 + skipped) as usize, input));
         }
 
         let shift = 10_u64.pow(len);
         if len == LEN_MORE && overflow!(val, shift, more, MAX) {
-            return Err(AtoiSimdError::Overflow(s));
+            return Err(AtoiSimdError::overflow(Sign::Pos, input, MAX as u128));
         }
         let res = val * shift + more;
 
@@ -314,7 +324,8 @@ pub(crate) fn parse_fb_64_pos<const MAX: u64, const LEN_MORE: u32, const SKIP_ZE
 pub(crate) fn parse_fb_64_neg<const SKIP_ZEROES: bool>(
     s: &[u8],
 ) -> Result<(i64, usize), AtoiSimdError<'_>> {
-    let (val, len) = parse_fb_64_pos::<{ i64::MAX as u64 + 1 }, 3, SKIP_ZEROES>(s)?;
+    let (val, len) = parse_fb_64_pos::<{ i64::MAX as u64 + 1 }, 3, SKIP_ZEROES>(s)
+        .map_err(AtoiSimdError::neg)?;
     if val > i64::MAX as u64 {
         return Ok((i64::MIN, len));
     }
@@ -328,6 +339,8 @@ pub(crate) fn parse_fb_128_pos<const MAX: u128, const SKIP_ZEROES: bool>(
 ) -> Result<(u128, usize), AtoiSimdError<'_>> {
     debug_assert!(MAX >= i128::MAX as u128);
 
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     loop {
         let (mut val, len, zeroes) = match parse_16_by_8::<SKIP_ZEROES>(s) {
@@ -373,9 +386,9 @@ pub(crate) fn parse_fb_128_pos<const MAX: u128, const SKIP_ZEROES: bool>(
                 s = s.get_safe_unchecked((zeroes as usize)..);
                 continue;
             }
-            return Err(AtoiSimdError::Size((len + 32 + skipped) as usize, s));
+            return Err(AtoiSimdError::Size((len + 32 + skipped) as usize, input));
         } else if len == 7 && overflow!(val, 10_000_000, more, MAX) {
-            return Err(AtoiSimdError::Overflow(s));
+            return Err(AtoiSimdError::overflow(Sign::Pos, input, MAX));
         }
         let res = val * 10_u128.pow(len) + more;
 
@@ -387,7 +400,8 @@ pub(crate) fn parse_fb_128_pos<const MAX: u128, const SKIP_ZEROES: bool>(
 pub(crate) fn parse_fb_128_neg<const SKIP_ZEROES: bool>(
     s: &[u8],
 ) -> Result<(i128, usize), AtoiSimdError<'_>> {
-    let (val, len) = parse_fb_128_pos::<{ i128::MAX as u128 + 1 }, SKIP_ZEROES>(s)?;
+    let (val, len) = parse_fb_128_pos::<{ i128::MAX as u128 + 1 }, SKIP_ZEROES>(s)
+        .map_err(AtoiSimdError::neg)?;
     if val > i128::MAX as u128 {
         return Ok((i128::MIN, len));
     }
@@ -450,7 +464,7 @@ pub(crate) fn parse_fb_checked_64_neg<const SKIP_ZEROES: bool>(
         parse_fb_64_neg::<SKIP_ZEROES>(s)?
     };
     if len != s_len {
-        return Err(AtoiSimdError::Invalid64(-res as u64, len, s));
+        return Err(AtoiSimdError::Invalid64(res.wrapping_neg() as u64, len, s));
     }
     Ok(res)
 }
@@ -482,17 +496,199 @@ pub(crate) fn parse_fb_checked_128_neg<const SKIP_ZEROES: bool>(
         parse_fb_128_neg::<SKIP_ZEROES>(s)?
     };
     if len != s_len {
-        return Err(AtoiSimdError::Invalid128(-res as u128, len, s));
+        return Err(AtoiSimdError::Invalid128(
+            res.wrapping_neg() as u128,
+            len,
+            s,
+        ));
     }
     Ok(res)
 }
 
+/// sets the high bit of each byte that is in `LOW..=HIGH`
+#[inline(always)]
+fn in_range_8<const LOW: u8, const HIGH: u8>(val: u64) -> u64 {
+    between_8(val, LOW, HIGH)
+}
+
+/// sets the high bit of each byte that is in `low..=high` (ASCII)
+#[inline(always)]
+fn between_8(val: u64, low: u8, high: u8) -> u64 {
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    // clear high bits to prevent carries between bytes
+    let low_bits = val & 0x7F7F_7F7F_7F7F_7F7F;
+    let ge_low = low_bits + (0x80 - low as u64) * ONES;
+    let gt_high = low_bits + (0x7F - high as u64) * ONES;
+    ge_low & !gt_high & !val & 0x8080_8080_8080_8080
+}
+
+/// Combines 8 digits of `shift` bits each, first byte is the most significant.
+///
+/// process_pow2_8(0x0807_0605_0403_0201, 4) = 0x1234_5678
+#[inline(always)]
+fn process_pow2_8(mut val: u64, shift: u32) -> u64 {
+    val = ((val << shift) | (val >> 8)) & 0x00FF_00FF_00FF_00FF;
+    val = ((val << (2 * shift)) | (val >> 16)) & 0x0000_FFFF_0000_FFFF;
+    ((val << (4 * shift)) | (val >> 32)) & 0xFFFF_FFFF
+}
+
+/// Parses up to 8 digits of a power of two `RADIX` (2, 8 or 16)
+#[inline(always)]
+fn parse_pow2_8<const RADIX: u32>(s: &[u8]) -> (u64, u32) {
+    let val = load_8(s);
+    let (valid, digits) = match RADIX {
+        2 => (in_range_8::<b'0', b'1'>(val), val & 0x0101_0101_0101_0101),
+        8 => (in_range_8::<b'0', b'7'>(val), val & 0x0707_0707_0707_0707),
+        16 => {
+            let digit = in_range_8::<b'0', b'9'>(val);
+            let alpha = in_range_8::<b'a', b'f'>(val | 0x2020_2020_2020_2020);
+            // 'a' & 0xF == 1, so adds 9 to the letters
+            (
+                digit | alpha,
+                (val & 0x0F0F_0F0F_0F0F_0F0F) + (alpha >> 7) * 9,
+            )
+        }
+        _ => unreachable!(),
+    };
+    let invalid = !valid & 0x8080_8080_8080_8080;
+    let len = invalid.trailing_zeros() / 8;
+    unsafe { crate::assert_unchecked(len <= 8) }
+    if len == 0 {
+        return (0, 0);
+    }
+
+    let shift = RADIX.trailing_zeros();
+    let digits = digits & (u64::MAX >> (64 - len * 8));
+    (process_pow2_8(digits, shift) >> (shift * (8 - len)), len)
+}
+
+/// Parses up to 16 digits of a power of two `RADIX` (2, 8 or 16)
+#[inline(always)]
+fn parse_fb_pow2_16<const RADIX: u32>(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    let (val, len) = parse_pow2_8::<RADIX>(s);
+    match len {
+        0 => Err(AtoiSimdError::Empty),
+        8 => {
+            let (more, len_more) = parse_pow2_8::<RADIX>(s.get_safe_unchecked(8..));
+            let shift = RADIX.trailing_zeros();
+            Ok((val << (shift * len_more) | more, (len + len_more) as usize))
+        }
+        _ => Ok((val, len as usize)),
+    }
+}
+
+/// Parses up to 16 binary digits
+#[inline(always)]
+pub(crate) fn parse_fb_bin_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_fb_pow2_16::<2>(s)
+}
+
+/// Parses up to 16 octal digits
+#[inline(always)]
+pub(crate) fn parse_fb_oct_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_fb_pow2_16::<8>(s)
+}
+
+/// Parses up to 16 hex digits
+#[inline(always)]
+pub(crate) fn parse_fb_hex_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_fb_pow2_16::<16>(s)
+}
+/// Moves the highest bit of each byte into a bit per byte mask
+#[inline(always)]
+fn movemask_8(val: u64) -> u64 {
+    ((val >> 7).wrapping_mul(0x0102_0408_1020_4080)) >> 56
+}
+
+/// Returns the masks (bit per byte) of the digits and of the `sep` chars in the first 64 bytes.
+/// `sep` must not be zero, it's used for padding.
+#[inline(always)]
+pub(crate) fn separated_masks_fb(s: &[u8], sep: u8) -> (u64, u64) {
+    let mut digits = 0;
+    let mut seps = 0;
+    let mut i = 0;
+    while i < 64 && i < s.len() {
+        let val = load_8(s.get_safe_unchecked(i..));
+        digits |= movemask_8(in_range_8::<b'0', b'9'>(val)) << i;
+        seps |= movemask_8(between_8(val, sep, sep)) << i;
+        i += 8;
+    }
+    (digits, seps)
+}
+
+/// Returns the mask (bit per byte) of the ASCII whitespace (`\t`, `\n`, `\x0C`, `\r` and ' ')
+#[inline(always)]
+fn whitespace_mask_8(val: u64) -> u8 {
+    movemask_8(
+        in_range_8::<b'\t', b'\n'>(val)
+            | in_range_8::<b'\x0C', b'\r'>(val)
+            | in_range_8::<b' ', b' '>(val),
+    ) as u8
+}
+
+/// Returns the count of the ASCII whitespace chars at the beginning
+#[inline(always)]
+pub(crate) fn whitespace_len_fb(s: &[u8]) -> usize {
+    let mut i = 0;
+    while i < s.len() {
+        // the padding zeroes are not whitespace
+        let len = whitespace_mask_8(load_8(s.get_safe_unchecked(i..))).trailing_ones() as usize;
+        if len < 8 {
+            return i + len;
+        }
+        i += 8;
+    }
+    s.len()
+}
+
+/// Returns the count of the ASCII whitespace chars at the end
+#[inline(always)]
+pub(crate) fn whitespace_len_back_fb(s: &[u8]) -> usize {
+    let mut end = s.len();
+    while end != 0 {
+        let start = end.saturating_sub(8);
+        let mask = whitespace_mask_8(load_8(s.get_safe_unchecked(start..end)));
+        let chunk_len = end - start;
+        let len = (mask << (8 - chunk_len)).leading_ones() as usize;
+        if len < chunk_len {
+            return s.len() - end + len;
+        }
+        end = start;
+    }
+    s.len()
+}
+
+/// Copies the bytes marked in `keep` (bit per byte) of the first `len` (up to 64) bytes
+/// to the beginning of `buf`. Returns the count of the copied bytes.
+#[inline(always)]
+pub(crate) fn compact_64_fb(s: &[u8], mut keep: u64, len: usize, buf: &mut [u8; 72]) -> usize {
+    debug_assert!(len <= 64);
+    let mut n = 0;
+    while keep != 0 {
+        *buf.get_safe_unchecked_mut(n) = *s.get_safe_unchecked(keep.trailing_zeros() as usize);
+        n += 1;
+        keep &= keep - 1;
+    }
+    n
+}
+
+/// Narrows the UTF-16 units to bytes with saturation (so the units above 0xFF are not digits),
+/// up to the length of `buf`. Returns the count of the narrowed units.
+#[inline(always)]
+pub(crate) fn narrow_16_fb(s: &[u16], buf: &mut [u8]) -> usize {
+    let len = s.len().min(buf.len());
+    for (b, &c) in buf.iter_mut().zip(s) {
+        *b = u8::try_from(c).unwrap_or(u8::MAX);
+    }
+    len
+}
+
 /* #[inline(always)]
 pub(crate) fn parse_short_pos<const MAX: u64>(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
     let (val, len) = parse_4(s)?;
     let val = val as u64;
     if val > MAX {
-        return Err(AtoiSimdError::Overflow(s));
+        return Err(AtoiSimdError::overflow(Sign::Pos, s, MAX as u128));
     }
 
     Ok((val, len))
@@ -504,7 +700,7 @@ pub(crate) fn parse_short_neg<const MIN: i64>(s: &[u8]) -> Result<(i64, usize),
     let (val, len) = parse_4(s)?;
     let val = -(val as i64);
     if val < MIN {
-        return Err(AtoiSimdError::Overflow(s));
+        return Err(AtoiSimdError::overflow(Sign::Neg, s, MIN.unsigned_abs() as u128));
     }
 
     Ok((val, len))
@@ -534,4 +730,92 @@ mod test {
             assert_eq!(loaded_len, len, "input: {:X?}", input);
         }
     }
+
+    #[test]
+    fn test_parse_pow2_8() {
+        let data: [(&[u8], u64, u32); 5] = [
+            (b"12345678", 0x1234_5678, 8),
+            (b"aBcDeF09", 0xABCD_EF09, 8),
+            (b"fg", 0xF, 1),
+            (b"@`GgZz/:", 0, 0),
+            (b"9\xFF", 9, 1),
+        ];
+        for (input, val, len) in data {
+            assert_eq!(parse_pow2_8::<16>(input), (val, len), "input: {:X?}", input);
+        }
+
+        let data: [(&[u8], u64, u32); 4] = [
+            (b"12345670", 0o1234_5670, 8),
+            (b"7778", 0o777, 3),
+            (b"8", 0, 0),
+            (b"0/", 0, 1),
+        ];
+        for (input, val, len) in data {
+            assert_eq!(parse_pow2_8::<8>(input), (val, len), "input: {:X?}", input);
+        }
+
+        let data: [(&[u8], u64, u32); 4] = [
+            (b"10110011", 0b1011_0011, 8),
+            (b"1102", 0b110, 3),
+            (b"2", 0, 0),
+            (b"1", 1, 1),
+        ];
+        for (input, val, len) in data {
+            assert_eq!(parse_pow2_8::<2>(input), (val, len), "input: {:X?}", input);
+        }
+    }
+
+    #[test]
+    fn test_separated_masks_fb() {
+        let data: [(&[u8], u64, u64); 5] = [
+            (b"1_000_000", 0b1_1101_1101, 0b0_0010_0010),
+            (b"_x9\0_\xFF_", 0b100, 0b101_0001),
+            (b"", 0, 0),
+            (b"12345678901234567890", 0xF_FFFF, 0),
+            (
+                b"___________________________________________________________________1",
+                0,
+                u64::MAX,
+            ),
+        ];
+        for (input, digits, seps) in data {
+            assert_eq!(
+                separated_masks_fb(input, b'_'),
+                (digits, seps),
+                "input: {:X?}",
+                input
+            );
+            let mut buf = [0; 72];
+            let len = input.len().min(64);
+            let n = compact_64_fb(input, digits, len, &mut buf);
+            assert_eq!(n, digits.count_ones() as usize);
+            assert!(buf[..n].iter().all(u8::is_ascii_digit));
+        }
+    }
+
+    #[test]
+    fn test_narrow_16_fb() {
+        let mut buf = [0; 64];
+        let input = [b'1' as u16, 0x0131, 0xFF11, 0x8030, 0xFF, b'-' as u16];
+        assert_eq!(narrow_16_fb(&input, &mut buf), 6);
+        assert_eq!(buf[..6], [b'1', 0xFF, 0xFF, 0xFF, 0xFF, b'-']);
+        assert_eq!(narrow_16_fb(&[b'9' as u16; 100], &mut buf), 64);
+        assert!(buf.iter().all(|&c| c == b'9'));
+    }
+
+    #[test]
+    fn test_whitespace_len_fb() {
+        let data: [(&[u8], usize, usize); 6] = [
+            (b"", 0, 0),
+            (b" \t\r\n\x0C", 5, 5),
+            (b"  -42\r\n", 2, 2),
+            (b"\x0B1\0", 0, 0),
+            (b"          1234567890          ", 10, 10),
+            (b"1\xA0 \x8D", 0, 0),
+        ];
+        for (input, front, back) in data {
+            assert_eq!(whitespace_len_fb(input), front, "input: {:X?}", input);
+            assert_eq!(whitespace_len_back_fb(input), back, "input: {:X?}", input);
+        }
+    }
 }
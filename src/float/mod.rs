@@ -0,0 +1,380 @@
+use crate::{
+    linker::{len_sign, ParsePos},
+    AtoiSimdError,
+};
+use core::{ops::Neg, str::FromStr};
+use debug_unsafe::slice::SliceGetter;
+use table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+
+mod table;
+
+const POW10_U64: [u64; 20] = {
+    let mut pow = [1; 20];
+    let mut i = 1;
+    while i < pow.len() {
+        pow[i] = pow[i - 1] * 10;
+        i += 1;
+    }
+    pow
+};
+
+/// The binary format of a float for the Eisel–Lemire algorithm
+struct Binary {
+    /// Explicit bits of the mantissa
+    mantissa_bits: i32,
+    /// The exponent of the smallest normal value minus 1 (without the bias)
+    min_exponent: i32,
+    /// The biased exponent of the infinity
+    infinite_power: i32,
+    /// The values below `10^smallest_power_of_ten` are zero (for 64-bit mantissas)
+    smallest_power_of_ten: i64,
+    /// The values above `10^largest_power_of_ten` are infinite
+    largest_power_of_ten: i64,
+    /// The range of the exponents where the value can be halfway between two floats
+    min_exponent_round_to_even: i64,
+    max_exponent_round_to_even: i64,
+}
+
+const F32: Binary = Binary {
+    mantissa_bits: 23,
+    min_exponent: -127,
+    infinite_power: 0xFF,
+    smallest_power_of_ten: -65,
+    largest_power_of_ten: 38,
+    min_exponent_round_to_even: -17,
+    max_exponent_round_to_even: 10,
+};
+
+const F64: Binary = Binary {
+    mantissa_bits: 52,
+    min_exponent: -1023,
+    infinite_power: 0x7FF,
+    smallest_power_of_ten: -342,
+    largest_power_of_ten: 308,
+    min_exponent_round_to_even: -4,
+    max_exponent_round_to_even: 23,
+};
+
+/// `floor(log2(10^q)) + 63`
+#[inline(always)]
+fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+/// The high 128 bits of `w * 5^q` (low and high 64 bits), only the first `precision` bits are exact
+#[inline(always)]
+fn compute_product_approx(q: i64, w: u64, precision: i32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+    let (hi5, lo5) = POWER_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+    let first = w as u128 * hi5 as u128;
+    let (mut first_lo, mut first_hi) = (first as u64, (first >> 64) as u64);
+    if first_hi & mask == mask {
+        // the truncated bits can carry into the exact ones
+        let second_hi = ((w as u128 * lo5 as u128) >> 64) as u64;
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}
+
+/// The Eisel–Lemire algorithm, the bits of the correctly rounded float of `w * 10^q`,
+/// `None` if the product isn't precise enough to round it
+#[inline(always)]
+fn eisel_lemire(w: u64, q: i64, binary: &Binary) -> Option<u64> {
+    let bits = binary.mantissa_bits;
+    if w == 0 || q < binary.smallest_power_of_ten {
+        return Some(0);
+    }
+    if q > binary.largest_power_of_ten {
+        return Some((binary.infinite_power as u64) << bits);
+    }
+    debug_assert!((SMALLEST_POWER_OF_FIVE..=LARGEST_POWER_OF_FIVE).contains(&q));
+
+    // normalized, so the high bit is set
+    let lz = w.leading_zeros();
+    let w = w << lz;
+    let (lo, hi) = compute_product_approx(q, w, bits + 3);
+    // the product is exact with `5^q` in 64 bits, otherwise it can be off by one in the last bit
+    if lo == u64::MAX && !(-27..=55).contains(&q) {
+        return None;
+    }
+    let upper_bit = (hi >> 63) as i32;
+    let shift = upper_bit + 64 - bits - 3;
+    let mut mantissa = hi >> shift;
+    let mut power2 = power(q as i32) + upper_bit - lz as i32 - binary.min_exponent;
+    if power2 <= 0 {
+        // more than 64 bits below the smallest subnormal value
+        if -power2 + 1 >= 64 {
+            return Some(0);
+        }
+        // subnormal, its exponent is 1 if it's rounded up to the smallest normal value
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        power2 = (mantissa >= 1 << bits) as i32;
+        return Some(mantissa | (power2 as u64) << bits);
+    }
+    // exactly halfway between two floats, rounds to even
+    if lo <= 1
+        && (binary.min_exponent_round_to_even..=binary.max_exponent_round_to_even).contains(&q)
+        && mantissa & 3 == 1
+        && mantissa << shift == hi
+    {
+        mantissa &= !1;
+    }
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    // rounded up to the next power of two
+    if mantissa >= 2 << bits {
+        mantissa = 1 << bits;
+        power2 += 1;
+    }
+    mantissa &= !(1 << bits);
+    if power2 >= binary.infinite_power {
+        return Some((binary.infinite_power as u64) << bits);
+    }
+    Some(mantissa | (power2 as u64) << bits)
+}
+
+/// Floating-point types for `parse_float`
+pub trait ParseFloat: Copy + FromStr + Neg<Output = Self> {
+    /// The correctly rounded value of `mantissa * 10^exp`: Clinger's fast path if it's exact,
+    /// then the Eisel–Lemire algorithm. `None` if it can't be rounded without more digits.
+    #[doc(hidden)]
+    fn atoi_simd_fast(mantissa: u64, exp: i64) -> Option<Self>;
+}
+
+macro_rules! parse_float_impl {
+    ($($t:ty, $mantissa_bits:literal, $binary:ident, [$($pow:literal),*];)*) => {$(
+        impl ParseFloat for $t {
+            #[inline(always)]
+            fn atoi_simd_fast(mantissa: u64, exp: i64) -> Option<Self> {
+                const MAX_MANTISSA: u64 = 1 << $mantissa_bits;
+                const POW10: &[$t] = &[$($pow),*];
+                const MAX_EXACT: i64 = POW10.len() as i64 - 1;
+
+                /// Clinger's fast path, the value if both of the mantissa and the power of ten are exact
+                #[inline(always)]
+                fn clinger(mantissa: u64, exp: i64) -> Option<$t> {
+                    // x87 rounds to the extended precision first
+                    if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) || mantissa > MAX_MANTISSA {
+                        return None;
+                    }
+                    if mantissa == 0 {
+                        return Some(0.0);
+                    }
+                    if (0..=MAX_EXACT).contains(&exp) {
+                        Some(mantissa as $t * POW10[exp as usize])
+                    } else if (-MAX_EXACT..0).contains(&exp) {
+                        Some(mantissa as $t / POW10[-exp as usize])
+                    } else if exp > MAX_EXACT && exp - MAX_EXACT < POW10_U64.len() as i64 {
+                        // "disguised" fast path, the mantissa takes the extra zeroes
+                        let mantissa = mantissa.checked_mul(POW10_U64[(exp - MAX_EXACT) as usize])?;
+                        if mantissa > MAX_MANTISSA {
+                            return None;
+                        }
+                        Some(mantissa as $t * POW10[MAX_EXACT as usize])
+                    } else {
+                        None
+                    }
+                }
+
+                clinger(mantissa, exp).or_else(|| {
+                    eisel_lemire(mantissa, exp, &$binary).map(|bits| <$t>::from_bits(bits as _))
+                })
+            }
+        }
+    )*};
+}
+
+parse_float_impl!(
+    f32, 24, F32, [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+    f64, 53, F64, [
+        1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+        1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22
+    ];
+);
+
+#[inline(always)]
+fn is_digit_at(s: &[u8], i: usize) -> bool {
+    s.get(i).map_or(false, u8::is_ascii_digit)
+}
+
+/// Parses the digits with the `u64` parser, `None` if they don't fit in it
+#[inline(always)]
+fn parse_digits(s: &[u8]) -> Option<(u64, usize)> {
+    match u64::atoi_simd_parse_prefix_pos::<true>(s) {
+        // the parser stops at its max length
+        Ok((_, len)) if is_digit_at(s, len) => None,
+        Ok(v) => Some(v),
+        Err(AtoiSimdError::Empty) => Some((0, 0)),
+        Err(_) => None,
+    }
+}
+
+/// Fast path for the numbers with a mantissa which fits in `u64` (up to 19 significant digits),
+/// converted with `ParseFloat::atoi_simd_fast`.
+/// Returns the value and the length of the number (without the sign),
+/// `None` for any other number or an error.
+#[inline(always)]
+fn parse_fast<T: ParseFloat>(s: &[u8]) -> Option<(T, usize)> {
+    let (int, int_len) = parse_digits(s)?;
+    let mut len = int_len;
+    let (mut mantissa, mut exp) = (int, 0);
+    if s.get(len) == Some(&b'.') {
+        let (frac, frac_len) = parse_digits(s.get_safe_unchecked(len + 1..))?;
+        if int_len + frac_len == 0 {
+            return None;
+        }
+        mantissa = int
+            .checked_mul(*POW10_U64.get(frac_len)?)?
+            .checked_add(frac)?;
+        exp = -(frac_len as i64);
+        len += 1 + frac_len;
+    } else if int_len == 0 {
+        return None;
+    }
+    if let Some(b'e' | b'E') = s.get(len) {
+        let (neg, sign) = match s.get(len + 1) {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let (e, e_len) = parse_digits(s.get(len + 1 + sign..)?)?;
+        if e_len == 0 || e > i32::MAX as u64 {
+            return None;
+        }
+        exp += if neg { -(e as i64) } else { e as i64 };
+        len += 1 + sign + e_len;
+    }
+    T::atoi_simd_fast(mantissa, exp).map(|v| (v, len))
+}
+
+/// Case-insensitive `prefix` at the beginning of `s`
+#[inline(always)]
+fn starts_with_ignore_case(s: &[u8], prefix: &[u8]) -> bool {
+    s.get(..prefix.len())
+        .map_or(false, |s| s.eq_ignore_ascii_case(prefix))
+}
+
+/// Returns the length of the number (without the sign) in the `str::parse::<f64>` syntax:
+/// `digits[.digits][(e|E)[sign]digits]` (the integer or the fractional digits can be omitted, but not both),
+/// and `inf`, `infinity` or `nan` (case-insensitive) with INF_NAN.
+/// Also returns the saturated integer part for the errors.
+#[inline(always)]
+fn scan<const INF_NAN: bool>(s: &[u8]) -> (usize, u64) {
+    if INF_NAN {
+        for special in [&b"infinity"[..], b"inf", b"nan"] {
+            if starts_with_ignore_case(s, special) {
+                return (special.len(), 0);
+            }
+        }
+    }
+    let digits = |from: usize| {
+        s.get(from..)
+            .map_or(0, |s| s.iter().take_while(|c| c.is_ascii_digit()).count())
+    };
+    let int_len = digits(0);
+    let int = s.get_safe_unchecked(..int_len).iter().fold(0_u64, |v, &c| {
+        v.saturating_mul(10).saturating_add((c & 0xF) as u64)
+    });
+    let mut len = int_len;
+    if s.get(len) == Some(&b'.') {
+        let frac_len = digits(len + 1);
+        if int_len + frac_len == 0 {
+            return (0, 0);
+        }
+        len += 1 + frac_len;
+    } else if int_len == 0 {
+        return (0, 0);
+    }
+    if let Some(b'e' | b'E') = s.get(len) {
+        let sign = matches!(s.get(len + 1), Some(b'-' | b'+')) as usize;
+        let e_len = digits(len + 1 + sign);
+        if e_len != 0 {
+            len += 1 + sign + e_len;
+        }
+    }
+    (len, int)
+}
+
+/// Parses the number with `str::parse` after checking its syntax.
+/// Errors are relative to the whole input.
+#[cold]
+fn parse_slow<T: ParseFloat, const INF_NAN: bool>(
+    s: &[u8],
+    sign: usize,
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let (len, int) = scan::<INF_NAN>(s.get_safe_unchecked(sign..));
+    if len == 0 {
+        return Err(AtoiSimdError::Empty);
+    }
+    let len = sign + len;
+    if !prefix && len != s.len() {
+        return Err(AtoiSimdError::Invalid64(int, len, s));
+    }
+    // the number is ASCII, and the syntax is checked
+    core::str::from_utf8(s.get_safe_unchecked(..len))
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(|v| (v, len))
+        .ok_or(AtoiSimdError::Invalid64(int, 0, s))
+}
+
+#[inline(always)]
+fn parse_float_impl<T: ParseFloat, const SKIP_PLUS: bool, const INF_NAN: bool>(
+    s: &[u8],
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, true)?;
+    match parse_fast::<T>(s.get_safe_unchecked(sign..)) {
+        Some((v, len)) if prefix || sign + len == s.len() => {
+            Ok((if neg { -v } else { v }, sign + len))
+        }
+        _ => parse_slow::<T, INF_NAN>(s, sign, prefix),
+    }
+}
+
+#[inline(always)]
+pub(crate) fn parse_float<T: ParseFloat, const SKIP_PLUS: bool, const INF_NAN: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    parse_float_impl::<T, SKIP_PLUS, INF_NAN>(s, false).map(|(v, _)| v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_float<T: ParseFloat, const SKIP_PLUS: bool, const INF_NAN: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_float_impl::<T, SKIP_PLUS, INF_NAN>(s, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        for (s, len) in [
+            ("1", 1),
+            ("1.", 2),
+            (".1", 2),
+            (".", 0),
+            ("e1", 0),
+            ("1e", 1),
+            ("1e+", 1),
+            ("1e+1", 4),
+            ("1.5E-10x", 7),
+            ("InFiNiTy", 8),
+            ("infinit", 3),
+            ("NaN1", 3),
+        ] {
+            assert_eq!(scan::<true>(s.as_bytes()).0, len, "input: {}", s);
+        }
+        assert_eq!(scan::<false>(b"inf").0, 0);
+        assert_eq!(scan::<false>(b"99999999999999999999999.5").1, u64::MAX);
+    }
+}
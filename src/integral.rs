@@ -0,0 +1,130 @@
+use crate::{
+    linker::{len_sign, ParseOverflow, ParsePos},
+    AtoiSimdError, Sign,
+};
+use debug_unsafe::slice::SliceGetter;
+
+#[inline(always)]
+fn digits_len(s: &[u8]) -> usize {
+    s.iter().take_while(|c| c.is_ascii_digit()).count()
+}
+
+/// The overflow of the number, at its end (`len`), because of the exponent
+#[cold]
+fn overflow(s: &[u8], len: usize, neg: bool) -> AtoiSimdError<'_> {
+    let sign = if neg { Sign::Neg } else { Sign::Pos };
+    AtoiSimdError::Overflow(sign, len, s)
+}
+
+#[inline(always)]
+fn trailing_zeroes(s: &[u8]) -> usize {
+    s.iter().rev().take_while(|&&c| c == b'0').count()
+}
+
+/// Parses the digits with the `u128` parser (any amount of zeroes at the beginning),
+/// `None` if they don't fit in it
+#[inline(always)]
+fn parse_digits(s: &[u8]) -> Option<u128> {
+    if s.is_empty() {
+        return Some(0);
+    }
+    u128::atoi_simd_parse_pos::<true>(s).ok()
+}
+
+/// Parses `[sign]digits[.digits][(e|E)[sign]digits]` (the integer or the fractional digits can be omitted, but not both),
+/// if the value is integral.
+/// Returns the value and the length of the number (with the sign).
+/// Errors are relative to the whole input.
+#[inline(always)]
+fn parse_integral_impl<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, T::MAX_NEG != 0)?;
+    let max = if neg { T::MAX_NEG } else { T::MAX_POS };
+    let rest = s.get_safe_unchecked(sign..);
+
+    let int = rest.get_safe_unchecked(..digits_len(rest));
+    let mut len = int.len();
+    let frac = match rest.get(len..) {
+        Some([b'.', frac @ ..]) => frac.get_safe_unchecked(..digits_len(frac)),
+        _ => &[],
+    };
+    if rest.get(len) == Some(&b'.') && int.len() + frac.len() != 0 {
+        len += 1 + frac.len();
+    } else if int.is_empty() {
+        return Err(AtoiSimdError::Empty);
+    }
+    let mut exp: i64 = 0;
+    if let Some(b'e' | b'E') = rest.get(len) {
+        let (exp_neg, exp_sign) = match rest.get(len + 1) {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let digits = rest.get_safe_unchecked(len + 1 + exp_sign..);
+        let exp_len = digits_len(digits);
+        if exp_len != 0 {
+            // a huge exponent is an overflow or a fraction anyway
+            let e = u32::atoi_simd_parse_pos::<true>(digits.get_safe_unchecked(..exp_len))
+                .unwrap_or(u32::MAX) as i64;
+            exp = if exp_neg { -e } else { e };
+            len += 1 + exp_sign + exp_len;
+        }
+    }
+    let len = sign + len;
+    if !prefix && len != s.len() {
+        let v = parse_digits(int).unwrap_or(u128::MAX).min(max);
+        return Err(T::atoi_simd_invalid(v, len, s));
+    }
+
+    // the significant digits without the trailing zeroes, and their power of 10
+    let frac = frac.get_safe_unchecked(..frac.len() - trailing_zeroes(frac));
+    let (int, shift) = if frac.is_empty() {
+        let zeroes = trailing_zeroes(int);
+        (
+            int.get_safe_unchecked(..int.len() - zeroes),
+            exp + zeroes as i64,
+        )
+    } else {
+        (int, exp - frac.len() as i64)
+    };
+    if shift < 0 && !(int.is_empty() && frac.is_empty()) {
+        return Err(AtoiSimdError::NotIntegral(s));
+    }
+    let mut mag = parse_digits(int).ok_or_else(|| overflow(s, len, neg))?;
+    if mag == 0 {
+        // the zeroes at the beginning of the fractional digits aren't significant
+        mag = parse_digits(frac).ok_or_else(|| overflow(s, len, neg))?;
+    } else if !frac.is_empty() {
+        mag = 10_u128
+            .checked_pow(frac.len() as u32)
+            .and_then(|pow| mag.checked_mul(pow))
+            .zip(parse_digits(frac))
+            .and_then(|(mag, frac)| mag.checked_add(frac))
+            .ok_or_else(|| overflow(s, len, neg))?;
+    }
+    if mag != 0 {
+        mag = u32::try_from(shift)
+            .ok()
+            .and_then(|shift| 10_u128.checked_pow(shift))
+            .and_then(|pow| mag.checked_mul(pow))
+            .filter(|&mag| mag <= max)
+            .ok_or_else(|| overflow(s, len, neg))?;
+    }
+    Ok((T::atoi_simd_from_magnitude(mag, neg), len))
+}
+
+#[inline(always)]
+pub(crate) fn parse_integral<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    parse_integral_impl::<T, SKIP_PLUS>(s, false).map(|(v, _)| v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_integral<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_integral_impl::<T, SKIP_PLUS>(s, true)
+}
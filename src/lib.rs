@@ -5,7 +5,9 @@
 //!
 //! Supports negative values and validates the input.
 //!
-//! Supported output types: u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize.
+//! Supported output types: u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, `U256` and `I256` (up to 78 digits).
+//!
+//! Also their `core::num::NonZero*` variants (zero is `AtoiSimdError::Zero`), `Wrapping` and `Saturating`.
 //!
 //! Has good test coverage, and can be considered safe.
 //!
@@ -18,9 +20,13 @@
 //!
 //! -   `RUSTFLAGS="-C target-cpu=native"` will optimize for your current cpu.
 //!
+//! Or enable the `runtime-dispatch` feature (x86/x86_64, requires `std`) to detect SSE4.1/AVX2
+//! at runtime, so a binary built for the baseline cpu still uses SIMD where it's available.
+//!
 //! If you have `&str` then use `.as_bytes()`
 //!
-//! Supports `no_std` with `--no-default-features`
+//! Supports `no_std` with `--no-default-features`, the `alloc` feature enables `parse_big` without `std`
+//! (and `num-bigint` adds the conversion of its result into `num_bigint::BigUint`/`BigInt`).
 //!
 //! # Examples
 //!
@@ -36,11 +42,43 @@
 //! assert_eq!(atoi_simd::parse_neg::<i64, false>(b"2345"), Ok(-2345_i64));
 //!
 //! assert_eq!(atoi_simd::parse_prefix::<u64, false, false>(b"123something_else"), Ok((123_u64, 3)));
+//!
+//! assert_eq!(atoi_simd::parse_hex::<u32, false, false>(b"DeadBeef"), Ok(0xDEAD_BEEF_u32));
+//! assert_eq!(atoi_simd::parse_oct::<u16, false, false>(b"755"), Ok(0o755_u16));
+//! assert_eq!(atoi_simd::parse_bin::<u8, false, false>(b"1010"), Ok(0b1010_u8));
+//! assert_eq!(atoi_simd::parse_radix::<i64, 36, false, false>(b"-Zz"), Ok(-1295_i64));
+//! assert_eq!(atoi_simd::parse_auto::<i32, false, false>(b"-0x1F"), Ok(-0x1F_i32));
+//! assert_eq!(atoi_simd::parse_prefix_auto::<u32, false, false>(b"0b1010 "), Ok((0b1010_u32, 6)));
+//! assert_eq!(atoi_simd::parse_separated::<u64, b'_', true, false, false>(b"1_000_000"), Ok(1_000_000_u64));
+//! assert_eq!(atoi_simd::parse_prefix_separated::<i32, b',', true, false, false>(b"-1,234,567, "), Ok((-1_234_567_i32, 10)));
+//! assert_eq!(atoi_simd::parse_grouped::<u64, b',', false, false>(b"12,34,567", atoi_simd::Grouping::Indian), Ok(1_234_567_u64));
+//! assert_eq!(atoi_simd::parse_utf8_digits::<i32, false, false>("-١٢٣".as_bytes()), Ok(-123_i32));
+//! let units: Vec<u16> = "-1234".encode_utf16().collect();
+//! assert_eq!(atoi_simd::parse_utf16::<i32, false, false>(&units), Ok(-1234_i32));
+//! assert_eq!(atoi_simd::parse_saturating::<u8, false>(b"1000"), Ok(255_u8));
+//! assert_eq!(atoi_simd::parse_wrapping::<i8, false>(b"-129"), Ok(127_i8));
+//! assert_eq!(atoi_simd::parse_bounded::<u16, 1, 12, false, false>(b"13"), Err(atoi_simd::AtoiSimdError::OutOfRange(b"13")));
+//! assert_eq!(atoi_simd::parse::<core::num::NonZeroU64, false, false>(b"0"), Err(atoi_simd::AtoiSimdError::Zero(b"0")));
+//! assert_eq!(atoi_simd::parse::<atoi_simd::U256, false, false>(b"340282366920938463463374607431768211456"), Ok(atoi_simd::U256 { hi: 1, lo: 0 }));
+//! assert_eq!(atoi_simd::parse_decimal::<i64, 4, false>(b"-123.45675", atoi_simd::Rounding::HalfEven), Ok(-1_234_568_i64));
+//! assert_eq!(atoi_simd::parse_float::<f64, false, false>(b"-1.5e3"), Ok(-1500.0_f64));
+//! assert_eq!(atoi_simd::parse_integral::<u32, false>(b"1.5e6"), Ok(1_500_000_u32));
+//! assert_eq!(atoi_simd::parse_trimmed::<i32, false, false>(b"  -42\r\n"), Ok(-42_i32));
+//! assert_eq!(atoi_simd::parse_canonical::<i32, false>(b"01"), Err(atoi_simd::AtoiSimdError::NonCanonical(atoi_simd::CanonicalRule::LeadingZero, b"01")));
+//! assert_eq!(atoi_simd::parse_syntax::<u8>(b"0xFF_u8", atoi_simd::Syntax::Rust), Ok(0xFF_u8));
+//! assert_eq!(atoi_simd::compat::strtol(b"  -0x1Fz", 0).value, -0x1F_i64);
+//! assert_eq!(atoi_simd::parse::<u8, false, false>(b"256").unwrap_err().kind(), atoi_simd::ErrorKind::PosOverflow);
+//! assert_eq!(atoi_simd::parse::<i8, false, false>(b"-1280").unwrap_err().offset(), Some(4));
+//! let opts = atoi_simd::ParseOptions::new().skip_zeroes(true).separator(Some(atoi_simd::Separator::Underscore));
+//! assert_eq!(atoi_simd::parse_with::<u64>(&opts, b"0001_000"), Ok(1000_u64));
 //! ```
 #![allow(clippy::comparison_chain)]
 #![cfg_attr(not(feature = "std"), no_std)]
 // #![feature(stdsimd)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[allow(unused)]
 #[inline(always)]
 #[cold]
@@ -58,6 +96,11 @@ pub(crate) const unsafe fn assert_unchecked(cond: bool) {
 #[rustversion::before(1.81.0)]
 pub(crate) const unsafe fn assert_unchecked(cond: bool) {}
 
+#[cfg(feature = "alloc")]
+mod big;
+mod canonical;
+pub mod compat;
+mod decimal;
 mod error;
 #[cfg(not(any(
     all(target_arch = "aarch64", target_feature = "neon"),
@@ -71,7 +114,17 @@ mod error;
     ),
 )))]
 mod fallback;
+mod float;
+mod integral;
 mod linker;
+mod options;
+mod radix;
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod runtime;
+mod separated;
 mod short;
 #[cfg(any(
     all(target_arch = "aarch64", target_feature = "neon"),
@@ -81,12 +134,37 @@ mod short;
         target_feature = "sse4.1",
         target_feature = "ssse3"
     ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
 ))]
 mod simd;
+mod syntax;
+mod u256;
+mod unicode;
+mod utf16;
+mod whitespace;
 
+#[cfg(feature = "alloc")]
+pub use crate::big::BigLimbs;
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+pub use crate::runtime::{set_simd_level, simd_level, SimdLevel};
+use crate::utf16::Format;
 pub use crate::{
-    error::AtoiSimdError,
-    linker::{Parse, ParseNeg},
+    decimal::Rounding,
+    error::{AtoiSimdError, CanonicalRule, ErrorKind, OwnedError, Sign},
+    float::ParseFloat,
+    linker::{
+        Parse, ParseBounded, ParseNeg, ParseOverflow, ParseRadix, ParseRadixNeg, ParseRadixPos,
+    },
+    options::{ParseOptions, Separator},
+    separated::Grouping,
+    syntax::Syntax,
+    u256::{I256, U256},
 };
 
 /// Parses a slice of digits, and checks for the first '-' char for signed integers.
@@ -124,7 +202,7 @@ pub fn parse_neg<T: ParseNeg, const SKIP_ZEROES: bool>(s: &[u8]) -> Result<T, At
 
 /// Parses a slice of digits until it reaches an invalid character,
 /// and checks for the first '-' char for signed integers.
-/// Returns the parsed value and the parsed size of the slice.
+/// Returns the parsed value and the parsed size of the slice (with the sign, like all of the prefix parsers).
 ///
 /// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than an integer's max length) at the beginning (SKIP_ZEROES),
 /// but it's a bit slower.
@@ -165,6 +243,968 @@ pub fn parse_prefix_neg<T: ParseNeg, const SKIP_ZEROES: bool>(
     T::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(s)
 }
 
+/// Parses a slice of digits like `parse`, but skips the ASCII whitespace (`\t`, `\n`, `\x0C`, `\r` and ' ')
+/// on both sides of it, without copying. The error indices are in the indices of `s`.
+#[inline]
+pub fn parse_trimmed<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_trimmed::<SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits until it reaches an invalid character like `parse_prefix`,
+/// but skips the ASCII whitespace at the beginning of it.
+/// Returns the parsed value and the parsed size of the slice, including the skipped whitespace.
+#[inline]
+pub fn parse_prefix_trimmed<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_trimmed::<SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits in `RADIX` (2..=36, letters are case-insensitive),
+/// and checks for the first '-' char for signed integers.
+/// A drop-in replacement for `from_str_radix`, with SIMD for the radixes 2, 8, 10 and 16.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Even without SKIP_ZEROES it parses zeroes at the beginning,
+/// but up to the length of the 64 (or 128) bits max value.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_radix<
+    T: ParseRadix,
+    const RADIX: u32,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix::<RADIX, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive integer in `RADIX` (2..=36). Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes at the beginning (SKIP_ZEROES), but it's a bit slower.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_pos_radix<T: ParseRadixPos, const RADIX: u32, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_pos::<RADIX, SKIP_ZEROES>(s)
+}
+
+/// Parses a negative integer in `RADIX` (2..=36).
+/// Does not check any signs, so slice must not contain them (including '-' sign).
+///
+/// Can skip extra zeroes at the beginning (SKIP_ZEROES), but it's a bit slower.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_neg_radix<T: ParseRadixNeg, const RADIX: u32, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_neg::<RADIX, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of digits in `RADIX` (2..=36) until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_prefix_radix<
+    T: ParseRadix,
+    const RADIX: u32,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix::<RADIX, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive integer in `RADIX` (2..=36) until it reaches an invalid character.
+/// Returns the parsed value and the parsed size of the slice.
+/// Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes at the beginning (SKIP_ZEROES), but it's a bit slower.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_prefix_pos_radix<T: ParseRadixPos, const RADIX: u32, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix_pos::<RADIX, SKIP_ZEROES>(s)
+}
+
+/// Parses a negative integer in `RADIX` (2..=36) until it reaches an invalid character.
+/// Returns the parsed value and the parsed size of the slice.
+/// Does not check any signs, so slice must not contain them (including '-' sign).
+///
+/// Can skip extra zeroes at the beginning (SKIP_ZEROES), but it's a bit slower.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_prefix_neg_radix<T: ParseRadixNeg, const RADIX: u32, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix_neg::<RADIX, SKIP_ZEROES>(s)
+}
+
+/// Parses an integer with an optional radix prefix (`0x`, `0o` or `0b`, case-insensitive)
+/// like Rust literals, decimal otherwise, and checks for the first '-' char for signed integers.
+/// The prefix goes after the sign, e.g. `-0x1F`. A leading zero without a prefix is still decimal.
+///
+/// Error indices are relative to the whole slice (including the sign and the prefix).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_auto<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_auto::<SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses an integer with an optional radix prefix (`0x`, `0o` or `0b`, case-insensitive)
+/// until it reaches an invalid character, and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign and the prefix).
+///
+/// A prefix without digits after it is not a prefix, so `b"0xg"` is parsed as `0` of size 1.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_auto<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_auto::<SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of hex digits (case-insensitive, without the `0x` prefix),
+/// and checks for the first '-' char for signed integers.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 16 digits, 32 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Even without SKIP_ZEROES it parses zeroes at the beginning, but up to 16 digits (32 for 128 bits).
+#[inline]
+pub fn parse_hex<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix::<16, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive hex integer. Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 16 digits, 32 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_pos_hex<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_pos::<16, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of hex digits until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 16 digits, 32 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_hex<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix::<16, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive hex integer until it reaches an invalid character.
+/// Returns the parsed value and the parsed size of the slice.
+/// Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 16 digits, 32 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_prefix_pos_hex<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix_pos::<16, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of octal digits (without the `0o` prefix),
+/// and checks for the first '-' char for signed integers.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 22 digits, 43 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Even without SKIP_ZEROES it parses zeroes at the beginning, but up to 22 digits (43 for 128 bits).
+#[inline]
+pub fn parse_oct<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix::<8, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive octal integer. Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 22 digits, 43 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_pos_oct<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_pos::<8, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of octal digits until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 22 digits, 43 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_oct<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix::<8, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive octal integer until it reaches an invalid character.
+/// Returns the parsed value and the parsed size of the slice.
+/// Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 22 digits, 43 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_prefix_pos_oct<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix_pos::<8, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of binary digits (without the `0b` prefix),
+/// and checks for the first '-' char for signed integers.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 64 digits, 128 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Even without SKIP_ZEROES it parses zeroes at the beginning, but up to 64 digits (128 for 128 bits).
+#[inline]
+pub fn parse_bin<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix::<2, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive binary integer. Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 64 digits, 128 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_pos_bin<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_pos::<2, SKIP_ZEROES>(s)
+}
+
+/// Parses a slice of binary digits until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than 64 digits, 128 for 128 bits) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_bin<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix::<2, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a positive binary integer until it reaches an invalid character.
+/// Returns the parsed value and the parsed size of the slice.
+/// Does not check any signs, so slice must not contain them.
+///
+/// Can skip extra zeroes (more than 64 digits, 128 for 128 bits) at the beginning (SKIP_ZEROES), but it's a bit slower.
+#[inline]
+pub fn parse_prefix_pos_bin<T: ParseRadixPos, const SKIP_ZEROES: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_radix_pos::<2, SKIP_ZEROES>(s)
+}
+
+/// Parses a decimal integer with digit separators (e.g. `1_000_000` or `1'000'000`),
+/// and checks for the first '-' char for signed integers.
+/// `SEPARATOR` must be a non-zero ASCII char, and not a digit, otherwise it panics.
+///
+/// With STRICT a separator is allowed only between two digits,
+/// so leading, trailing and doubled separators are errors.
+/// Otherwise separators are ignored anywhere after the sign.
+///
+/// Error indices are relative to the whole slice.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower. Without SKIP_ZEROES the number can have up to 64 digits (with the zeroes).
+#[inline]
+pub fn parse_separated<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    separated::parse_separated::<T, SEPARATOR, STRICT, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a decimal integer with digit separators until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign and the separators).
+/// `SEPARATOR` must be a non-zero ASCII char, and not a digit, otherwise it panics.
+///
+/// With STRICT the number ends before a separator that is not between two digits,
+/// so `b"1__2"` is parsed as `1` of size 1.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower. Without SKIP_ZEROES the number can have up to 64 digits (with the zeroes).
+#[inline]
+pub fn parse_prefix_separated<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    separated::parse_prefix_separated::<T, SEPARATOR, STRICT, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a decimal integer with thousands separators (e.g. `1,234,567`, `1.234.567`, `1 234 567`
+/// or Indian `12,34,567`), checks that the separators are in legal positions for the `grouping`,
+/// and checks for the first '-' char for signed integers.
+/// A number without separators is valid for any grouping.
+/// `SEPARATOR` must be a non-zero ASCII char, and not a digit, otherwise it panics.
+///
+/// Returns `AtoiSimdError::Invalid64` (or `Invalid128`) with the index of the first char
+/// where a separator is required or not allowed, and the value of the number before it.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_grouped<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+    grouping: Grouping,
+) -> Result<T, AtoiSimdError<'_>> {
+    separated::parse_grouped::<T, SEPARATOR, SKIP_ZEROES, SKIP_PLUS>(s, grouping)
+}
+
+/// Parses a decimal integer with thousands separators until it reaches an invalid character,
+/// and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice (including the sign and the separators).
+/// `SEPARATOR` must be a non-zero ASCII char, and not a digit, otherwise it panics.
+///
+/// The number ends before a separator that is not between two digits,
+/// but a separator in an illegal position for the `grouping` is still an error.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_grouped<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+    grouping: Grouping,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    separated::parse_prefix_grouped::<T, SEPARATOR, SKIP_ZEROES, SKIP_PLUS>(s, grouping)
+}
+
+/// Parses a slice of Unicode decimal digits (`Nd`, e.g. Arabic-Indic `١٢٣`, Devanagari `१२३`
+/// or full-width `１２３`) in UTF-8, and checks for the first '-' char for signed integers.
+/// All of the digits must be from the same script, ASCII digits are parsed with the SIMD parser.
+///
+/// Error indices are in bytes.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_utf8_digits<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    unicode::parse_utf8_digits::<T, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of Unicode decimal digits (`Nd`) in UTF-8 until it reaches an invalid character
+/// (or a digit from another script), and checks for the first '-' char for signed integers.
+/// Returns the parsed value and the parsed size of the slice in bytes.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+#[inline]
+pub fn parse_prefix_utf8_digits<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    unicode::parse_prefix_utf8_digits::<T, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of UTF-16 code units (e.g. from Windows APIs or JS engines) like `parse`,
+/// narrowing them to bytes with SIMD.
+///
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than an integer's max length) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Without SKIP_ZEROES the numbers longer than 64 code units are too long, unless they are rejected before.
+#[inline]
+pub fn parse_utf16<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses a positive integer from UTF-16 code units like `parse_pos`.
+/// Does not check any signs, so slice must not contain them.
+///
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_pos_utf16<T: Parse, const SKIP_ZEROES: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(s, Format::DECIMAL, T::atoi_simd_parse_pos::<SKIP_ZEROES>)
+}
+
+/// Parses a negative integer from UTF-16 code units like `parse_neg`.
+/// Does not check any signs, so slice must not contain them (including '-' sign).
+///
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_neg_utf16<T: ParseNeg, const SKIP_ZEROES: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(s, Format::DECIMAL, T::atoi_simd_parse_neg::<SKIP_ZEROES>)
+}
+
+/// Parses UTF-16 code units until it reaches an invalid character like `parse_prefix`.
+/// Returns the parsed value and the parsed size of the slice in code units.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (more than an integer's max length) at the beginning (SKIP_ZEROES),
+/// but it's a bit slower.
+/// Without SKIP_ZEROES the numbers longer than 64 code units are too long, unless they are rejected before.
+#[inline]
+pub fn parse_prefix_utf16<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_prefix::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses a positive integer from UTF-16 code units until it reaches an invalid character
+/// like `parse_prefix_pos`. Returns the parsed value and the parsed size of the slice in code units.
+/// Does not check any signs, so slice must not contain them.
+#[inline]
+pub fn parse_prefix_pos_utf16<T: Parse, const SKIP_ZEROES: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>,
+    )
+}
+
+/// Parses a negative integer from UTF-16 code units until it reaches an invalid character
+/// like `parse_prefix_neg`. Returns the parsed value and the parsed size of the slice in code units.
+/// Does not check any signs, so slice must not contain them (including '-' sign).
+#[inline]
+pub fn parse_prefix_neg_utf16<T: ParseNeg, const SKIP_ZEROES: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>,
+    )
+}
+
+/// Parses UTF-16 code units in `RADIX` (2..=36) like `parse_radix`.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_radix_utf16<
+    T: ParseRadix,
+    const RADIX: u32,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::radix::<RADIX, SKIP_ZEROES>(),
+        T::atoi_simd_parse_radix::<RADIX, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units in `RADIX` (2..=36) until it reaches an invalid character like `parse_prefix_radix`.
+/// Returns the parsed value and the parsed size of the slice in code units.
+///
+/// Panics if `RADIX` is not in `2..=36`.
+#[inline]
+pub fn parse_prefix_radix_utf16<
+    T: ParseRadix,
+    const RADIX: u32,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::radix::<RADIX, SKIP_ZEROES>(),
+        T::atoi_simd_parse_prefix_radix::<RADIX, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses hexadecimal UTF-16 code units like `parse_hex`.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_hex_utf16<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    parse_radix_utf16::<T, 16, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses hexadecimal UTF-16 code units until it reaches an invalid character like `parse_prefix_hex`.
+/// Returns the parsed value and the parsed size of the slice in code units.
+#[inline]
+pub fn parse_prefix_hex_utf16<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_prefix_radix_utf16::<T, 16, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses UTF-16 code units with an optional radix prefix (`0x`, `0o` or `0b`) like `parse_auto`.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_auto_utf16<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::auto::<SKIP_ZEROES>(),
+        T::atoi_simd_parse_auto::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units with an optional radix prefix (`0x`, `0o` or `0b`)
+/// until it reaches an invalid character like `parse_prefix_auto`.
+/// Returns the parsed value and the parsed size of the slice in code units.
+#[inline]
+pub fn parse_prefix_auto_utf16<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::auto::<SKIP_ZEROES>(),
+        T::atoi_simd_parse_prefix_auto::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units with digit separators like `parse_separated`.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+///
+/// The numbers longer than 160 code units (without the extra zeroes) are too long.
+#[inline]
+pub fn parse_separated_utf16<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::separated::<SEPARATOR>(),
+        separated::parse_separated::<T, SEPARATOR, STRICT, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units with digit separators until it reaches an invalid character
+/// like `parse_prefix_separated`. Returns the parsed value and the parsed size of the slice in code units.
+///
+/// The numbers longer than 160 code units (without the extra zeroes) are too long.
+#[inline]
+pub fn parse_prefix_separated_utf16<
+    T: ParseRadix,
+    const SEPARATOR: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::separated::<SEPARATOR>(),
+        separated::parse_prefix_separated::<T, SEPARATOR, STRICT, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units like `parse_trimmed`, skipping the ASCII whitespace on both sides of them.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_trimmed_utf16<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_trimmed_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units until it reaches an invalid character like `parse_prefix_trimmed`,
+/// skipping the ASCII whitespace at the beginning of them.
+/// Returns the parsed value and the parsed size of the slice in code units, including the skipped whitespace.
+#[inline]
+pub fn parse_prefix_trimmed_utf16<T: Parse, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_trimmed_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_prefix::<SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units like `parse_bounded`, checking that the value is in the `MIN..=MAX` range.
+/// Error indices are in code units, the error has no input (`OwnedError::from_utf16` adds a snippet of the units).
+#[inline]
+pub fn parse_bounded_utf16<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<T, AtoiSimdError<'_>> {
+    utf16::parse_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_bounded::<MIN, MAX, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses UTF-16 code units until it reaches an invalid character like `parse_prefix_bounded`,
+/// checking that the value is in the `MIN..=MAX` range.
+/// Returns the parsed value and the parsed size of the slice in code units.
+#[inline]
+pub fn parse_prefix_bounded_utf16<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u16],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    utf16::parse_prefix_utf16::<_, SKIP_ZEROES>(
+        s,
+        Format::DECIMAL,
+        T::atoi_simd_parse_prefix_bounded::<MIN, MAX, SKIP_ZEROES, SKIP_PLUS>,
+    )
+}
+
+/// Parses a slice of digits like `parse`, but clamps the value to `T::MIN`/`T::MAX` on overflow,
+/// and checks for the first '-' char for signed integers.
+/// Parses the inputs of any length (extra zeroes and digits).
+///
+/// `core::num::Saturating<T>` implements `Parse` with this policy.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_saturating<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_saturating::<SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits until it reaches an invalid character like `parse_prefix`,
+/// but clamps the value to `T::MIN`/`T::MAX` on overflow.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_prefix_saturating<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_saturating::<SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits like `parse`, but wraps the value around (modulo 2^N) on overflow,
+/// and checks for the first '-' char for signed integers.
+/// Parses the inputs of any length (extra zeroes and digits).
+///
+/// `core::num::Wrapping<T>` implements `Parse` with this policy.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_wrapping<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_wrapping::<SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits until it reaches an invalid character like `parse_prefix`,
+/// but wraps the value around (modulo 2^N) on overflow.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_prefix_wrapping<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_wrapping::<SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits like `parse`, and checks that the value is in the `MIN..=MAX` range.
+/// Returns `AtoiSimdError::OutOfRange` for the values out of it, including the overflow of `T`.
+/// It stops at the digit which is out of range, so the chars after it aren't checked.
+///
+/// The bounds are `i128`, so `u128` values above `i128::MAX` are always out of range.
+/// `MIN <= MAX` must be in the range of `T`, otherwise it fails to compile:
+///
+/// ```compile_fail
+/// let _ = atoi_simd::parse_bounded::<u8, 0, 256, false, false>(b"1");
+/// ```
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (SKIP_ZEROES) like `parse`.
+#[inline]
+pub fn parse_bounded<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_bounded::<MIN, MAX, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a slice of digits until it reaches an invalid character like `parse_prefix`,
+/// and checks that the value is in the `MIN..=MAX` range.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS) and extra zeroes (SKIP_ZEROES) like `parse_prefix`.
+#[inline]
+pub fn parse_prefix_bounded<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    T::atoi_simd_parse_prefix_bounded::<MIN, MAX, SKIP_ZEROES, SKIP_PLUS>(s)
+}
+
+/// Parses a decimal integer of any length into little-endian `u64` limbs,
+/// and checks for the first '-' char.
+///
+/// With the `num-bigint` feature `BigLimbs` converts into `num_bigint::BigUint`/`BigInt`.
+/// Error indices are in the whole input (with the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS).
+///
+/// ```
+/// let big = atoi_simd::parse_big::<false>(b"-340282366920938463463374607431768211456").unwrap();
+/// assert_eq!(big, atoi_simd::BigLimbs { neg: true, limbs: vec![0, 0, 1] });
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn parse_big<const SKIP_PLUS: bool>(s: &[u8]) -> Result<BigLimbs, AtoiSimdError<'_>> {
+    big::parse_big::<SKIP_PLUS>(s)
+}
+
+/// Parses a decimal integer of any length into little-endian `u64` limbs
+/// until it reaches an invalid character like `parse_prefix`.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn parse_prefix_big<const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(BigLimbs, usize), AtoiSimdError<'_>> {
+    big::parse_prefix_big::<SKIP_PLUS>(s)
+}
+
+/// Parses a fixed-point decimal number (like `-123.4567`) into an integer scaled by `10^SCALE`,
+/// and checks for the first '-' char for signed integers.
+/// The integer or the fractional part can be omitted (like `12`, `12.` or `.5`), but not both.
+/// Fractional digits beyond `SCALE` are handled by the `rounding` policy,
+/// and `AtoiSimdError::Overflow` is returned if the scaled value doesn't fit in `T`.
+/// Error indices are in the whole input (with the sign).
+///
+/// `SCALE` must be less than or equal to 38.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_decimal<T: ParseOverflow, const SCALE: u32, const SKIP_PLUS: bool>(
+    s: &[u8],
+    rounding: Rounding,
+) -> Result<T, AtoiSimdError<'_>> {
+    decimal::parse_decimal::<T, SCALE, SKIP_PLUS>(s, rounding)
+}
+
+/// Parses a fixed-point decimal number until it reaches an invalid character like `parse_prefix`,
+/// scaled by `10^SCALE` like `parse_decimal`.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_prefix_decimal<T: ParseOverflow, const SCALE: u32, const SKIP_PLUS: bool>(
+    s: &[u8],
+    rounding: Rounding,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    decimal::parse_prefix_decimal::<T, SCALE, SKIP_PLUS>(s, rounding)
+}
+
+/// Parses a floating-point number (`f32` or `f64`) like `str::parse` (with the same result):
+/// `[-]digits[.digits][(e|E)[sign]digits]`, the integer or the fractional digits can be omitted, but not both.
+/// Numbers with up to 19 significant digits are parsed with the integer parser and converted
+/// with Clinger's fast path or the Eisel–Lemire algorithm, others with `str::parse`.
+/// Error indices are in the whole input (with the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS), and parse `inf`, `infinity` and `nan` (case-insensitive) with INF_NAN.
+#[inline]
+pub fn parse_float<T: ParseFloat, const SKIP_PLUS: bool, const INF_NAN: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    float::parse_float::<T, SKIP_PLUS, INF_NAN>(s)
+}
+
+/// Parses a floating-point number until it reaches an invalid character like `parse_prefix`,
+/// with the same syntax and result as `parse_float`.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS), and parse `inf`, `infinity` and `nan` (case-insensitive) with INF_NAN.
+#[inline]
+pub fn parse_prefix_float<T: ParseFloat, const SKIP_PLUS: bool, const INF_NAN: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    float::parse_prefix_float::<T, SKIP_PLUS, INF_NAN>(s)
+}
+
+/// Parses an integer in the decimal or scientific notation (like `42.0` or `1e6`),
+/// and checks for the first '-' char for signed integers.
+/// The syntax is the same as `parse_float`, but the value must be integral and fit in `T`,
+/// otherwise it's `AtoiSimdError::NotIntegral` or `AtoiSimdError::Overflow`.
+/// Error indices are in the whole input (with the sign).
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_integral<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    integral::parse_integral::<T, SKIP_PLUS>(s)
+}
+
+/// Parses an integer in the decimal or scientific notation until it reaches an invalid character
+/// like `parse_prefix`, with the same syntax and checks as `parse_integral`.
+/// Returns the parsed value and the parsed size of the slice.
+///
+/// Can skip the '+' char (SKIP_PLUS).
+#[inline]
+pub fn parse_prefix_integral<T: ParseOverflow, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    integral::parse_prefix_integral::<T, SKIP_PLUS>(s)
+}
+
+/// Parses an integer literal in the grammar of a language or a config format (see `Syntax`),
+/// and checks for the first '-' char for signed integers.
+/// Zeroes at the beginning that aren't allowed are reported as `AtoiSimdError::NonCanonical`.
+#[inline]
+pub fn parse_syntax<T: ParseRadix>(s: &[u8], syntax: Syntax) -> Result<T, AtoiSimdError<'_>> {
+    syntax::parse_syntax::<T>(s, syntax)
+}
+
+/// Parses an integer literal in the grammar of a language or a config format until it reaches an invalid character,
+/// like `parse_prefix`, with the same checks as `parse_syntax`.
+/// Returns the parsed value and the parsed size of the slice (with the prefix and the suffix).
+#[inline]
+pub fn parse_prefix_syntax<T: ParseRadix>(
+    s: &[u8],
+    syntax: Syntax,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    syntax::parse_prefix_syntax::<T>(s, syntax)
+}
+
+/// Parses a slice of digits in the canonical decimal form (like JSON integers),
+/// and checks for the first '-' char for signed integers.
+/// Rejects the '+' sign, zeroes at the beginning (unless the value is zero)
+/// and "-0" (unless ALLOW_NEG_ZERO) with `AtoiSimdError::NonCanonical` and the violated rule.
+#[inline]
+pub fn parse_canonical<T: Parse, const ALLOW_NEG_ZERO: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    canonical::parse_canonical::<T, ALLOW_NEG_ZERO>(s)
+}
+
+/// Parses a slice of digits in the canonical decimal form until it reaches an invalid character
+/// like `parse_prefix`, with the same checks as `parse_canonical`.
+/// Returns the parsed value and the parsed size of the slice.
+#[inline]
+pub fn parse_prefix_canonical<T: Parse, const ALLOW_NEG_ZERO: bool>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    canonical::parse_prefix_canonical::<T, ALLOW_NEG_ZERO>(s)
+}
+
+/// Parses a slice of digits like `parse`, with the options set at runtime (see `ParseOptions`),
+/// and checks for the first '-' char for signed integers.
+/// The options select one of the const generic functions: `parse`, `parse_trimmed` or `parse_separated`
+/// (trimmed like `parse_trimmed` with the `trim` option).
+#[inline]
+pub fn parse_with<'a, T: Parse + ParseRadix>(
+    opts: &ParseOptions,
+    s: &'a [u8],
+) -> Result<T, AtoiSimdError<'a>> {
+    options::parse_with::<T>(opts, s)
+}
+
+/// Parses a slice of digits until it reaches an invalid character like `parse_prefix`,
+/// with the options set at runtime (see `ParseOptions`).
+/// Returns the parsed value and the parsed size of the slice, including the skipped whitespace.
+#[inline]
+pub fn parse_prefix_with<'a, T: Parse + ParseRadix>(
+    opts: &ParseOptions,
+    s: &'a [u8],
+) -> Result<(T, usize), AtoiSimdError<'a>> {
+    options::parse_prefix_with::<T>(opts, s)
+}
+
 #[deprecated(since = "0.18.0", note = "Use `parse::<_, true, true>` instead")]
 #[inline]
 pub fn parse_skipped<T: Parse>(s: &[u8]) -> Result<T, AtoiSimdError<'_>> {
@@ -0,0 +1,210 @@
+use super::{len_sign, Parse};
+use crate::{short::parse_short_bounded, AtoiSimdError};
+use core::marker::PhantomData;
+use debug_unsafe::slice::SliceGetter;
+
+/// Parsing with the `MIN..=MAX` range check, the values out of it (including the overflow of the type)
+/// are `AtoiSimdError::OutOfRange`. The digits are parsed only until the one which is out of range.
+///
+/// The bounds are `i128`, so `u128` values above `i128::MAX` can't be in the range.
+/// `MIN <= MAX` must be in the range of the type, otherwise it fails to compile.
+///
+/// Note: all of the provided methods are `#[inline(always)]`
+pub trait ParseBounded: Parse {
+    /// Min value of the type
+    #[doc(hidden)]
+    const ATOI_SIMD_MIN: i128;
+    /// Max value of the type (`i128::MAX` for `u128`)
+    #[doc(hidden)]
+    const ATOI_SIMD_MAX: i128;
+    /// Max count of the digits
+    #[doc(hidden)]
+    const ATOI_SIMD_DIGITS: usize;
+
+    #[doc(hidden)]
+    fn atoi_simd_in_range(&self, min: i128, max: i128) -> bool;
+
+    /// Converts the magnitude (in the range of the type) to the value
+    #[doc(hidden)]
+    fn atoi_simd_from_bounded(mag: u128, neg: bool) -> Self;
+
+    #[inline(always)]
+    fn atoi_simd_parse_bounded<
+        const MIN: i128,
+        const MAX: i128,
+        const SKIP_ZEROES: bool,
+        const SKIP_PLUS: bool,
+    >(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        let () = Bounds::<Self, MIN, MAX>::CHECK;
+        match parse_bounded::<Self, MIN, MAX, SKIP_PLUS>(s) {
+            Some(Ok((v, len))) if len == s.len() => {
+                if v.atoi_simd_in_range(MIN, MAX) {
+                    Ok(v)
+                } else {
+                    Err(AtoiSimdError::OutOfRange(s))
+                }
+            }
+            Some(Err(e)) => Err(e),
+            // the exact error of the decimal parser
+            _ => parse_slow::<Self, MIN, MAX, SKIP_ZEROES, SKIP_PLUS>(s),
+        }
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_bounded<
+        const MIN: i128,
+        const MAX: i128,
+        const SKIP_ZEROES: bool,
+        const SKIP_PLUS: bool,
+    >(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        let () = Bounds::<Self, MIN, MAX>::CHECK;
+        match parse_bounded::<Self, MIN, MAX, SKIP_PLUS>(s) {
+            Some(Ok((v, len))) => {
+                if v.atoi_simd_in_range(MIN, MAX) {
+                    Ok((v, len))
+                } else {
+                    Err(AtoiSimdError::OutOfRange(s))
+                }
+            }
+            Some(Err(e)) => Err(e),
+            None => parse_prefix_slow::<Self, MIN, MAX, SKIP_ZEROES, SKIP_PLUS>(s),
+        }
+    }
+}
+
+/// The compile time check of the bounds
+struct Bounds<T, const MIN: i128, const MAX: i128>(PhantomData<T>);
+
+impl<T: ParseBounded, const MIN: i128, const MAX: i128> Bounds<T, MIN, MAX> {
+    const CHECK: () = assert!(
+        T::ATOI_SIMD_MIN <= MIN && MIN <= MAX && MAX <= T::ATOI_SIMD_MAX,
+        "the bounds must be MIN <= MAX, in the range of the type"
+    );
+}
+
+/// Parses the number until the digit which makes its magnitude out of `MIN..=MAX` (`OutOfRange`).
+/// Returns the value (without the check of the other bound) and the length of the number (with the sign),
+/// or `None` for the parser of the type: without digits, or longer than the type.
+#[inline(always)]
+fn parse_bounded<T: ParseBounded, const MIN: i128, const MAX: i128, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Option<Result<(T, usize), AtoiSimdError<'_>>> {
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, T::ATOI_SIMD_MIN < 0).ok()?;
+    let digits = s.get_safe_unchecked(sign..);
+    if digits.len() > T::ATOI_SIMD_DIGITS {
+        return None;
+    }
+    let max = match (neg, MIN < 0, MAX > 0) {
+        (true, true, _) => MIN.unsigned_abs(),
+        (false, _, true) => MAX as u128,
+        // only zero
+        _ => 0,
+    };
+    match parse_short_bounded(digits, max) {
+        None => Some(Err(AtoiSimdError::OutOfRange(s))),
+        Some((_, 0)) => None,
+        Some((mag, len)) => Some(Ok((T::atoi_simd_from_bounded(mag, neg), sign + len))),
+    }
+}
+
+#[cold]
+fn parse_slow<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    match T::atoi_simd_parse::<SKIP_ZEROES, SKIP_PLUS>(s) {
+        Ok(v) if v.atoi_simd_in_range(MIN, MAX) => Ok(v),
+        Ok(_) | Err(AtoiSimdError::Overflow(..)) => Err(AtoiSimdError::OutOfRange(s)),
+        Err(e) => Err(e),
+    }
+}
+
+#[cold]
+fn parse_prefix_slow<
+    T: ParseBounded,
+    const MIN: i128,
+    const MAX: i128,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    match T::atoi_simd_parse_prefix::<SKIP_ZEROES, SKIP_PLUS>(s) {
+        Ok((v, len)) if v.atoi_simd_in_range(MIN, MAX) => Ok((v, len)),
+        Ok(_) | Err(AtoiSimdError::Overflow(..)) => Err(AtoiSimdError::OutOfRange(s)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Count of the decimal digits of `max`
+const fn digits(mut max: u128) -> usize {
+    let mut n = 1;
+    while max >= 10 {
+        max /= 10;
+        n += 1;
+    }
+    n
+}
+
+macro_rules! parse_bounded_impl_unsigned {
+    ($($t:ty)*) => {$(
+        impl ParseBounded for $t {
+            const ATOI_SIMD_MIN: i128 = 0;
+            const ATOI_SIMD_MAX: i128 = if <$t>::MAX as u128 > i128::MAX as u128 {
+                i128::MAX
+            } else {
+                <$t>::MAX as i128
+            };
+            const ATOI_SIMD_DIGITS: usize = digits(<$t>::MAX as u128);
+
+            #[inline(always)]
+            fn atoi_simd_in_range(&self, min: i128, max: i128) -> bool {
+                let v = *self as u128;
+                max >= 0 && v <= max as u128 && (min <= 0 || v >= min as u128)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_from_bounded(mag: u128, _: bool) -> Self {
+                mag as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! parse_bounded_impl_signed {
+    ($($t:ty)*) => {$(
+        impl ParseBounded for $t {
+            const ATOI_SIMD_MIN: i128 = <$t>::MIN as i128;
+            const ATOI_SIMD_MAX: i128 = <$t>::MAX as i128;
+            const ATOI_SIMD_DIGITS: usize = digits(<$t>::MAX as u128);
+
+            #[inline(always)]
+            fn atoi_simd_in_range(&self, min: i128, max: i128) -> bool {
+                let v = *self as i128;
+                min <= v && v <= max
+            }
+
+            #[inline(always)]
+            fn atoi_simd_from_bounded(mag: u128, neg: bool) -> Self {
+                let v = mag as $t;
+                if neg {
+                    v.wrapping_neg()
+                } else {
+                    v
+                }
+            }
+        }
+    )*};
+}
+
+parse_bounded_impl_unsigned!(u8 u16 u32 usize u64 u128);
+parse_bounded_impl_signed!(i8 i16 i32 isize i64 i128);
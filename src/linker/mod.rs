@@ -29,6 +29,10 @@ mod simd_64;
         target_feature = "sse4.1",
         target_feature = "ssse3"
     ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
 )))]
 mod fb_32;
 #[cfg(not(any(
@@ -41,10 +45,52 @@ mod fb_32;
         target_feature = "avx",
         target_feature = "avx2"
     ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
 )))]
 mod fb_64;
 
-use crate::AtoiSimdError;
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    )),
+))]
+mod rt_32;
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3",
+        target_feature = "avx",
+        target_feature = "avx2"
+    )),
+))]
+mod rt_64;
+
+mod bounded;
+mod nonzero;
+mod overflow;
+mod radix;
+
+pub use bounded::ParseBounded;
+pub use overflow::ParseOverflow;
+pub(crate) use radix::len_sign;
+pub use radix::{ParseRadix, ParseRadixNeg, ParseRadixPos};
+
+use crate::{
+    whitespace::{parse_prefix_trimmed, parse_trimmed},
+    AtoiSimdError, I256, U256,
+};
 use debug_unsafe::slice::SliceGetter;
 
 /// Note: all of the provided methods are `#[inline(always)]`
@@ -67,24 +113,37 @@ pub trait ParseNeg: Sized {
 pub trait Parse: ParsePos {
     #[inline(always)]
     fn atoi_simd_parse<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
-        mut s: &[u8],
+        s: &[u8],
     ) -> Result<Self, AtoiSimdError<'_>> {
-        if SKIP_PLUS && *s.first().ok_or(AtoiSimdError::Empty)? == b'+' {
-            s = s.get_safe_unchecked(1..);
-        }
-
-        Self::atoi_simd_parse_pos::<SKIP_ZEROES>(s)
+        let (sign, _) = len_sign::<SKIP_PLUS>(s, false)?;
+        Self::atoi_simd_parse_pos::<SKIP_ZEROES>(s.get_safe_unchecked(sign..))
+            .map_err(|e| e.with_offset(sign, s))
     }
 
     #[inline(always)]
     fn atoi_simd_parse_prefix<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
-        mut s: &[u8],
+        s: &[u8],
     ) -> Result<(Self, usize), AtoiSimdError<'_>> {
-        if SKIP_PLUS && *s.first().ok_or(AtoiSimdError::Empty)? == b'+' {
-            s = s.get_safe_unchecked(1..);
-        }
+        let (sign, _) = len_sign::<SKIP_PLUS>(s, false)?;
+        Self::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s.get_safe_unchecked(sign..))
+            .map(|(v, l)| (v, l + sign))
+            .map_err(|e| e.with_offset(sign, s))
+    }
+
+    /// Skips the ASCII whitespace on both sides of `s`
+    #[inline(always)]
+    fn atoi_simd_parse_trimmed<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        parse_trimmed(s, Self::atoi_simd_parse::<SKIP_ZEROES, SKIP_PLUS>)
+    }
 
-        Self::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s)
+    /// Skips the ASCII whitespace at the beginning of `s`
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_trimmed<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        parse_prefix_trimmed(s, Self::atoi_simd_parse_prefix::<SKIP_ZEROES, SKIP_PLUS>)
     }
 }
 
@@ -94,25 +153,17 @@ fn atoi_simd_parse_signed<
     const SKIP_ZEROES: bool,
     const SKIP_PLUS: bool,
 >(
-    mut s: &[u8],
+    s: &[u8],
 ) -> Result<T, AtoiSimdError<'_>> {
-    let neg = match *s.first().ok_or(AtoiSimdError::Empty)? {
-        b'+' if SKIP_PLUS => {
-            s = s.get_safe_unchecked(1..);
-            false
-        }
-        b'-' => {
-            s = s.get_safe_unchecked(1..);
-            true
-        }
-        _ => false,
-    };
-
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, true)?;
+    let rest = s.get_safe_unchecked(sign..);
+    // the errors are in the indices of `s`, with the sign
     if neg {
-        T::atoi_simd_parse_neg::<SKIP_ZEROES>(s)
+        T::atoi_simd_parse_neg::<SKIP_ZEROES>(rest)
     } else {
-        T::atoi_simd_parse_pos::<SKIP_ZEROES>(s)
+        T::atoi_simd_parse_pos::<SKIP_ZEROES>(rest)
     }
+    .map_err(|e| e.with_offset(sign, s))
 }
 
 #[inline(always)]
@@ -121,25 +172,17 @@ fn atoi_simd_parse_prefix_signed<
     const SKIP_ZEROES: bool,
     const SKIP_PLUS: bool,
 >(
-    mut s: &[u8],
+    s: &[u8],
 ) -> Result<(T, usize), AtoiSimdError<'_>> {
-    let neg = match *s.first().ok_or(AtoiSimdError::Empty)? {
-        b'+' if SKIP_PLUS => {
-            s = s.get_safe_unchecked(1..);
-            false
-        }
-        b'-' => {
-            s = s.get_safe_unchecked(1..);
-            true
-        }
-        _ => false,
-    };
-
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, true)?;
+    let rest = s.get_safe_unchecked(sign..);
     if neg {
-        T::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(s).map(|(v, l)| (v, l + 1))
+        T::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(rest)
     } else {
-        T::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s)
+        T::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(rest)
     }
+    .map(|(v, l)| (v, l + sign))
+    .map_err(|e| e.with_offset(sign, s))
 }
 
 impl Parse for u8 {}
@@ -148,6 +191,7 @@ impl Parse for u32 {}
 impl Parse for usize {}
 impl Parse for u64 {}
 impl Parse for u128 {}
+impl Parse for U256 {}
 
 macro_rules! parse_impl_signed {
     ($($t:ty)*) => {$(
@@ -165,4 +209,4 @@ macro_rules! parse_impl_signed {
     )*};
 }
 
-parse_impl_signed!(i8 i16 i32 isize i64 i128);
+parse_impl_signed!(i8 i16 i32 isize i64 i128 I256);
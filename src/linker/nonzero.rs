@@ -0,0 +1,91 @@
+use super::{Parse, ParseNeg, ParsePos};
+use crate::AtoiSimdError;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+macro_rules! parse_nonzero_impl {
+    ($($nz:ident, $t:ty;)*) => {$(
+        impl ParsePos for $nz {
+            #[inline(always)]
+            fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                let v = <$t>::atoi_simd_parse_pos::<SKIP_ZEROES>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s))
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                let (v, len) = <$t>::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s)).map(|v| (v, len))
+            }
+        }
+
+        impl Parse for $nz {
+            #[inline(always)]
+            fn atoi_simd_parse<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                let v = <$t>::atoi_simd_parse::<SKIP_ZEROES, SKIP_PLUS>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s))
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                let (v, len) = <$t>::atoi_simd_parse_prefix::<SKIP_ZEROES, SKIP_PLUS>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s)).map(|v| (v, len))
+            }
+        }
+    )*};
+}
+
+macro_rules! parse_nonzero_impl_neg {
+    ($($nz:ident, $t:ty;)*) => {$(
+        impl ParseNeg for $nz {
+            #[inline(always)]
+            fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                let v = <$t>::atoi_simd_parse_neg::<SKIP_ZEROES>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s))
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                let (v, len) = <$t>::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(s)?;
+                $nz::new(v).ok_or(AtoiSimdError::Zero(s)).map(|v| (v, len))
+            }
+        }
+    )*};
+}
+
+parse_nonzero_impl!(
+    NonZeroU8, u8;
+    NonZeroU16, u16;
+    NonZeroU32, u32;
+    NonZeroUsize, usize;
+    NonZeroU64, u64;
+    NonZeroU128, u128;
+    NonZeroI8, i8;
+    NonZeroI16, i16;
+    NonZeroI32, i32;
+    NonZeroIsize, isize;
+    NonZeroI64, i64;
+    NonZeroI128, i128;
+);
+parse_nonzero_impl_neg!(
+    NonZeroI8, i8;
+    NonZeroI16, i16;
+    NonZeroI32, i32;
+    NonZeroIsize, isize;
+    NonZeroI64, i64;
+    NonZeroI128, i128;
+);
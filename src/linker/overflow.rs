@@ -0,0 +1,266 @@
+use super::{len_sign, Parse, ParseNeg, ParsePos};
+use crate::AtoiSimdError;
+use core::num::Wrapping;
+use debug_unsafe::slice::SliceGetter;
+
+/// Parsing with saturating (clamping to `MIN`/`MAX`) or wrapping (modulo 2^N) on overflow,
+/// for the inputs of any length.
+///
+/// Note: all of the provided methods are `#[inline(always)]`
+pub trait ParseOverflow: Parse {
+    /// Max magnitude of a positive value
+    #[doc(hidden)]
+    const MAX_POS: u128;
+    /// Max magnitude of a negative value, zero for unsigned integers
+    #[doc(hidden)]
+    const MAX_NEG: u128;
+
+    /// Converts the magnitude (saturated, or wrapped modulo 2^128) to the value
+    #[doc(hidden)]
+    fn atoi_simd_from_magnitude(mag: u128, neg: bool) -> Self;
+
+    /// The same error as the decimal parser for an invalid char at `index`
+    #[doc(hidden)]
+    fn atoi_simd_invalid(mag: u128, index: usize, s: &[u8]) -> AtoiSimdError<'_>;
+
+    #[inline(always)]
+    fn atoi_simd_parse_saturating<const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        Self::atoi_simd_parse::<false, SKIP_PLUS>(s)
+            .or_else(|e| parse_signed_slow::<Self, true, SKIP_PLUS>(s, e, false).map(|(v, _)| v))
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_saturating<const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        match Self::atoi_simd_parse_prefix::<false, SKIP_PLUS>(s) {
+            Ok((v, len)) if !followed_by_digit(s, len) => Ok((v, len)),
+            Ok(_) => parse_signed_slow::<Self, true, SKIP_PLUS>(s, AtoiSimdError::Empty, true),
+            Err(e) => parse_signed_slow::<Self, true, SKIP_PLUS>(s, e, true),
+        }
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_wrapping<const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        Self::atoi_simd_parse::<false, SKIP_PLUS>(s)
+            .or_else(|e| parse_signed_slow::<Self, false, SKIP_PLUS>(s, e, false).map(|(v, _)| v))
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_wrapping<const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        match Self::atoi_simd_parse_prefix::<false, SKIP_PLUS>(s) {
+            Ok((v, len)) if !followed_by_digit(s, len) => Ok((v, len)),
+            Ok(_) => parse_signed_slow::<Self, false, SKIP_PLUS>(s, AtoiSimdError::Empty, true),
+            Err(e) => parse_signed_slow::<Self, false, SKIP_PLUS>(s, e, true),
+        }
+    }
+}
+
+/// The fast prefix parser stops at its max length, so the rest of the digits are checked here
+#[inline(always)]
+fn followed_by_digit(s: &[u8], len: usize) -> bool {
+    s.get(len).map_or(false, u8::is_ascii_digit)
+}
+
+/// Parses the digits after the sign (of length `sign`) with the overflow policy, when the fast parser returned `e`.
+/// Returns the value and the count of the digits.
+///
+/// The errors are in the indices of `s`, like the errors of the decimal parser.
+#[cold]
+fn parse_slow<'a, T: ParseOverflow, const SATURATE: bool>(
+    s: &'a [u8],
+    sign: usize,
+    neg: bool,
+    e: AtoiSimdError<'a>,
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'a>> {
+    let digits = s.get_safe_unchecked(sign..);
+    let max = if neg { T::MAX_NEG } else { T::MAX_POS };
+    let mut mag: u128 = 0;
+    let mut overflow = false;
+    let mut len = 0;
+    for &c in digits {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        let digit = (c & 0xF) as u128;
+        match mag.checked_mul(10).and_then(|v| v.checked_add(digit)) {
+            Some(v) if v <= max && !overflow => mag = v,
+            _ if SATURATE => {
+                overflow = true;
+                mag = max;
+            }
+            _ => {
+                overflow = true;
+                mag = mag.wrapping_mul(10).wrapping_add(digit);
+            }
+        }
+        len += 1;
+    }
+    if len == 0 {
+        return Err(e);
+    }
+    if !prefix && len != digits.len() {
+        // the same error as the fast parser, if it found the invalid char (its variant depends on the kernel)
+        return Err(match e {
+            AtoiSimdError::Invalid64(_, i, _) | AtoiSimdError::Invalid128(_, i, _)
+                if !overflow && i == sign + len =>
+            {
+                e
+            }
+            _ => T::atoi_simd_invalid(mag, sign + len, s),
+        });
+    }
+    Ok((T::atoi_simd_from_magnitude(mag, neg), len))
+}
+
+#[inline(always)]
+fn parse_signed_slow<'a, T: ParseOverflow, const SATURATE: bool, const SKIP_PLUS: bool>(
+    s: &'a [u8],
+    e: AtoiSimdError<'a>,
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'a>> {
+    let (sign, neg) = len_sign::<SKIP_PLUS>(s, T::MAX_NEG != 0)?;
+    parse_slow::<T, SATURATE>(s, sign, neg, e, prefix).map(|(v, len)| (v, len + sign))
+}
+
+macro_rules! parse_overflow_impl {
+    ($inv:ident; $($t:ty, $u:ty, $max_neg:expr;)*) => {$(
+        impl ParseOverflow for $t {
+            const MAX_POS: u128 = <$t>::MAX as u128;
+            const MAX_NEG: u128 = $max_neg;
+
+            #[inline(always)]
+            fn atoi_simd_from_magnitude(mag: u128, neg: bool) -> Self {
+                let v = mag as $t;
+                if neg {
+                    v.wrapping_neg()
+                } else {
+                    v
+                }
+            }
+
+            #[inline(always)]
+            fn atoi_simd_invalid(mag: u128, index: usize, s: &[u8]) -> AtoiSimdError<'_> {
+                AtoiSimdError::$inv(mag as $u as _, index, s)
+            }
+        }
+    )*};
+}
+
+parse_overflow_impl!(Invalid64;
+    u8, u8, 0;
+    u16, u16, 0;
+    u32, u32, 0;
+    i8, u8, i8::MIN.unsigned_abs() as u128;
+    i16, u16, i16::MIN.unsigned_abs() as u128;
+    i32, u32, i32::MIN.unsigned_abs() as u128;
+);
+parse_overflow_impl!(Invalid128;
+    u64, u64, 0;
+    u128, u128, 0;
+    i64, u64, i64::MIN.unsigned_abs() as u128;
+    i128, u128, i128::MIN.unsigned_abs();
+);
+#[cfg(target_pointer_width = "64")]
+parse_overflow_impl!(Invalid128;
+    usize, usize, 0;
+    isize, usize, isize::MIN.unsigned_abs() as u128;
+);
+#[cfg(not(target_pointer_width = "64"))]
+parse_overflow_impl!(Invalid64;
+    usize, usize, 0;
+    isize, usize, isize::MIN.unsigned_abs() as u128;
+);
+
+macro_rules! parse_policy_impl {
+    ($wrapper:ident, $saturate:literal, $parse:ident, $parse_prefix:ident) => {
+        impl<T: ParseOverflow> ParsePos for $wrapper<T> {
+            #[inline(always)]
+            fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                T::atoi_simd_parse_pos::<SKIP_ZEROES>(s)
+                    .or_else(|e| parse_slow::<T, $saturate>(s, 0, false, e, false).map(|(v, _)| v))
+                    .map($wrapper)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                match T::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s) {
+                    Ok((v, len)) if !followed_by_digit(s, len) => Ok((v, len)),
+                    Ok(_) => parse_slow::<T, $saturate>(s, 0, false, AtoiSimdError::Empty, true),
+                    Err(e) => parse_slow::<T, $saturate>(s, 0, false, e, true),
+                }
+                .map(|(v, len)| ($wrapper(v), len))
+            }
+        }
+
+        impl<T: ParseOverflow + ParseNeg> ParseNeg for $wrapper<T> {
+            #[inline(always)]
+            fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                T::atoi_simd_parse_neg::<SKIP_ZEROES>(s)
+                    .or_else(|e| parse_slow::<T, $saturate>(s, 0, true, e, false).map(|(v, _)| v))
+                    .map($wrapper)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                match T::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(s) {
+                    Ok((v, len)) if !followed_by_digit(s, len) => Ok((v, len)),
+                    Ok(_) => parse_slow::<T, $saturate>(s, 0, true, AtoiSimdError::Empty, true),
+                    Err(e) => parse_slow::<T, $saturate>(s, 0, true, e, true),
+                }
+                .map(|(v, len)| ($wrapper(v), len))
+            }
+        }
+
+        impl<T: ParseOverflow> Parse for $wrapper<T> {
+            #[inline(always)]
+            fn atoi_simd_parse<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+                s: &[u8],
+            ) -> Result<Self, AtoiSimdError<'_>> {
+                T::$parse::<SKIP_PLUS>(s).map($wrapper)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+                s: &[u8],
+            ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                T::$parse_prefix::<SKIP_PLUS>(s).map(|(v, len)| ($wrapper(v), len))
+            }
+        }
+    };
+}
+
+parse_policy_impl!(
+    Wrapping,
+    false,
+    atoi_simd_parse_wrapping,
+    atoi_simd_parse_prefix_wrapping
+);
+
+#[rustversion::since(1.74)]
+mod saturating {
+    use super::*;
+    use core::num::Saturating;
+
+    parse_policy_impl!(
+        Saturating,
+        true,
+        atoi_simd_parse_saturating,
+        atoi_simd_parse_prefix_saturating
+    );
+}
@@ -0,0 +1,270 @@
+use super::{ParseNeg, ParsePos};
+use crate::{radix::*, AtoiSimdError};
+use debug_unsafe::slice::SliceGetter;
+
+/// Parsing of positive integers in other radixes (bases). `RADIX` must be in `2..=36`, otherwise it panics.
+/// Letters are case-insensitive.
+///
+/// Note: all of the provided methods are `#[inline(always)]`
+pub trait ParseRadixPos: Sized {
+    fn atoi_simd_parse_radix_pos<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>>;
+    fn atoi_simd_parse_prefix_radix_pos<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>>;
+
+    /// The invalid char error of the radix parsers, with the magnitude of the value before it
+    #[doc(hidden)]
+    #[inline(always)]
+    fn atoi_simd_radix_invalid(mag: u128, index: usize, s: &[u8]) -> AtoiSimdError<'_> {
+        AtoiSimdError::Invalid64(mag as u64, index, s)
+    }
+}
+
+/// Parsing of negative integers (without the '-' sign) in other radixes (bases).
+/// `RADIX` must be in `2..=36`, otherwise it panics. Letters are case-insensitive.
+///
+/// Note: all of the provided methods are `#[inline(always)]`
+pub trait ParseRadixNeg: Sized {
+    fn atoi_simd_parse_radix_neg<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>>;
+    fn atoi_simd_parse_prefix_radix_neg<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>>;
+}
+
+/// Parsing in other radixes (bases) with the sign. `RADIX` must be in `2..=36`, otherwise it panics.
+/// Letters are case-insensitive.
+///
+/// Note: all of the provided methods are `#[inline(always)]`
+pub trait ParseRadix: ParseRadixPos {
+    /// Returns the length of the sign, and if it's negative ('-' is a sign only for signed integers)
+    #[doc(hidden)]
+    #[inline(always)]
+    fn atoi_simd_radix_sign<const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<(usize, bool), AtoiSimdError<'_>> {
+        len_sign::<SKIP_PLUS>(s, false)
+    }
+
+    /// Parses the digits after the sign returned by `atoi_simd_radix_sign`
+    #[doc(hidden)]
+    #[inline(always)]
+    fn atoi_simd_parse_radix_digits<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+        _neg: bool,
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        Self::atoi_simd_parse_radix_pos::<RADIX, SKIP_ZEROES>(s)
+    }
+
+    /// Parses the digits after the sign returned by `atoi_simd_radix_sign`
+    #[doc(hidden)]
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_radix_digits<const RADIX: u32, const SKIP_ZEROES: bool>(
+        s: &[u8],
+        _neg: bool,
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        Self::atoi_simd_parse_prefix_radix_pos::<RADIX, SKIP_ZEROES>(s)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_radix<const RADIX: u32, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        let (sign, neg) = Self::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+        Self::atoi_simd_parse_radix_digits::<RADIX, SKIP_ZEROES>(s.get_safe_unchecked(sign..), neg)
+            .map_err(|e| e.with_offset(sign, s))
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_radix<
+        const RADIX: u32,
+        const SKIP_ZEROES: bool,
+        const SKIP_PLUS: bool,
+    >(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        let (sign, neg) = Self::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+        Self::atoi_simd_parse_prefix_radix_digits::<RADIX, SKIP_ZEROES>(
+            s.get_safe_unchecked(sign..),
+            neg,
+        )
+        .map(|(v, l)| (v, l + sign))
+        .map_err(|e| e.with_offset(sign, s))
+    }
+
+    /// Detects the radix by the `0x`, `0o` or `0b` prefix (after the sign), decimal otherwise
+    #[inline(always)]
+    fn atoi_simd_parse_auto<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<Self, AtoiSimdError<'_>> {
+        let (sign, neg) = Self::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+        let rest = s.get_safe_unchecked(sign..);
+        let (radix, len_prefix) = radix_prefix(rest);
+        let digits = rest.get_safe_unchecked(len_prefix..);
+        match radix {
+            16 => Self::atoi_simd_parse_radix_digits::<16, SKIP_ZEROES>(digits, neg),
+            8 => Self::atoi_simd_parse_radix_digits::<8, SKIP_ZEROES>(digits, neg),
+            2 => Self::atoi_simd_parse_radix_digits::<2, SKIP_ZEROES>(digits, neg),
+            _ => Self::atoi_simd_parse_radix_digits::<10, SKIP_ZEROES>(digits, neg),
+        }
+        .or_else(|e| match e {
+            // no digits after the prefix, so it's just a zero followed by a letter
+            AtoiSimdError::Empty if len_prefix != 0 => {
+                Self::atoi_simd_parse_radix_digits::<10, SKIP_ZEROES>(rest, neg)
+                    .map_err(|e| e.with_offset(sign, s))
+            }
+            e => Err(e.with_offset(sign + len_prefix, s)),
+        })
+    }
+
+    /// Detects the radix by the `0x`, `0o` or `0b` prefix (after the sign), decimal otherwise
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_auto<const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        let (sign, neg) = Self::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+        let rest = s.get_safe_unchecked(sign..);
+        let (radix, len_prefix) = radix_prefix(rest);
+        let digits = rest.get_safe_unchecked(len_prefix..);
+        match radix {
+            16 => Self::atoi_simd_parse_prefix_radix_digits::<16, SKIP_ZEROES>(digits, neg),
+            8 => Self::atoi_simd_parse_prefix_radix_digits::<8, SKIP_ZEROES>(digits, neg),
+            2 => Self::atoi_simd_parse_prefix_radix_digits::<2, SKIP_ZEROES>(digits, neg),
+            _ => Self::atoi_simd_parse_prefix_radix_digits::<10, SKIP_ZEROES>(digits, neg),
+        }
+        .map(|(v, l)| (v, l + sign + len_prefix))
+        .or_else(|e| match e {
+            // no digits after the prefix, so it's just a zero followed by a letter
+            AtoiSimdError::Empty if len_prefix != 0 => {
+                Self::atoi_simd_parse_prefix_radix_digits::<10, SKIP_ZEROES>(rest, neg)
+                    .map(|(v, l)| (v, l + sign))
+                    .map_err(|e| e.with_offset(sign, s))
+            }
+            e => Err(e.with_offset(sign + len_prefix, s)),
+        })
+    }
+}
+
+/// Returns the length of the sign, and if it's negative
+#[inline(always)]
+pub(crate) fn len_sign<const SKIP_PLUS: bool>(
+    s: &[u8],
+    signed: bool,
+) -> Result<(usize, bool), AtoiSimdError<'_>> {
+    match *s.first().ok_or(AtoiSimdError::Empty)? {
+        b'+' if SKIP_PLUS => Ok((1, false)),
+        b'-' if signed => Ok((1, true)),
+        _ => Ok((0, false)),
+    }
+}
+
+/// Returns the radix of the prefix and its length
+#[inline(always)]
+fn radix_prefix(s: &[u8]) -> (u32, usize) {
+    match s {
+        [b'0', b'x' | b'X', ..] => (16, 2),
+        [b'0', b'o' | b'O', ..] => (8, 2),
+        [b'0', b'b' | b'B', ..] => (2, 2),
+        _ => (10, 0),
+    }
+}
+
+macro_rules! parse_radix_impl_pos {
+    ($u:ty, $parse:ident, $parse_checked:ident, $inv:ident; $($t:ty)*) => {$(
+        impl ParseRadixPos for $t {
+            #[inline(always)]
+            fn atoi_simd_parse_radix_pos<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8]) -> Result<Self, AtoiSimdError<'_>> {
+                if RADIX == 10 {
+                    return <$t as ParsePos>::atoi_simd_parse_pos::<SKIP_ZEROES>(s);
+                }
+                $parse_checked::<RADIX, { <$t>::MAX as $u }, SKIP_ZEROES>(s).map(|v| v as $t)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_radix_pos<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8]) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                if RADIX == 10 {
+                    return <$t as ParsePos>::atoi_simd_parse_prefix_pos::<SKIP_ZEROES>(s);
+                }
+                $parse::<RADIX, { <$t>::MAX as $u }, SKIP_ZEROES>(s).map(|(v, i)| (v as $t, i))
+            }
+
+            #[inline(always)]
+            fn atoi_simd_radix_invalid(mag: u128, index: usize, s: &[u8]) -> AtoiSimdError<'_> {
+                AtoiSimdError::$inv(mag as $u, index, s)
+            }
+        }
+    )*};
+}
+
+parse_radix_impl_pos!(u64, parse_radix_u64, parse_radix_checked_u64, Invalid64; u8 u16 u32 usize u64 i8 i16 i32 isize i64);
+parse_radix_impl_pos!(u128, parse_radix_u128, parse_radix_checked_u128, Invalid128; u128 i128);
+
+macro_rules! parse_radix_impl_neg {
+    ($u:ty, $parse:ident, $parse_checked:ident; $($t:ty)*) => {$(
+        impl ParseRadixNeg for $t {
+            #[inline(always)]
+            fn atoi_simd_parse_radix_neg<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8]) -> Result<Self, AtoiSimdError<'_>> {
+                if RADIX == 10 {
+                    return <$t as ParseNeg>::atoi_simd_parse_neg::<SKIP_ZEROES>(s);
+                }
+                $parse_checked::<RADIX, { <$t>::MIN.unsigned_abs() as $u }, SKIP_ZEROES>(s)
+                    .map(|v| (v as $t).wrapping_neg())
+                    .map_err(AtoiSimdError::neg)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_radix_neg<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8]) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                if RADIX == 10 {
+                    return <$t as ParseNeg>::atoi_simd_parse_prefix_neg::<SKIP_ZEROES>(s);
+                }
+                $parse::<RADIX, { <$t>::MIN.unsigned_abs() as $u }, SKIP_ZEROES>(s)
+                    .map(|(v, i)| ((v as $t).wrapping_neg(), i))
+                    .map_err(AtoiSimdError::neg)
+            }
+        }
+    )*};
+}
+
+parse_radix_impl_neg!(u64, parse_radix_u64, parse_radix_checked_u64; i8 i16 i32 isize i64);
+parse_radix_impl_neg!(u128, parse_radix_u128, parse_radix_checked_u128; i128);
+
+impl ParseRadix for u8 {}
+impl ParseRadix for u16 {}
+impl ParseRadix for u32 {}
+impl ParseRadix for usize {}
+impl ParseRadix for u64 {}
+impl ParseRadix for u128 {}
+
+macro_rules! parse_radix_impl_signed {
+    ($($t:ty)*) => {$(
+        impl ParseRadix for $t {
+            #[inline(always)]
+            fn atoi_simd_radix_sign<const SKIP_PLUS: bool>(s: &[u8]) -> Result<(usize, bool), AtoiSimdError<'_>> {
+                len_sign::<SKIP_PLUS>(s, true)
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_radix_digits<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8], neg: bool) -> Result<Self, AtoiSimdError<'_>> {
+                if neg {
+                    Self::atoi_simd_parse_radix_neg::<RADIX, SKIP_ZEROES>(s)
+                } else {
+                    Self::atoi_simd_parse_radix_pos::<RADIX, SKIP_ZEROES>(s)
+                }
+            }
+
+            #[inline(always)]
+            fn atoi_simd_parse_prefix_radix_digits<const RADIX: u32, const SKIP_ZEROES: bool>(s: &[u8], neg: bool) -> Result<(Self, usize), AtoiSimdError<'_>> {
+                if neg {
+                    Self::atoi_simd_parse_prefix_radix_neg::<RADIX, SKIP_ZEROES>(s)
+                } else {
+                    Self::atoi_simd_parse_prefix_radix_pos::<RADIX, SKIP_ZEROES>(s)
+                }
+            }
+        }
+    )*};
+}
+
+parse_radix_impl_signed!(i8 i16 i32 isize i64 i128);
@@ -0,0 +1,296 @@
+use super::*;
+use crate::{
+    fallback::*,
+    runtime::{avx2, dispatch},
+};
+
+#[cfg(target_pointer_width = "64")]
+impl ParsePos for usize {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<usize, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            u64,
+            [
+                parse_fb_checked_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                parse_fb_checked_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                avx2::parse_simd_checked_u64::<SKIP_ZEROES>
+            ]
+        )
+        .map(|v| v as usize)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(usize, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (u64, usize),
+            [
+                parse_fb_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                parse_fb_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                avx2::parse_simd_u64::<SKIP_ZEROES>
+            ]
+        )
+        .map(|(v, i)| (v as usize, i))
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ParsePos for isize {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<isize, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i64,
+            [
+                |s| parse_fb_checked_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|v| v as i64),
+                |s| parse_fb_checked_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|v| v as i64),
+                avx2::parse_simd_checked_i64::<SKIP_ZEROES>
+            ]
+        )
+        .map(|v| v as isize)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(isize, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i64, usize),
+            [
+                |s| parse_fb_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i64, i)),
+                |s| parse_fb_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i64, i)),
+                avx2::parse_simd_i64::<SKIP_ZEROES>
+            ]
+        )
+        .map(|(v, i)| (v as isize, i))
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ParseNeg for isize {
+    #[inline(always)]
+    fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<isize, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i64,
+            [
+                parse_fb_checked_64_neg::<SKIP_ZEROES>,
+                parse_fb_checked_64_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_checked_i64_neg::<SKIP_ZEROES>
+            ]
+        )
+        .map(|v| v as isize)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(isize, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i64, usize),
+            [
+                parse_fb_64_neg::<SKIP_ZEROES>,
+                parse_fb_64_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_i64_neg::<SKIP_ZEROES>
+            ]
+        )
+        .map(|(v, i)| (v as isize, i))
+    }
+}
+
+impl ParsePos for u64 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<u64, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            u64,
+            [
+                parse_fb_checked_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                parse_fb_checked_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                avx2::parse_simd_checked_u64::<SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(u64, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (u64, usize),
+            [
+                parse_fb_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                parse_fb_64_pos::<{ u64::MAX }, 4, SKIP_ZEROES>,
+                avx2::parse_simd_u64::<SKIP_ZEROES>
+            ]
+        )
+    }
+}
+
+impl ParsePos for i64 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<i64, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i64,
+            [
+                |s| parse_fb_checked_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|v| v as i64),
+                |s| parse_fb_checked_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|v| v as i64),
+                avx2::parse_simd_checked_i64::<SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(i64, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i64, usize),
+            [
+                |s| parse_fb_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i64, i)),
+                |s| parse_fb_64_pos::<{ i64::MAX as u64 }, 3, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i64, i)),
+                avx2::parse_simd_i64::<SKIP_ZEROES>
+            ]
+        )
+    }
+}
+
+impl ParseNeg for i64 {
+    #[inline(always)]
+    fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<i64, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i64,
+            [
+                parse_fb_checked_64_neg::<SKIP_ZEROES>,
+                parse_fb_checked_64_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_checked_i64_neg::<SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(i64, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i64, usize),
+            [
+                parse_fb_64_neg::<SKIP_ZEROES>,
+                parse_fb_64_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_i64_neg::<SKIP_ZEROES>
+            ]
+        )
+    }
+}
+
+impl ParsePos for u128 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<u128, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            u128,
+            [
+                parse_fb_checked_128_pos::<{ u128::MAX }, SKIP_ZEROES>,
+                parse_fb_checked_128_pos::<{ u128::MAX }, SKIP_ZEROES>,
+                avx2::parse_simd_checked_u128::<39, SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(u128, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (u128, usize),
+            [
+                parse_fb_128_pos::<{ u128::MAX }, SKIP_ZEROES>,
+                parse_fb_128_pos::<{ u128::MAX }, SKIP_ZEROES>,
+                avx2::parse_simd_u128::<39, SKIP_ZEROES>
+            ]
+        )
+    }
+}
+
+impl ParsePos for i128 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<i128, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i128,
+            [
+                |s| parse_fb_checked_128_pos::<{ i128::MAX as u128 }, SKIP_ZEROES>(s)
+                    .map(|v| v as i128),
+                |s| parse_fb_checked_128_pos::<{ i128::MAX as u128 }, SKIP_ZEROES>(s)
+                    .map(|v| v as i128),
+                avx2::parse_simd_checked_i128::<SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(i128, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i128, usize),
+            [
+                |s| parse_fb_128_pos::<{ i128::MAX as u128 }, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i128, i)),
+                |s| parse_fb_128_pos::<{ i128::MAX as u128 }, SKIP_ZEROES>(s)
+                    .map(|(v, i)| (v as i128, i)),
+                avx2::parse_simd_i128::<SKIP_ZEROES>
+            ]
+        )
+    }
+}
+
+impl ParseNeg for i128 {
+    #[inline(always)]
+    fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<i128, AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            i128,
+            [
+                parse_fb_checked_128_neg::<SKIP_ZEROES>,
+                parse_fb_checked_128_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_checked_i128_neg::<SKIP_ZEROES>
+            ]
+        )
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(i128, usize), AtoiSimdError<'_>> {
+        dispatch!(
+            s,
+            (i128, usize),
+            [
+                parse_fb_128_neg::<SKIP_ZEROES>,
+                parse_fb_128_neg::<SKIP_ZEROES>,
+                avx2::parse_simd_i128_neg::<SKIP_ZEROES>
+            ]
+        )
+    }
+}
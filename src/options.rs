@@ -0,0 +1,198 @@
+use crate::{
+    separated::{parse_prefix_separated_by, parse_separated_by},
+    whitespace::{trim, whitespace_len},
+    AtoiSimdError, Parse, ParseRadix,
+};
+use debug_unsafe::slice::SliceGetter;
+
+/// Digit separators for `ParseOptions`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// `_`, like `1_000_000`
+    Underscore,
+    /// `,`, like `1,000,000`
+    Comma,
+    /// `.`, like `1.000.000`
+    Dot,
+    /// `'`, like `1'000'000`
+    Apostrophe,
+    /// ' ', like `1 000 000`
+    Space,
+}
+
+impl Separator {
+    /// Returns the separator for the char `c`, if it's one of them
+    #[inline]
+    pub const fn from_byte(c: u8) -> Option<Self> {
+        match c {
+            b'_' => Some(Separator::Underscore),
+            b',' => Some(Separator::Comma),
+            b'.' => Some(Separator::Dot),
+            b'\'' => Some(Separator::Apostrophe),
+            b' ' => Some(Separator::Space),
+            _ => None,
+        }
+    }
+
+    /// The char of the separator
+    #[inline]
+    pub const fn byte(self) -> u8 {
+        match self {
+            Separator::Underscore => b'_',
+            Separator::Comma => b',',
+            Separator::Dot => b'.',
+            Separator::Apostrophe => b'\'',
+            Separator::Space => b' ',
+        }
+    }
+}
+
+/// Runtime options for `parse_with` and `parse_prefix_with`,
+/// the same as the const generic flags of the other functions.
+///
+/// Built with the chained methods from `ParseOptions::new()` (or `default()`), which disables all of them
+/// (it's `non_exhaustive`, the fields are only for reading):
+///
+/// ```
+/// use atoi_simd::{ParseOptions, Separator};
+///
+/// let opts = ParseOptions::new().skip_plus(true).trim(true).separator(Some(Separator::Comma));
+/// assert_eq!(atoi_simd::parse_with::<u32>(&opts, b" +1,234 "), Ok(1234_u32));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// See `ParseOptions::skip_zeroes`
+    pub skip_zeroes: bool,
+    /// See `ParseOptions::skip_plus`
+    pub skip_plus: bool,
+    /// See `ParseOptions::trim`
+    pub trim: bool,
+    /// See `ParseOptions::separator`
+    pub separator: Option<Separator>,
+    /// See `ParseOptions::strict`
+    pub strict: bool,
+}
+
+impl ParseOptions {
+    /// All of the options are disabled, like `parse::<T, false, false>`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            skip_zeroes: false,
+            skip_plus: false,
+            trim: false,
+            separator: None,
+            strict: false,
+        }
+    }
+
+    /// Skips an infinite amount of zeroes at the beginning (SKIP_ZEROES)
+    #[inline]
+    pub const fn skip_zeroes(mut self, skip_zeroes: bool) -> Self {
+        self.skip_zeroes = skip_zeroes;
+        self
+    }
+
+    /// Skips the '+' char (SKIP_PLUS)
+    #[inline]
+    pub const fn skip_plus(mut self, skip_plus: bool) -> Self {
+        self.skip_plus = skip_plus;
+        self
+    }
+
+    /// Skips the ASCII whitespace on both sides of the number (at the beginning of it for `parse_prefix_with`),
+    /// like `parse_trimmed`
+    #[inline]
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Digit separator, like `parse_separated`
+    #[inline]
+    pub const fn separator(mut self, separator: Option<Separator>) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// A separator is allowed only between two digits (STRICT of `parse_separated`)
+    #[inline]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+/// Calls `$f` with the runtime flags as the last const generic arguments
+macro_rules! dispatch {
+    ($($f:ident)::+, [$($g:tt),*], ($($arg:expr),*), [$($c:tt),*]) => {
+        $($f)::+::<$($g,)* $($c),*>($($arg),*)
+    };
+    ($($f:ident)::+, [$($g:tt),*], ($($arg:expr),*), [$($c:tt),*], $flag:expr $(, $rest:expr)*) => {
+        if $flag {
+            dispatch!($($f)::+, [$($g),*], ($($arg),*), [$($c,)* true] $(, $rest)*)
+        } else {
+            dispatch!($($f)::+, [$($g),*], ($($arg),*), [$($c,)* false] $(, $rest)*)
+        }
+    };
+}
+
+/// Only SKIP_ZEROES and SKIP_PLUS select a monomorphised parser (4 of them with and 4 without a separator),
+/// the trimming, the separator and STRICT are runtime arguments, so `parse_with` doesn't grow the binary
+/// by a parser for each combination of the options
+#[inline]
+pub(crate) fn parse_with<'a, T: Parse + ParseRadix>(
+    opts: &ParseOptions,
+    s: &'a [u8],
+) -> Result<T, AtoiSimdError<'a>> {
+    let (start, rest) = if opts.trim { trim(s) } else { (0, s) };
+    match opts.separator {
+        None => dispatch!(
+            T::atoi_simd_parse,
+            [],
+            (rest),
+            [],
+            opts.skip_zeroes,
+            opts.skip_plus
+        ),
+        Some(sep) => dispatch!(
+            parse_separated_by,
+            [T],
+            (rest, sep.byte(), opts.strict),
+            [],
+            opts.skip_zeroes,
+            opts.skip_plus
+        ),
+    }
+    .map_err(|e| e.with_offset(start, s))
+}
+
+#[inline]
+pub(crate) fn parse_prefix_with<'a, T: Parse + ParseRadix>(
+    opts: &ParseOptions,
+    s: &'a [u8],
+) -> Result<(T, usize), AtoiSimdError<'a>> {
+    let start = if opts.trim { whitespace_len(s) } else { 0 };
+    let rest = s.get_safe_unchecked(start..);
+    match opts.separator {
+        None => dispatch!(
+            T::atoi_simd_parse_prefix,
+            [],
+            (rest),
+            [],
+            opts.skip_zeroes,
+            opts.skip_plus
+        ),
+        Some(sep) => dispatch!(
+            parse_prefix_separated_by,
+            [T],
+            (rest, sep.byte(), opts.strict),
+            [],
+            opts.skip_zeroes,
+            opts.skip_plus
+        ),
+    }
+    .map(|(v, len)| (v, start + len))
+    .map_err(|e| e.with_offset(start, s))
+}
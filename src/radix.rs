@@ -0,0 +1,283 @@
+use crate::{AtoiSimdError, Sign};
+use debug_unsafe::slice::SliceGetter;
+
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+))]
+use crate::simd::shared_32::{
+    parse_simd_bin_16 as parse_bin_16, parse_simd_hex_16 as parse_hex_16,
+    parse_simd_oct_16 as parse_oct_16,
+};
+
+#[cfg(not(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
+)))]
+use crate::fallback::{
+    parse_fb_bin_16 as parse_bin_16, parse_fb_hex_16 as parse_hex_16,
+    parse_fb_oct_16 as parse_oct_16,
+};
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    )),
+))]
+macro_rules! dispatch_16 {
+    ($($name:ident: $fb:ident, $simd:ident;)*) => {$(
+        #[inline(always)]
+        fn $name(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+            use crate::{
+                fallback::$fb,
+                runtime::{dispatch, sse41},
+            };
+
+            dispatch!(s, (u64, usize), [$fb, sse41::$simd, sse41::$simd])
+        }
+    )*};
+}
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    )),
+))]
+dispatch_16! {
+    parse_bin_16: parse_fb_bin_16, parse_simd_bin_16;
+    parse_oct_16: parse_fb_oct_16, parse_simd_oct_16;
+    parse_hex_16: parse_fb_hex_16, parse_simd_hex_16;
+}
+
+/// Parses up to 16 digits of a power of two `RADIX` (2, 8 or 16)
+#[inline(always)]
+fn parse_pow2_16<const RADIX: u32>(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    match RADIX {
+        2 => parse_bin_16(s),
+        8 => parse_oct_16(s),
+        16 => parse_hex_16(s),
+        _ => unreachable!(),
+    }
+}
+
+macro_rules! parse_pow2 {
+    ($t:ty, $parse:ident, $parse_checked:ident, $invalid:ident) => {
+        /// Parses digits of a power of two `RADIX` (2, 8 or 16) by chunks of 16.
+        /// Without SKIP_ZEROES it fails when there are more (zero) digits than the type can hold.
+        #[inline(always)]
+        fn $parse<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<($t, usize), AtoiSimdError<'_>> {
+            const BITS: u32 = <$t>::BITS;
+            let shift = RADIX.trailing_zeros();
+            let max_len = ((BITS + shift - 1) / shift) as usize;
+
+            let (res, mut len) = parse_pow2_16::<RADIX>(s)?;
+            let mut res = res as $t;
+            while len % 16 == 0 && len < s.len() {
+                let (more, len_more) = match parse_pow2_16::<RADIX>(s.get_safe_unchecked(len..)) {
+                    Ok(v) => v,
+                    Err(_) => break,
+                };
+                let shift_more = shift * len_more as u32;
+                if res >> (BITS - shift_more) != 0 {
+                    crate::cold_path();
+                    return Err(AtoiSimdError::overflow_radix(
+                        Sign::Pos,
+                        s,
+                        RADIX,
+                        MAX as u128,
+                    ));
+                }
+                len += len_more;
+                if !SKIP_ZEROES && len > max_len {
+                    crate::cold_path();
+                    return Err(AtoiSimdError::Size(len, s));
+                }
+                res = res.checked_shl(shift_more).unwrap_or(0) | more as $t;
+            }
+            if res > MAX {
+                return Err(AtoiSimdError::overflow_radix(
+                    Sign::Pos,
+                    s,
+                    RADIX,
+                    MAX as u128,
+                ));
+            }
+
+            Ok((res, len))
+        }
+
+        #[inline(always)]
+        fn $parse_checked<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<$t, AtoiSimdError<'_>> {
+            let (res, len) = $parse::<RADIX, MAX, SKIP_ZEROES>(s)?;
+            if len != s.len() {
+                return Err(AtoiSimdError::$invalid(res as _, len, s));
+            }
+            Ok(res)
+        }
+    };
+}
+
+parse_pow2!(u64, parse_pow2_u64, parse_pow2_checked_u64, Invalid64);
+parse_pow2!(u128, parse_pow2_u128, parse_pow2_checked_u128, Invalid128);
+
+/// Value of a digit in `RADIX` (letters are case-insensitive)
+#[inline(always)]
+fn to_digit<const RADIX: u32>(c: u8) -> Option<u32> {
+    let digit = match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'z' => c - b'a' + 10,
+        b'A'..=b'Z' => c - b'A' + 10,
+        _ => return None,
+    } as u32;
+    if digit < RADIX {
+        Some(digit)
+    } else {
+        None
+    }
+}
+
+/// Number of digits of `max` in `RADIX`
+#[inline(always)]
+fn max_len<const RADIX: u32>(mut max: u128) -> usize {
+    let mut len = 0;
+    while max > 0 {
+        max /= RADIX as u128;
+        len += 1;
+    }
+    len
+}
+
+macro_rules! parse_generic {
+    ($t:ty, $parse:ident, $parse_checked:ident, $invalid:ident) => {
+        /// Parses digits of any `RADIX` one by one, checking the overflow on every step.
+        /// Without SKIP_ZEROES it fails when there are more (zero) digits than the type can hold.
+        #[inline(always)]
+        fn $parse<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<($t, usize), AtoiSimdError<'_>> {
+            // this many digits can't reach the MAX
+            let safe_len = max_len::<RADIX>(MAX as u128) - 1;
+            let mut res: $t = 0;
+            let mut len = 0;
+            for &c in s {
+                let digit = match to_digit::<RADIX>(c) {
+                    Some(d) => d as $t,
+                    None => break,
+                };
+                res = if len < safe_len {
+                    res * RADIX as $t + digit
+                } else {
+                    match res
+                        .checked_mul(RADIX as $t)
+                        .and_then(|r| r.checked_add(digit))
+                    {
+                        Some(r) if r <= MAX => r,
+                        _ => return Err(AtoiSimdError::Overflow(Sign::Pos, len, s)),
+                    }
+                };
+                len += 1;
+            }
+            if len == 0 {
+                return Err(AtoiSimdError::Empty);
+            }
+            if !SKIP_ZEROES && len > max_len::<RADIX>(<$t>::MAX as u128) {
+                return Err(AtoiSimdError::Size(len, s));
+            }
+
+            Ok((res, len))
+        }
+
+        #[inline(always)]
+        fn $parse_checked<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<$t, AtoiSimdError<'_>> {
+            let (res, len) = $parse::<RADIX, MAX, SKIP_ZEROES>(s)?;
+            if len != s.len() {
+                return Err(AtoiSimdError::$invalid(res as _, len, s));
+            }
+            Ok(res)
+        }
+    };
+}
+
+parse_generic!(u64, parse_generic_u64, parse_generic_checked_u64, Invalid64);
+parse_generic!(
+    u128,
+    parse_generic_u128,
+    parse_generic_checked_u128,
+    Invalid128
+);
+
+macro_rules! parse_radix {
+    ($t:ty, $parse:ident, $parse_checked:ident, $pow2:ident, $pow2_checked:ident, $generic:ident, $generic_checked:ident) => {
+        /// Uses SIMD for the radixes 2, 8 and 16 (10 has its own parser)
+        #[inline(always)]
+        pub(crate) fn $parse<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<($t, usize), AtoiSimdError<'_>> {
+            assert!(2 <= RADIX && RADIX <= 36, "RADIX must be in 2..=36");
+            match RADIX {
+                2 | 8 | 16 => $pow2::<RADIX, MAX, SKIP_ZEROES>(s),
+                _ => $generic::<RADIX, MAX, SKIP_ZEROES>(s),
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn $parse_checked<const RADIX: u32, const MAX: $t, const SKIP_ZEROES: bool>(
+            s: &[u8],
+        ) -> Result<$t, AtoiSimdError<'_>> {
+            assert!(2 <= RADIX && RADIX <= 36, "RADIX must be in 2..=36");
+            match RADIX {
+                2 | 8 | 16 => $pow2_checked::<RADIX, MAX, SKIP_ZEROES>(s),
+                _ => $generic_checked::<RADIX, MAX, SKIP_ZEROES>(s),
+            }
+        }
+    };
+}
+
+parse_radix!(
+    u64,
+    parse_radix_u64,
+    parse_radix_checked_u64,
+    parse_pow2_u64,
+    parse_pow2_checked_u64,
+    parse_generic_u64,
+    parse_generic_checked_u64
+);
+parse_radix!(
+    u128,
+    parse_radix_u128,
+    parse_radix_checked_u128,
+    parse_pow2_u128,
+    parse_pow2_checked_u128,
+    parse_generic_u128,
+    parse_generic_checked_u128
+);
@@ -0,0 +1,131 @@
+#![allow(dead_code)] // some wrappers are unused when `target-feature` already enables SIMD
+
+use crate::AtoiSimdError;
+use ::core::sync::atomic::{AtomicU8, Ordering};
+
+/// SIMD instruction set used by the parser, selected at runtime with the `runtime-dispatch` feature.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SimdLevel {
+    /// Non-SIMD functions
+    Fallback = 0,
+    /// SSE2, SSE3, SSSE3, SSE4.1 (used for types up to 32 bits)
+    Sse41 = 1,
+    /// SSE4.1 and AVX, AVX2 (used for all types)
+    Avx2 = 2,
+}
+
+const UNKNOWN: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+#[inline]
+fn detect() -> SimdLevel {
+    if is_x86_feature_detected!("sse2")
+        && is_x86_feature_detected!("sse3")
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("sse4.1")
+    {
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") {
+            SimdLevel::Avx2
+        } else {
+            SimdLevel::Sse41
+        }
+    } else {
+        SimdLevel::Fallback
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn detect_and_store() -> SimdLevel {
+    let level = detect();
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    level
+}
+
+/// Returns the SIMD level used by the parser.
+/// Detects it on the first call, and caches it.
+#[inline(always)]
+pub fn simd_level() -> SimdLevel {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => SimdLevel::Fallback,
+        1 => SimdLevel::Sse41,
+        2 => SimdLevel::Avx2,
+        _ => detect_and_store(),
+    }
+}
+
+/// Forces the parser to use a lower SIMD level (for example to test or benchmark each path).
+///
+/// Returns `false` and does nothing if the cpu doesn't support the requested level.
+/// Does not affect the paths already selected at compile time with `target-feature`.
+///
+/// The level is global: it changes the parser of every thread, including the concurrent calls
+/// (they stay correct, only slower). Save `simd_level()` before and set it back when done.
+pub fn set_simd_level(level: SimdLevel) -> bool {
+    if level > detect() {
+        return false;
+    }
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    true
+}
+
+/// Selects a function from the table `[fallback, sse41, avx2]` by the cached `simd_level()`
+#[allow(unused_macros)]
+macro_rules! dispatch {
+    ($s:ident, $ret:ty, [$fb:expr, $sse:expr, $avx:expr $(,)?]) => {{
+        let table: [unsafe fn(&[u8]) -> Result<$ret, AtoiSimdError<'_>>; 3] = [$fb, $sse, $avx];
+        // SAFETY: SIMD functions are selected only if the cpu supports them
+        unsafe { table[crate::runtime::simd_level() as usize]($s) }
+    }};
+}
+#[allow(unused_imports)]
+pub(crate) use dispatch;
+
+/// Wraps SIMD functions into `#[target_feature]` ones
+macro_rules! target_feature_fns {
+    ($feature:literal; $($name:ident<$(const $c:ident: $ct:ty),*> -> $ret:ty;)*) => {$(
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn $name<$(const $c: $ct),*>(s: &[u8]) -> Result<$ret, AtoiSimdError<'_>> {
+            shared::$name::<$($c),*>(s)
+        }
+    )*};
+}
+
+pub(crate) mod sse41 {
+    use super::*;
+    use crate::simd::shared_32 as shared;
+
+    target_feature_fns! {
+        "sse2,sse3,ssse3,sse4.1";
+        parse_simd<const MAX: u64, const SKIP_ZEROES: bool> -> (u64, usize);
+        parse_simd_checked<const MAX: u64, const SKIP_ZEROES: bool> -> u64;
+        parse_simd_neg<const MIN: i64, const SKIP_ZEROES: bool> -> (i64, usize);
+        parse_simd_checked_neg<const MIN: i64, const SKIP_ZEROES: bool> -> i64;
+        parse_simd_bin_16<> -> (u64, usize);
+        parse_simd_oct_16<> -> (u64, usize);
+        parse_simd_hex_16<> -> (u64, usize);
+    }
+}
+
+pub(crate) mod avx2 {
+    use super::*;
+    use crate::simd::shared_64 as shared;
+
+    target_feature_fns! {
+        "sse2,sse3,ssse3,sse4.1,avx,avx2";
+        parse_simd_u64<const SKIP_ZEROES: bool> -> (u64, usize);
+        parse_simd_checked_u64<const SKIP_ZEROES: bool> -> u64;
+        parse_simd_i64<const SKIP_ZEROES: bool> -> (i64, usize);
+        parse_simd_checked_i64<const SKIP_ZEROES: bool> -> i64;
+        parse_simd_i64_neg<const SKIP_ZEROES: bool> -> (i64, usize);
+        parse_simd_checked_i64_neg<const SKIP_ZEROES: bool> -> i64;
+        parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool> -> (u128, usize);
+        parse_simd_checked_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool> -> u128;
+        parse_simd_i128<const SKIP_ZEROES: bool> -> (i128, usize);
+        parse_simd_checked_i128<const SKIP_ZEROES: bool> -> i128;
+        parse_simd_i128_neg<const SKIP_ZEROES: bool> -> (i128, usize);
+        parse_simd_checked_i128_neg<const SKIP_ZEROES: bool> -> i128;
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{AtoiSimdError, ParseRadix, Sign};
+use core::iter;
+use debug_unsafe::slice::SliceGetter;
+
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+))]
+use crate::simd::{compact_64, separated_masks};
+
+#[cfg(not(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+)))]
+use crate::fallback::{compact_64_fb as compact_64, separated_masks_fb as separated_masks};
+
+/// Max count of digits in the buffer, more than enough for any integer (without extra zeroes)
+pub(crate) const BUF_LEN: usize = 64;
+
+pub(crate) type Buf = [u8; BUF_LEN + 8];
+
+/// Copies the digits of the number at the beginning of `s` to `buf`, skipping the separators.
+/// Returns the count of the digits, the length of the number in `s`,
+/// and the mask (bit per byte) of the separators in the first 64 bytes of it.
+///
+/// With `strict` a separator must be between two digits, otherwise the number ends before it.
+#[inline(always)]
+fn compact<const SKIP_ZEROES: bool>(
+    s: &[u8],
+    sep: u8,
+    strict: bool,
+    buf: &mut Buf,
+) -> (usize, usize, u64) {
+    let (digits, seps) = separated_masks(s, sep);
+    let seps = if strict {
+        seps & (digits << 1) & (digits >> 1)
+    } else {
+        seps
+    };
+    let len = (!(digits | seps)).trailing_zeros() as usize;
+    if len >= 63 && s.len() > 64 {
+        // the next chars are unknown
+        return compact_long::<SKIP_ZEROES>(s, sep, strict, buf);
+    }
+
+    let run = if len == 0 { 0 } else { u64::MAX >> (64 - len) };
+    (compact_64(s, digits & run, len, buf), len, seps & run)
+}
+
+/// Byte by byte version of `compact` for the numbers longer than 64 chars.
+/// Counts the digits beyond the buffer, but doesn't copy them.
+#[cold]
+fn compact_long<const SKIP_ZEROES: bool>(
+    s: &[u8],
+    sep: u8,
+    strict: bool,
+    buf: &mut Buf,
+) -> (usize, usize, u64) {
+    let mut n = 0;
+    let mut len = 0;
+    let mut seps = 0;
+    let mut zeroes = false;
+    for (i, &c) in s.iter().enumerate() {
+        if c.is_ascii_digit() {
+            if SKIP_ZEROES && n == 0 && c == b'0' {
+                zeroes = true;
+            } else {
+                if n < BUF_LEN {
+                    buf[n] = c;
+                }
+                n += 1;
+            }
+        } else if c != sep
+            || strict
+                && !(i > 0
+                    && s.get_safe_unchecked(i - 1).is_ascii_digit()
+                    && s.get(i + 1).map_or(false, u8::is_ascii_digit))
+        {
+            break;
+        } else if i < 64 {
+            seps |= 1 << i;
+        }
+        len = i + 1;
+    }
+    if n == 0 && zeroes {
+        buf[0] = b'0';
+        n = 1;
+    }
+    (n, len, seps)
+}
+
+/// Index in `s` of the compacted digit `k` (of `n`), the number ends at `len`.
+/// The digits are ASCII, or the UTF-8 chars of `parse_utf8_digits` (their first bytes are counted).
+/// The digits before the compacted ones are the skipped zeroes.
+#[cold]
+fn digit_index(s: &[u8], n: usize, len: usize, k: usize) -> usize {
+    let mut digits = s
+        .get_safe_unchecked(..len)
+        .iter()
+        .enumerate()
+        .filter(|(_, &c)| c.is_ascii_digit() || c >= 0xC0);
+    let skipped = digits.clone().count() - n;
+    digits.nth(skipped + k).map_or(len, |(i, _)| i)
+}
+
+/// The overflow of the significant `digits` (after the `zeroes` of the `n` compacted digits),
+/// at the first digit which makes the value out of range, `len` is the length of the number in `s`
+#[cold]
+fn overflow_at<'a, T: ParseRadix>(
+    s: &'a [u8],
+    n: usize,
+    len: usize,
+    neg: bool,
+    zeroes: usize,
+    digits: &[u8],
+) -> AtoiSimdError<'a> {
+    // the longer runs of the digits are bigger, so it's the shortest one out of range
+    let (mut lo, mut hi) = (0, digits.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if T::atoi_simd_parse_radix_digits::<10, false>(digits.get_safe_unchecked(..=mid), neg)
+            .is_err()
+        {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let sign = if neg { Sign::Neg } else { Sign::Pos };
+    AtoiSimdError::Overflow(sign, digit_index(s, n, len, zeroes + lo), s)
+}
+
+/// Reports the char after the number (at `len`) as invalid, with the value of the significant `digits`
+#[cold]
+fn invalid_after<'a, T: ParseRadix>(s: &'a [u8], len: usize, digits: &[u8]) -> AtoiSimdError<'a> {
+    // the value is in range, so the magnitude fits
+    let mag = digits
+        .iter()
+        .fold(0, |mag: u128, &c| mag * 10 + (c - b'0') as u128);
+    T::atoi_simd_radix_invalid(mag, len, s)
+}
+
+/// Parses `n` compacted digits, `len` is the length of the number in `s` (with the sign).
+/// If `invalid`, reports the char after the number as invalid.
+///
+/// The zeroes at the beginning are skipped here, so the result doesn't depend on the kernel:
+/// without SKIP_ZEROES the number can have up to `BUF_LEN` digits.
+#[inline(always)]
+pub(crate) fn parse_buf<'a, T: ParseRadix, const SKIP_ZEROES: bool>(
+    s: &'a [u8],
+    buf: &Buf,
+    n: usize,
+    len: usize,
+    neg: bool,
+    invalid: bool,
+) -> Result<T, AtoiSimdError<'a>> {
+    if n == 0 {
+        return Err(AtoiSimdError::Empty);
+    }
+    if n > BUF_LEN && !SKIP_ZEROES {
+        return Err(AtoiSimdError::Size(n, s));
+    }
+    // the digits in the buffer overflow anyway, if there are more of them
+    let end = n.min(BUF_LEN);
+    let zeroes = buf
+        .get_safe_unchecked(..end - 1)
+        .iter()
+        .take_while(|&&c| c == b'0')
+        .count();
+    let digits = buf.get_safe_unchecked(zeroes..end);
+    match T::atoi_simd_parse_radix_digits::<10, false>(digits, neg) {
+        Ok(_) if invalid => Err(invalid_after::<T>(s, len, digits)),
+        Ok(v) => Ok(v),
+        // all of the digits are valid, so it's out of range
+        Err(_) => Err(overflow_at::<T>(s, n, len, neg, zeroes, digits)),
+    }
+}
+
+#[inline(always)]
+fn check_separator(sep: u8) {
+    assert!(
+        sep != 0 && sep.is_ascii() && !sep.is_ascii_digit(),
+        "SEPARATOR must be a non-zero ASCII char, and not a digit"
+    );
+}
+
+/// With `strict` the number ends before a separator at the beginning,
+/// so it's the invalid char instead of an empty number
+#[inline(always)]
+fn check_leading<T: ParseRadix>(
+    s: &[u8],
+    sign: usize,
+    n: usize,
+    sep: u8,
+) -> Result<(), AtoiSimdError<'_>> {
+    if n == 0 && s.get(sign) == Some(&sep) {
+        return Err(T::atoi_simd_radix_invalid(0, sign, s));
+    }
+    Ok(())
+}
+
+/// Parses the number with the separator `sep`, it's a constant for `parse_separated`
+/// and a runtime value for `parse_with`
+#[inline(always)]
+pub(crate) fn parse_separated_by<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+    sep: u8,
+    strict: bool,
+) -> Result<T, AtoiSimdError<'_>> {
+    check_separator(sep);
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len, _) = compact::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), sep, strict, &mut buf);
+    if strict {
+        check_leading::<T>(s, sign, n, sep)?;
+    }
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, len != s.len())
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_separated_by<
+    T: ParseRadix,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+    sep: u8,
+    strict: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    check_separator(sep);
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len, _) = compact::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), sep, strict, &mut buf);
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, false).map(|v| (v, len))
+}
+
+#[inline(always)]
+pub(crate) fn parse_separated<
+    T: ParseRadix,
+    const SEP: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    parse_separated_by::<T, SKIP_ZEROES, SKIP_PLUS>(s, SEP, STRICT)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_separated<
+    T: ParseRadix,
+    const SEP: u8,
+    const STRICT: bool,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_prefix_separated_by::<T, SKIP_ZEROES, SKIP_PLUS>(s, SEP, STRICT)
+}
+
+/// Digit grouping schemes for `parse_grouped`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// Groups of 3 digits, like `1,234,567`
+    Western,
+    /// The last group of 3 digits, and groups of 2 before it, like `12,34,567`
+    Indian,
+    /// Groups of any size, like `1,2345,67`
+    Lenient,
+}
+
+impl Grouping {
+    /// Max size of the first group, size of the middle groups, and size of the last group
+    #[inline(always)]
+    fn sizes(self) -> Option<(usize, usize, usize)> {
+        match self {
+            Grouping::Western => Some((3, 3, 3)),
+            Grouping::Indian => Some((2, 2, 3)),
+            Grouping::Lenient => None,
+        }
+    }
+}
+
+/// Checks the positions of the separators (ascending) in a number of `len` chars.
+/// Returns the index of the first char where a separator is required or not allowed.
+#[inline(always)]
+fn check_groups(
+    mut seps: impl Iterator<Item = usize>,
+    len: usize,
+    (first, middle, last): (usize, usize, usize),
+) -> Result<(), usize> {
+    let mut prev = match seps.next() {
+        // not grouped
+        None => return Ok(()),
+        Some(i) if i > first => return Err(first),
+        Some(i) => i,
+    };
+    // it's unknown if the group is the last one, until the next separator
+    let longest = middle.max(last);
+    for i in seps {
+        let size = i - prev - 1;
+        if size > longest {
+            return Err(prev + longest + 1);
+        }
+        if size != middle {
+            return Err(i);
+        }
+        prev = i;
+    }
+    match (len - prev - 1).cmp(&last) {
+        core::cmp::Ordering::Greater => Err(prev + last + 1),
+        core::cmp::Ordering::Less => Err(prev),
+        core::cmp::Ordering::Equal => Ok(()),
+    }
+}
+
+/// Reports the char at `index` as invalid, with the value of the number before it
+#[cold]
+fn invalid_at<T: ParseRadix, const SKIP_ZEROES: bool>(
+    s: &[u8],
+    sign: usize,
+    neg: bool,
+    index: usize,
+    sep: u8,
+) -> AtoiSimdError<'_> {
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, _, _) = compact::<SKIP_ZEROES>(s.get_safe_unchecked(sign..index), sep, false, &mut buf);
+    match parse_buf::<T, SKIP_ZEROES>(s, &buf, n, index, neg, true) {
+        Err(e) => e,
+        Ok(_) => AtoiSimdError::Empty, // unreachable, there are digits before `index`
+    }
+}
+
+/// Checks the separators of the number of `len` chars after the sign
+#[inline(always)]
+fn check_grouping<T: ParseRadix, const SKIP_ZEROES: bool>(
+    s: &[u8],
+    sign: usize,
+    neg: bool,
+    len: usize,
+    mut seps: u64,
+    grouping: Grouping,
+    sep: u8,
+) -> Result<(), AtoiSimdError<'_>> {
+    let sizes = match grouping.sizes() {
+        Some(sizes) => sizes,
+        // any valid separator is fine
+        None => return Ok(()),
+    };
+    if len <= 64 {
+        let positions = iter::from_fn(|| {
+            if seps == 0 {
+                return None;
+            }
+            let i = seps.trailing_zeros() as usize;
+            seps &= seps - 1;
+            Some(i)
+        });
+        check_groups(positions, len, sizes)
+    } else {
+        let positions = s
+            .get_safe_unchecked(sign..sign + len)
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c == sep)
+            .map(|(i, _)| i);
+        check_groups(positions, len, sizes)
+    }
+    .map_err(|i| invalid_at::<T, SKIP_ZEROES>(s, sign, neg, sign + i, sep))
+}
+
+#[inline(always)]
+pub(crate) fn parse_grouped<
+    T: ParseRadix,
+    const SEP: u8,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+    grouping: Grouping,
+) -> Result<T, AtoiSimdError<'_>> {
+    check_separator(SEP);
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len, seps) = compact::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), SEP, true, &mut buf);
+    check_leading::<T>(s, sign, n, SEP)?;
+    check_grouping::<T, SKIP_ZEROES>(s, sign, neg, len, seps, grouping, SEP)?;
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, len != s.len())
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_grouped<
+    T: ParseRadix,
+    const SEP: u8,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+    grouping: Grouping,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    check_separator(SEP);
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len, seps) = compact::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), SEP, true, &mut buf);
+    check_grouping::<T, SKIP_ZEROES>(s, sign, neg, len, seps, grouping, SEP)?;
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, false).map(|v| (v, len))
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::AtoiSimdError;
+use crate::{AtoiSimdError, Sign};
 use debug_unsafe::slice::SliceGetter;
 
 macro_rules! overflow {
@@ -31,7 +31,7 @@ pub(crate) fn parse_short_pos<const MAX: u64>(s: &[u8]) -> Result<(u64, usize),
                         let digit = (c & 0xF) as u64;
 
                         if MAX <= u32::MAX as u64 && overflow!(res * 10 + digit, MAX) {
-                            return Err(AtoiSimdError::Overflow(s));
+                            return Err(AtoiSimdError::Overflow(Sign::Pos, i, s));
                         }
 
                         res = res * 10 + digit;
@@ -46,6 +46,36 @@ pub(crate) fn parse_short_pos<const MAX: u64>(s: &[u8]) -> Result<(u64, usize),
     }
 }
 
+/// Parses the magnitude at the beginning of `s` until the digit which makes it bigger than `max` (`None`),
+/// the check is folded with a constant `max`.
+/// Returns the magnitude and the count of the digits (0 without a digit).
+#[inline(always)]
+pub(crate) fn parse_short_bounded(s: &[u8], max: u128) -> Option<(u128, usize)> {
+    macro_rules! bounded {
+        ($t:ty) => {{
+            let max = max as $t;
+            let mut res: $t = 0;
+            for (i, &c) in s.iter().enumerate() {
+                if !c.is_ascii_digit() {
+                    return Some((res as u128, i));
+                }
+                let digit = (c & 0xF) as $t;
+                if overflow!(res * 10 + digit, max) {
+                    return None;
+                }
+                res = res * 10 + digit;
+            }
+            Some((res as u128, s.len()))
+        }};
+    }
+
+    if max <= u64::MAX as u128 {
+        bounded!(u64)
+    } else {
+        bounded!(u128)
+    }
+}
+
 #[inline]
 pub(crate) fn parse_short_neg<const MIN: i64>(s: &[u8]) -> Result<(i64, usize), AtoiSimdError<'_>> {
     debug_assert!(MIN < 0);
@@ -64,7 +94,7 @@ pub(crate) fn parse_short_neg<const MIN: i64>(s: &[u8]) -> Result<(i64, usize),
 
                         if MIN >= i32::MIN as i64 && overflow_neg!(res * 10 - digit, MIN) {
                             // can't overflow, because MIN is bigger than i64::MIN
-                            return Err(AtoiSimdError::Overflow(s));
+                            return Err(AtoiSimdError::Overflow(Sign::Neg, i, s));
                         }
 
                         res = res * 10 - digit;
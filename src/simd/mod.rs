@@ -1,20 +1,32 @@
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 mod neon;
-#[cfg(all(
-    target_feature = "sse2",
-    target_feature = "sse3",
-    target_feature = "sse4.1",
-    target_feature = "ssse3"
+#[cfg(any(
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
 ))]
 mod sse_avx;
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 pub(crate) use neon::*;
-#[cfg(all(
-    target_feature = "sse2",
-    target_feature = "sse",
-    target_feature = "sse4.1",
-    target_feature = "ssse3"
+#[cfg(any(
+    all(
+        target_feature = "sse2",
+        target_feature = "sse",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+    all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
 ))]
 pub(crate) use sse_avx::*;
 
@@ -38,3 +50,25 @@ pub(crate) fn process_skipped(
         res
     }
 }
+
+/// Shuffle indices to move the bytes marked in the index (bit per byte) to the beginning,
+/// the rest are `0x80` (zeroed by the shuffle)
+pub(crate) static COMPACT_TABLE: [[u8; 8]; 256] = compact_table();
+
+const fn compact_table() -> [[u8; 8]; 256] {
+    let mut table = [[0x80; 8]; 256];
+    let mut mask = 0;
+    while mask < 256 {
+        let mut n = 0;
+        let mut i = 0;
+        while i < 8 {
+            if mask & (1 << i) != 0 {
+                table[mask][n] = i as u8;
+                n += 1;
+            }
+            i += 1;
+        }
+        mask += 1;
+    }
+    table
+}
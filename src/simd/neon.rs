@@ -1,5 +1,5 @@
 use super::process_skipped;
-use crate::AtoiSimdError;
+use crate::{AtoiSimdError, Sign};
 use ::core::{arch::aarch64::*, convert::TryInto};
 use debug_unsafe::slice::SliceGetter;
 
@@ -383,12 +383,240 @@ unsafe fn odd_even_small_32(chunk: uint16x4_t) -> (uint32x2_t, uint32x2_t) {
     (sum, chunk)
 } */
 
+/// Parses up to 16 digits of a power of two `RADIX` (2, 8 or 16)
+#[inline(always)]
+fn parse_simd_pow2_16<const RADIX: u32>(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    unsafe {
+        let chunk = load_16(s);
+
+        // both are inverted, so it's a mask of invalid chars
+        let (check_chunk, numbers) = match RADIX {
+            2 | 8 => {
+                let max = if RADIX == 2 { b'1' } else { b'7' };
+                let check_chunk = vorrq_u8(
+                    vcgtq_u8(chunk, vdupq_n_u8(max)),
+                    vcgtq_u8(vdupq_n_u8(CHAR_MIN), chunk),
+                );
+                (check_chunk, vandq_u8(chunk, vdupq_n_u8(0xF)))
+            }
+            16 => {
+                let check_digit = vorrq_u8(
+                    vcgtq_u8(chunk, vdupq_n_u8(CHAR_MAX)),
+                    vcgtq_u8(vdupq_n_u8(CHAR_MIN), chunk),
+                );
+                let lower = vorrq_u8(chunk, vdupq_n_u8(0x20));
+                let check_alpha = vorrq_u8(
+                    vcgtq_u8(lower, vdupq_n_u8(b'f')),
+                    vcgtq_u8(vdupq_n_u8(b'a'), lower),
+                );
+                // 'a' & 0xF == 1, so adds 9 to the letters
+                let numbers = vaddq_u8(
+                    vandq_u8(chunk, vdupq_n_u8(0xF)),
+                    vbicq_u8(vdupq_n_u8(9), check_alpha),
+                );
+                (vandq_u8(check_digit, check_alpha), numbers)
+            }
+            _ => unreachable!(),
+        };
+
+        // into u64
+        let check_chunk = vreinterpretq_u16_u8(check_chunk);
+        let check_chunk = vshrn_n_u16(check_chunk, 4);
+        let check_chunk = vreinterpret_u64_u8(check_chunk);
+        let res = vget_lane_u64(check_chunk, 0);
+
+        let len = res.trailing_zeros() / 4;
+        crate::assert_unchecked(len <= 16);
+        if len == 0 {
+            return Err(AtoiSimdError::Empty);
+        }
+
+        // zero the chars after len
+        let index = vld1q_u8([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15].as_ptr());
+        let numbers = vandq_u8(numbers, vcltq_u8(index, vdupq_n_u8(len as u8)));
+
+        // combine the digits, first byte is the most significant
+        let shift = RADIX.trailing_zeros() as i32;
+        let chunk = vreinterpretq_u16_u8(numbers);
+        let chunk = vandq_u16(
+            vorrq_u16(
+                vshlq_u16(chunk, vdupq_n_s16(shift as i16)),
+                vshrq_n_u16(chunk, 8),
+            ),
+            vdupq_n_u16(0xFF),
+        );
+        let chunk = vreinterpretq_u32_u16(chunk);
+        let chunk = vandq_u32(
+            vorrq_u32(
+                vshlq_u32(chunk, vdupq_n_s32(2 * shift)),
+                vshrq_n_u32(chunk, 16),
+            ),
+            vdupq_n_u32(0xFFFF),
+        );
+        let chunk = vreinterpretq_u64_u32(chunk);
+        let chunk = vandq_u64(
+            vorrq_u64(
+                vshlq_u64(chunk, vdupq_n_s64(4 * shift as i64)),
+                vshrq_n_u64(chunk, 32),
+            ),
+            vdupq_n_u64(0xFFFF_FFFF),
+        );
+        let res = (vgetq_lane_u64(chunk, 0) << (8 * shift) | vgetq_lane_u64(chunk, 1))
+            >> (shift as u32 * (16 - len));
+
+        Ok((res, len as usize))
+    }
+}
+
+/// Parses up to 16 binary digits
+#[inline(always)]
+pub(crate) fn parse_simd_bin_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<2>(s)
+}
+
+/// Parses up to 16 octal digits
+#[inline(always)]
+pub(crate) fn parse_simd_oct_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<8>(s)
+}
+
+/// Parses up to 16 hex digits
+#[inline(always)]
+pub(crate) fn parse_simd_hex_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<16>(s)
+}
+
+/// Emulates `_mm_movemask_epi8`, takes the lowest bit of each byte
+#[inline(always)]
+unsafe fn movemask(chunk: uint8x16_t) -> u16 {
+    let bits = vandq_u8(
+        chunk,
+        vld1q_u8([1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128].as_ptr()),
+    );
+    vaddv_u8(vget_low_u8(bits)) as u16 | (vaddv_u8(vget_high_u8(bits)) as u16) << 8
+}
+
+/// Returns the masks (bit per byte) of the digits and of the `sep` chars in the first 64 bytes.
+/// `sep` must not be zero, it's used for padding.
+#[inline(always)]
+pub(crate) fn separated_masks(s: &[u8], sep: u8) -> (u64, u64) {
+    let mut digits = 0;
+    let mut seps = 0;
+    let mut i = 0;
+    while i < 64 && i < s.len() {
+        unsafe {
+            let chunk = load_16(s.get_safe_unchecked(i..));
+            let check_chunk = vandq_u8(
+                vcgeq_u8(chunk, vdupq_n_u8(CHAR_MIN)),
+                vcleq_u8(chunk, vdupq_n_u8(CHAR_MAX)),
+            );
+            digits |= (movemask(check_chunk) as u64) << i;
+            seps |= (movemask(vceqq_u8(chunk, vdupq_n_u8(sep))) as u64) << i;
+        }
+        i += 16;
+    }
+    (digits, seps)
+}
+
+/// Copies the bytes marked in `keep` (bit per byte) of the first `len` (up to 64) bytes
+/// to the beginning of `buf`. Returns the count of the copied bytes.
+#[inline(always)]
+pub(crate) fn compact_64(s: &[u8], keep: u64, len: usize, buf: &mut [u8; 72]) -> usize {
+    debug_assert!(len <= 64);
+    let mut n = 0;
+    let mut i = 0;
+    while i < len {
+        unsafe {
+            let chunk = load_16(s.get_safe_unchecked(i..));
+            for half in [vget_low_u8(chunk), vget_high_u8(chunk)] {
+                let mask = (keep >> i) as u8;
+                let shuffle = vld1_u8(
+                    crate::simd::COMPACT_TABLE
+                        .get_safe_unchecked(mask as usize)
+                        .as_ptr(),
+                );
+                // writes 8 bytes, but only `count_ones` of them are needed
+                vst1_u8(buf.as_mut_ptr().add(n), vtbl1_u8(half, shuffle));
+                n += mask.count_ones() as usize;
+                i += 8;
+            }
+        }
+    }
+    n
+}
+
+/// Narrows the UTF-16 units to bytes with saturation (so the units above 0xFF are not digits),
+/// up to the length of `buf`. Returns the count of the narrowed units.
+#[inline(always)]
+pub(crate) fn narrow_16(s: &[u16], buf: &mut [u8]) -> usize {
+    let len = s.len().min(buf.len());
+    let mut i = 0;
+    while i + 16 <= len {
+        unsafe {
+            let low = vqmovn_u16(vld1q_u16(s.as_ptr().add(i)));
+            let high = vqmovn_u16(vld1q_u16(s.as_ptr().add(i + 8)));
+            vst1q_u8(buf.as_mut_ptr().add(i), vcombine_u8(low, high));
+        }
+        i += 16;
+    }
+    while i < len {
+        *buf.get_safe_unchecked_mut(i) = u8::try_from(*s.get_safe_unchecked(i)).unwrap_or(u8::MAX);
+        i += 1;
+    }
+    len
+}
+
+/// Returns the mask (bit per byte) of the ASCII whitespace (`\t`, `\n`, `\x0C`, `\r` and ' ')
+#[inline(always)]
+unsafe fn whitespace_mask(chunk: uint8x16_t) -> u16 {
+    let mut ws = vceqq_u8(chunk, vdupq_n_u8(b' '));
+    for c in [b'\t', b'\n', b'\x0C', b'\r'] {
+        ws = vorrq_u8(ws, vceqq_u8(chunk, vdupq_n_u8(c)));
+    }
+    movemask(ws)
+}
+
+/// Returns the count of the ASCII whitespace chars at the beginning
+#[inline(always)]
+pub(crate) fn whitespace_len(s: &[u8]) -> usize {
+    let mut i = 0;
+    while i < s.len() {
+        // the padding zeroes are not whitespace
+        let len =
+            unsafe { whitespace_mask(load_16(s.get_safe_unchecked(i..))) }.trailing_ones() as usize;
+        if len < 16 {
+            return i + len;
+        }
+        i += 16;
+    }
+    s.len()
+}
+
+/// Returns the count of the ASCII whitespace chars at the end
+#[inline(always)]
+pub(crate) fn whitespace_len_back(s: &[u8]) -> usize {
+    let mut end = s.len();
+    while end != 0 {
+        let start = end.saturating_sub(16);
+        let mask = unsafe { whitespace_mask(load_16(s.get_safe_unchecked(start..end))) };
+        let chunk_len = end - start;
+        let len = (mask << (16 - chunk_len)).leading_ones() as usize;
+        if len < chunk_len {
+            return s.len() - end + len;
+        }
+        end = start;
+    }
+    s.len()
+}
+
 #[inline(always)]
 pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
     mut s: &[u8],
 ) -> Result<(u128, usize), AtoiSimdError<'_>> {
     debug_assert!(LEN_LIMIT > 16, "use `parse_simd_16` instead");
     debug_assert!(LEN_LIMIT <= 39);
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     loop {
         unsafe {
@@ -517,7 +745,7 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
                         }
 
                         if LEN_LIMIT < 32 {
-                            return Err(AtoiSimdError::Size(32, s));
+                            return Err(AtoiSimdError::Size((32 + skipped) as usize, input));
                         }
 
                         let chunk3_16 = vcombine_u8(chunk3, vdup_n_u8(0));
@@ -567,7 +795,10 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
                             }
                             len_extra => {
                                 crate::cold_path();
-                                return Err(AtoiSimdError::Size((len_extra + 32) as usize, s));
+                                return Err(AtoiSimdError::Size(
+                                    (len_extra + 32 + skipped) as usize,
+                                    input,
+                                ));
                             }
                         };
                         chunk3 = vget_low_u8(chunk3_16);
@@ -618,9 +849,11 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
 
                 let extra = (vaddlvq_u32(chunk3) as u128)
                     .checked_mul(100_000_000_000_000_000_000_000_000_000_000)
-                    .ok_or(AtoiSimdError::Overflow(s))?;
+                    .ok_or_else(|| AtoiSimdError::overflow(Sign::Pos, input, u128::MAX))?;
 
-                res = res.checked_add(extra).ok_or(AtoiSimdError::Overflow(s))?;
+                res = res
+                    .checked_add(extra)
+                    .ok_or_else(|| AtoiSimdError::overflow(Sign::Pos, input, u128::MAX))?;
             }
 
             return Ok((res, (len + 16 + skipped) as usize));
@@ -667,7 +900,7 @@ mod test {
         ];
 
         for (input, len) in data {
-            let (loaded_len, _) = unsafe { load_len_16(&mut input.as_ref()) };
+            let (loaded_len, _) = unsafe { load_len_16(&input) };
             assert_eq!(loaded_len, len, "input: {:X?}", input);
         }
     }
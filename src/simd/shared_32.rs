@@ -1,8 +1,10 @@
 use crate::{
     short::{parse_short_neg, parse_short_pos},
-    AtoiSimdError,
+    AtoiSimdError, Sign,
 };
 
+pub(crate) use super::{parse_simd_bin_16, parse_simd_hex_16, parse_simd_oct_16};
+
 #[inline(always)]
 fn parse_simd_checked_pre_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<u64, AtoiSimdError<'_>> {
     let (res, len) = if s.len() < super::SHORT {
@@ -35,7 +37,7 @@ pub(crate) fn parse_simd<const MAX: u64, const SKIP_ZEROES: bool>(
 ) -> Result<(u64, usize), AtoiSimdError<'_>> {
     let (res, len) = super::parse_simd_16::<SKIP_ZEROES>(s)?;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, MAX as u128))
     } else {
         Ok((res, len))
     }
@@ -47,7 +49,7 @@ pub(crate) fn parse_simd_checked<const MAX: u64, const SKIP_ZEROES: bool>(
 ) -> Result<u64, AtoiSimdError<'_>> {
     let res = parse_simd_checked_pre_pos::<SKIP_ZEROES>(s)?;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, MAX as u128))
     } else {
         Ok(res)
     }
@@ -61,7 +63,11 @@ pub(crate) fn parse_simd_neg<const MIN: i64, const SKIP_ZEROES: bool>(
     let (res, len) = super::parse_simd_16::<SKIP_ZEROES>(s)?;
     let min = -MIN as u64;
     if res > min {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(
+            Sign::Neg,
+            s,
+            MIN.unsigned_abs() as u128,
+        ))
     } else if res == min {
         Ok((MIN, len))
     } else {
@@ -76,7 +82,11 @@ pub(crate) fn parse_simd_checked_neg<const MIN: i64, const SKIP_ZEROES: bool>(
     debug_assert!(MIN < 0);
     let res = parse_simd_checked_pre_neg::<SKIP_ZEROES>(s)?;
     if res < MIN {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(
+            Sign::Neg,
+            s,
+            MIN.unsigned_abs() as u128,
+        ))
     } else {
         Ok(res)
     }
@@ -1,6 +1,6 @@
 #![allow(dead_code)] // used when you don't have avx
 
-use crate::{short::parse_short_pos, AtoiSimdError};
+use crate::{short::parse_short_pos, AtoiSimdError, Sign};
 
 pub(crate) use super::parse_simd_u128;
 
@@ -28,7 +28,7 @@ pub(crate) fn parse_simd_i128<const SKIP_ZEROES: bool>(
 ) -> Result<(i128, usize), AtoiSimdError<'_>> {
     let (res, len) = parse_simd_u128::<39, SKIP_ZEROES>(s)?;
     if res > i128::MAX as u128 {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, i128::MAX as u128))
     } else {
         Ok((res as i128, len))
     }
@@ -40,7 +40,7 @@ pub(crate) fn parse_simd_checked_i128<const SKIP_ZEROES: bool>(
 ) -> Result<i128, AtoiSimdError<'_>> {
     let res = parse_simd_checked_u128::<39, SKIP_ZEROES>(s)?;
     if res > i128::MAX as u128 {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, i128::MAX as u128))
     } else {
         Ok(res as i128)
     }
@@ -53,7 +53,7 @@ pub(crate) fn parse_simd_u64<const SKIP_ZEROES: bool>(
     let (res, len) = parse_simd_u128::<20, SKIP_ZEROES>(s)?;
     if res > u64::MAX as u128 {
         crate::cold_path();
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, u64::MAX as u128))
     } else {
         Ok((res as u64, len))
     }
@@ -66,7 +66,7 @@ pub(crate) fn parse_simd_checked_u64<const SKIP_ZEROES: bool>(
     let res = parse_simd_checked_u128::<20, SKIP_ZEROES>(s)?;
     if res > u64::MAX as u128 {
         crate::cold_path();
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, u64::MAX as u128))
     } else {
         Ok(res as u64)
     }
@@ -79,7 +79,7 @@ pub(crate) fn parse_simd_i64<const SKIP_ZEROES: bool>(
     let (res, len) = parse_simd_u128::<19, SKIP_ZEROES>(s)?;
     if res > i64::MAX as u128 {
         crate::cold_path();
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, i64::MAX as u128))
     } else {
         Ok((res as i64, len))
     }
@@ -92,7 +92,7 @@ pub(crate) fn parse_simd_checked_i64<const SKIP_ZEROES: bool>(
     let res = parse_simd_checked_u128::<19, SKIP_ZEROES>(s)?;
     if res > i64::MAX as u128 {
         crate::cold_path();
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Pos, s, i64::MAX as u128))
     } else {
         Ok(res as i64)
     }
@@ -105,7 +105,7 @@ pub(crate) fn parse_simd_i64_neg<const SKIP_ZEROES: bool>(
     let (res, len) = parse_simd_u128::<19, SKIP_ZEROES>(s)?;
     const MAX: u128 = -(i64::MIN as i128) as u128;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Neg, s, MAX))
     } else if res == MAX {
         Ok((i64::MIN, len))
     } else {
@@ -120,7 +120,7 @@ pub(crate) fn parse_simd_checked_i64_neg<const SKIP_ZEROES: bool>(
     let res = parse_simd_checked_u128::<19, SKIP_ZEROES>(s)?;
     const MAX: u128 = -(i64::MIN as i128) as u128;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Neg, s, MAX))
     } else if res == MAX {
         Ok(i64::MIN)
     } else {
@@ -132,10 +132,10 @@ pub(crate) fn parse_simd_checked_i64_neg<const SKIP_ZEROES: bool>(
 pub(crate) fn parse_simd_i128_neg<const SKIP_ZEROES: bool>(
     s: &[u8],
 ) -> Result<(i128, usize), AtoiSimdError<'_>> {
-    let (res, len) = parse_simd_u128::<39, SKIP_ZEROES>(s)?;
+    let (res, len) = parse_simd_u128::<39, SKIP_ZEROES>(s).map_err(AtoiSimdError::neg)?;
     const MAX: u128 = i128::MAX as u128 + 1;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Neg, s, MAX))
     } else if res == MAX {
         Ok((i128::MIN, len))
     } else {
@@ -147,10 +147,10 @@ pub(crate) fn parse_simd_i128_neg<const SKIP_ZEROES: bool>(
 pub(crate) fn parse_simd_checked_i128_neg<const SKIP_ZEROES: bool>(
     s: &[u8],
 ) -> Result<i128, AtoiSimdError<'_>> {
-    let res = parse_simd_checked_u128::<39, SKIP_ZEROES>(s)?;
+    let res = parse_simd_checked_u128::<39, SKIP_ZEROES>(s).map_err(AtoiSimdError::neg)?;
     const MAX: u128 = i128::MAX as u128 + 1;
     if res > MAX {
-        Err(AtoiSimdError::Overflow(s))
+        Err(AtoiSimdError::overflow(Sign::Neg, s, MAX))
     } else if res == MAX {
         Ok(i128::MIN)
     } else {
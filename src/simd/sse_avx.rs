@@ -6,13 +6,16 @@ use self::arch::{
     _mm256_madd_epi16, _mm256_maddubs_epi16, _mm256_movemask_epi8, _mm256_mul_epu32,
     _mm256_or_si256, _mm256_packus_epi32, _mm256_permute2x128_si256, _mm256_permute4x64_epi64,
     _mm256_set1_epi8, _mm256_set_epi16, _mm256_set_epi32, _mm256_set_epi8, _mm256_set_m128i,
-    _mm256_setzero_si256, _mm256_srli_epi64, _mm_add_epi64, _mm_and_si128, _mm_bslli_si128,
-    _mm_cmpeq_epi8, _mm_cmpgt_epi8, _mm_cvtsi128_si32, _mm_loadu_si128, _mm_madd_epi16,
-    _mm_maddubs_epi16, _mm_movemask_epi8, _mm_mul_epu32, _mm_or_si128, _mm_packus_epi32,
-    _mm_set1_epi8, _mm_set_epi16, _mm_set_epi32, _mm_set_epi8, _mm_setzero_si128, _mm_srli_epi64,
+    _mm256_setzero_si256, _mm256_srli_epi64, _mm_add_epi64, _mm_add_epi8, _mm_and_si128,
+    _mm_andnot_si128, _mm_bslli_si128, _mm_bsrli_si128, _mm_cmpeq_epi8, _mm_cmpgt_epi8,
+    _mm_cvtsi128_si32, _mm_loadl_epi64, _mm_loadu_si128, _mm_madd_epi16, _mm_maddubs_epi16,
+    _mm_min_epu16, _mm_movemask_epi8, _mm_mul_epu32, _mm_or_si128, _mm_packus_epi16,
+    _mm_packus_epi32, _mm_set1_epi16, _mm_set1_epi8, _mm_set_epi16, _mm_set_epi32, _mm_set_epi8,
+    _mm_setzero_si128, _mm_shuffle_epi8, _mm_slli_epi16, _mm_srli_epi64, _mm_storel_epi64,
+    _mm_storeu_si128,
 };
 use super::process_skipped;
-use crate::AtoiSimdError;
+use crate::{AtoiSimdError, Sign};
 #[cfg(target_arch = "x86")]
 use ::core::arch::x86 as arch;
 #[cfg(target_arch = "x86_64")]
@@ -664,6 +667,235 @@ pub(crate) fn parse_simd_16<const SKIP_ZEROES: bool>(
     }
 }
 
+/// Parses up to 16 digits of a power of two `RADIX` (2, 8 or 16)
+#[inline(always)]
+fn parse_simd_pow2_16<const RADIX: u32>(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    unsafe {
+        let chunk = load(s);
+
+        // both are inverted, so it's a mask of invalid chars
+        let (check_chunk, numbers) = match RADIX {
+            2 | 8 => {
+                let max = if RADIX == 2 { b'1' } else { b'7' };
+                let check_chunk = _mm_or_si128(
+                    process_gt(chunk, _mm_set1_epi8(max as i8)),
+                    process_gt(_mm_set1_epi8(CHAR_MIN), chunk),
+                );
+                (check_chunk, to_numbers(chunk))
+            }
+            16 => {
+                let check_digit = _mm_or_si128(
+                    process_gt(chunk, _mm_set1_epi8(CHAR_MAX)),
+                    process_gt(_mm_set1_epi8(CHAR_MIN), chunk),
+                );
+                let lower = _mm_or_si128(chunk, _mm_set1_epi8(0x20));
+                let check_alpha = _mm_or_si128(
+                    process_gt(lower, _mm_set1_epi8(b'f' as i8)),
+                    process_gt(_mm_set1_epi8(b'a' as i8), lower),
+                );
+                // 'a' & 0xF == 1, so adds 9 to the letters
+                let numbers = _mm_add_epi8(
+                    to_numbers(chunk),
+                    _mm_andnot_si128(check_alpha, _mm_set1_epi8(9)),
+                );
+                (_mm_and_si128(check_digit, check_alpha), numbers)
+            }
+            _ => unreachable!(),
+        };
+        let len = (_mm_movemask_epi8(check_chunk) as u16).trailing_zeros();
+        if len == 0 {
+            return Err(AtoiSimdError::Empty);
+        }
+
+        // zero the chars after len
+        let index = _mm_set_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let numbers = _mm_and_si128(numbers, process_gt(_mm_set1_epi8(len as i8), index));
+
+        let res = match RADIX {
+            2 => {
+                // move the digit bits into the sign bits
+                let bits = _mm_movemask_epi8(_mm_slli_epi16(numbers, 7)) as u16;
+                // first char is the most significant
+                bits.reverse_bits() as u64 >> (16 - len)
+            }
+            8 => {
+                let chunk = _mm_maddubs_epi16(
+                    numbers,
+                    _mm_set_epi8(1, 8, 1, 8, 1, 8, 1, 8, 1, 8, 1, 8, 1, 8, 1, 8),
+                );
+                let chunk = _mm_madd_epi16(chunk, _mm_set_epi16(1, 64, 1, 64, 1, 64, 1, 64));
+
+                // 4 lanes of 12 bits, the first one is the most significant
+                let arr = ::core::mem::transmute::<__m128i, [u32; 4]>(chunk);
+                ((arr[0] as u64) << 36
+                    | (arr[1] as u64) << 24
+                    | (arr[2] as u64) << 12
+                    | arr[3] as u64)
+                    >> (3 * (16 - len))
+            }
+            16 => {
+                // combine nibbles into bytes
+                let chunk = _mm_maddubs_epi16(
+                    numbers,
+                    _mm_set_epi8(1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16),
+                );
+                let chunk = _mm_packus_epi16(chunk, chunk);
+
+                // first byte is the most significant
+                let arr = ::core::mem::transmute::<__m128i, [u64; 2]>(chunk);
+                u64::from_be(arr[0]) >> (4 * (16 - len))
+            }
+            _ => unreachable!(),
+        };
+
+        Ok((res, len as usize))
+    }
+}
+
+/// Parses up to 16 binary digits
+#[inline(always)]
+pub(crate) fn parse_simd_bin_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<2>(s)
+}
+
+/// Parses up to 16 octal digits
+#[inline(always)]
+pub(crate) fn parse_simd_oct_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<8>(s)
+}
+
+/// Parses up to 16 hex digits
+#[inline(always)]
+pub(crate) fn parse_simd_hex_16(s: &[u8]) -> Result<(u64, usize), AtoiSimdError<'_>> {
+    parse_simd_pow2_16::<16>(s)
+}
+
+/// Returns the masks (bit per byte) of the digits and of the `sep` chars in the first 64 bytes.
+/// `sep` must not be zero, it's used for padding.
+#[inline(always)]
+pub(crate) fn separated_masks(s: &[u8], sep: u8) -> (u64, u64) {
+    let mut digits = 0;
+    let mut seps = 0;
+    let mut i = 0;
+    while i < 64 && i < s.len() {
+        unsafe {
+            let chunk = load(s.get_safe_unchecked(i..));
+            let check_chunk = _mm_or_si128(
+                process_gt(chunk, _mm_set1_epi8(CHAR_MAX)),
+                process_gt(_mm_set1_epi8(CHAR_MIN), chunk),
+            );
+            digits |= (!_mm_movemask_epi8(check_chunk) as u16 as u64) << i;
+            seps |= (_mm_movemask_epi8(_mm_cmpeq_epi8(chunk, _mm_set1_epi8(sep as i8))) as u16
+                as u64)
+                << i;
+        }
+        i += 16;
+    }
+    (digits, seps)
+}
+
+/// Copies the bytes marked in `keep` (bit per byte) of the first `len` (up to 64) bytes
+/// to the beginning of `buf`. Returns the count of the copied bytes.
+#[inline(always)]
+pub(crate) fn compact_64(s: &[u8], keep: u64, len: usize, buf: &mut [u8; 72]) -> usize {
+    debug_assert!(len <= 64);
+    let mut n = 0;
+    let mut i = 0;
+    while i < len {
+        unsafe {
+            let chunk = load(s.get_safe_unchecked(i..));
+            for half in [chunk, _mm_bsrli_si128(chunk, 8)] {
+                let mask = (keep >> i) as u8;
+                let shuffle = _mm_loadl_epi64(
+                    crate::simd::COMPACT_TABLE
+                        .get_safe_unchecked(mask as usize)
+                        .as_ptr() as *const __m128i,
+                );
+                // writes 8 bytes, but only `count_ones` of them are needed
+                _mm_storel_epi64(
+                    buf.as_mut_ptr().add(n) as *mut __m128i,
+                    _mm_shuffle_epi8(half, shuffle),
+                );
+                n += mask.count_ones() as usize;
+                i += 8;
+            }
+        }
+    }
+    n
+}
+
+/// Narrows the UTF-16 units to bytes with saturation (so the units above 0xFF are not digits),
+/// up to the length of `buf`. Returns the count of the narrowed units.
+#[inline(always)]
+pub(crate) fn narrow_16(s: &[u16], buf: &mut [u8]) -> usize {
+    let len = s.len().min(buf.len());
+    let mut i = 0;
+    while i + 16 <= len {
+        unsafe {
+            // `_mm_packus_epi16` saturates the signed units, so the ones from 0x8000 would be 0x00
+            let max = _mm_set1_epi16(0xFF);
+            let low = _mm_min_epu16(_mm_loadu_si128(s.as_ptr().add(i) as *const __m128i), max);
+            let high = _mm_min_epu16(
+                _mm_loadu_si128(s.as_ptr().add(i + 8) as *const __m128i),
+                max,
+            );
+            _mm_storeu_si128(
+                buf.as_mut_ptr().add(i) as *mut __m128i,
+                _mm_packus_epi16(low, high),
+            );
+        }
+        i += 16;
+    }
+    while i < len {
+        *buf.get_safe_unchecked_mut(i) = u8::try_from(*s.get_safe_unchecked(i)).unwrap_or(u8::MAX);
+        i += 1;
+    }
+    len
+}
+
+/// Returns the mask (bit per byte) of the ASCII whitespace (`\t`, `\n`, `\x0C`, `\r` and ' ')
+#[inline(always)]
+unsafe fn whitespace_mask(chunk: __m128i) -> u16 {
+    let mut ws = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b' ' as i8));
+    for c in [b'\t', b'\n', b'\x0C', b'\r'] {
+        ws = _mm_or_si128(ws, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(c as i8)));
+    }
+    _mm_movemask_epi8(ws) as u16
+}
+
+/// Returns the count of the ASCII whitespace chars at the beginning
+#[inline(always)]
+pub(crate) fn whitespace_len(s: &[u8]) -> usize {
+    let mut i = 0;
+    while i < s.len() {
+        // the padding zeroes are not whitespace
+        let len =
+            unsafe { whitespace_mask(load(s.get_safe_unchecked(i..))) }.trailing_ones() as usize;
+        if len < 16 {
+            return i + len;
+        }
+        i += 16;
+    }
+    s.len()
+}
+
+/// Returns the count of the ASCII whitespace chars at the end
+#[inline(always)]
+pub(crate) fn whitespace_len_back(s: &[u8]) -> usize {
+    let mut end = s.len();
+    while end != 0 {
+        let start = end.saturating_sub(16);
+        let mask = unsafe { whitespace_mask(load(s.get_safe_unchecked(start..end))) };
+        let chunk_len = end - start;
+        let len = (mask << (16 - chunk_len)).leading_ones() as usize;
+        if len < chunk_len {
+            return s.len() - end + len;
+        }
+        end = start;
+    }
+    s.len()
+}
+
 /// Uses AVX/AVX2 intrinsics
 #[inline(always)]
 pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
@@ -671,6 +903,8 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
 ) -> Result<(u128, usize), AtoiSimdError<'_>> {
     debug_assert!(LEN_LIMIT > 16, "use `parse_simd_16` instead");
     debug_assert!(LEN_LIMIT <= 39);
+    // the errors are in the indices of the whole input, with the skipped zeroes
+    let input = s;
     let mut skipped = 0;
     loop {
         unsafe {
@@ -732,7 +966,7 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
                         }
 
                         if LEN_LIMIT < 32 {
-                            return Err(AtoiSimdError::Size(32, s));
+                            return Err(AtoiSimdError::Size((32 + skipped) as usize, input));
                         }
                     }
 
@@ -744,7 +978,7 @@ pub(crate) fn parse_simd_u128<const LEN_LIMIT: u32, const SKIP_ZEROES: bool>(
                 }
             };
 
-            return process_avx(s, chunk, len + skipped, chunk_extra, len_extra);
+            return process_avx(input, chunk, len + skipped, chunk_extra, len_extra);
         }
     }
 }
@@ -845,10 +1079,34 @@ unsafe fn process_avx(
         Ok((
             (arr[0] as u128 * 10_000_000_000_000_000 + arr[1] as u128)
                 .checked_mul(mult16 as u128)
-                .ok_or(AtoiSimdError::Overflow(s))?
+                .ok_or_else(|| AtoiSimdError::overflow(Sign::Pos, s, u128::MAX))?
                 .checked_add(arr[2] as u128)
-                .ok_or(AtoiSimdError::Overflow(s))?,
+                .ok_or_else(|| AtoiSimdError::overflow(Sign::Pos, s, u128::MAX))?,
             (len + len_extra) as usize,
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_narrow_16() {
+        let mut buf = [0; 64];
+        // the units from 0x8000 are in the SIMD chunk
+        let mut input = [b'7' as u16; 20];
+        input[1] = 0xFF10;
+        input[5] = 0x8030;
+        input[9] = 0x0131;
+        input[14] = 0xFFFF;
+        input[18] = 0xFF11;
+        assert_eq!(narrow_16(&input, &mut buf), 20);
+        for (i, &c) in input.iter().enumerate() {
+            let expected = u8::try_from(c).unwrap_or(u8::MAX);
+            assert_eq!(buf[i], expected, "unit: {:X}", c);
+        }
+        assert_eq!(narrow_16(&[b'9' as u16; 100], &mut buf), 64);
+        assert!(buf.iter().all(|&c| c == b'9'));
+    }
+}
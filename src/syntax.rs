@@ -0,0 +1,441 @@
+use crate::{AtoiSimdError, CanonicalRule, ParseRadix, Sign};
+use debug_unsafe::slice::SliceGetter;
+
+/// Max count of the compacted digits, enough for any integer in binary (without extra zeroes)
+const BUF_LEN: usize = 128;
+
+/// Integer literal grammars for `parse_syntax`.
+/// All of them accept the '-' sign for signed integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Syntax {
+    /// Rust: the `0x`, `0o` and `0b` prefixes, `_` anywhere after the first digit or the prefix,
+    /// zeroes at the beginning, and an optional type suffix, like `0xFF_u8` or `1_000i64`.
+    /// The value must fit in the type of the suffix (`Overflow`, or `OutOfRange` for the '-' sign of an unsigned one)
+    Rust,
+    /// C (C23): octal with a `0` at the beginning, the `0x`/`0X` and `0b`/`0B` prefixes,
+    /// `'` between digits, and an optional `u`, `l`, `ll` suffix (any case, any order), like `0755` or `1'000UL`.
+    /// The suffix is only skipped, it doesn't limit the value
+    C,
+    /// Python: the `0x`, `0o` and `0b` prefixes (any case), `_` between digits or after the prefix,
+    /// no zeroes at the beginning of a non-zero decimal, and the '+' sign, like `0x_FF` or `1_000`
+    Python,
+    /// TOML 1.0: the `0x`, `0o` and `0b` prefixes (lowercase, without a sign), `_` between digits,
+    /// no zeroes at the beginning of a decimal, and the '+' sign, like `0xdead_beef` or `+1_000`
+    Toml,
+    /// YAML 1.2 core schema: the `0x` and `0o` prefixes (lowercase, without a sign),
+    /// zeroes at the beginning, and the '+' sign, like `0o755` or `+007`
+    Yaml,
+}
+
+impl Syntax {
+    /// Whether the '+' char is a sign
+    #[inline(always)]
+    fn plus(self) -> bool {
+        matches!(self, Syntax::Python | Syntax::Toml | Syntax::Yaml)
+    }
+
+    /// Returns the radix of the number at the beginning of `s` (after the sign) and the length of its prefix
+    #[inline(always)]
+    fn radix_prefix(self, s: &[u8], signed: bool) -> (u32, usize) {
+        match (self, s) {
+            (Syntax::Rust | Syntax::Python, [b'0', b'x', ..])
+            | (Syntax::Python, [b'0', b'X', ..])
+            | (Syntax::C, [b'0', b'x' | b'X', ..]) => (16, 2),
+            (Syntax::Rust | Syntax::Python, [b'0', b'o', ..])
+            | (Syntax::Python, [b'0', b'O', ..]) => (8, 2),
+            (Syntax::Rust | Syntax::Python, [b'0', b'b', ..])
+            | (Syntax::Python, [b'0', b'B', ..])
+            | (Syntax::C, [b'0', b'b' | b'B', ..]) => (2, 2),
+            (Syntax::Toml | Syntax::Yaml, [b'0', b'x', ..]) if !signed => (16, 2),
+            (Syntax::Toml | Syntax::Yaml, [b'0', b'o', ..]) if !signed => (8, 2),
+            (Syntax::Toml, [b'0', b'b', ..]) if !signed => (2, 2),
+            _ => (self.zero_radix(s), 0),
+        }
+    }
+
+    /// Radix of the number without a prefix, in C a `0` at the beginning makes it octal
+    #[inline(always)]
+    fn zero_radix(self, s: &[u8]) -> u32 {
+        match (self, s) {
+            (Syntax::C, [b'0', ..]) => 8,
+            _ => 10,
+        }
+    }
+
+    /// Digit separator
+    #[inline(always)]
+    fn separator(self) -> Option<u8> {
+        match self {
+            Syntax::Rust | Syntax::Python | Syntax::Toml => Some(b'_'),
+            Syntax::C => Some(b'\''),
+            Syntax::Yaml => None,
+        }
+    }
+
+    /// Whether the separator must be between two digits (or right after the prefix, see `separator_after_prefix`)
+    #[inline(always)]
+    fn strict(self) -> bool {
+        !matches!(self, Syntax::Rust)
+    }
+
+    /// Whether the separator can be right after the prefix
+    #[inline(always)]
+    fn separator_after_prefix(self) -> bool {
+        matches!(self, Syntax::Rust | Syntax::Python)
+    }
+
+    /// Checks the zeroes at the beginning of a decimal number
+    #[inline(always)]
+    fn check_zeroes(self, digits: &[u8]) -> bool {
+        match (self, digits) {
+            (Syntax::Python, [b'0', rest @ ..]) => !rest.iter().any(|c| matches!(c, b'1'..=b'9')),
+            (Syntax::Toml, [b'0', _, ..]) => false,
+            _ => true,
+        }
+    }
+
+    /// Length of the type suffix at the beginning of `s`
+    #[inline(always)]
+    fn suffix_len(self, s: &[u8]) -> usize {
+        match self {
+            Syntax::Rust => match s {
+                [b'u' | b'i', b'1', b'2', b'8', ..] => 4,
+                [b'u' | b'i', b's', b'i', b'z', b'e', ..] => 5,
+                [b'u' | b'i', b'1', b'6', ..]
+                | [b'u' | b'i', b'3', b'2', ..]
+                | [b'u' | b'i', b'6', b'4', ..] => 3,
+                [b'u' | b'i', b'8', ..] => 2,
+                _ => 0,
+            },
+            Syntax::C => {
+                let unsigned = |s: &[u8]| matches!(s, [b'u' | b'U', ..]) as usize;
+                let long = |s: &[u8]| match s {
+                    [b'l', b'l', ..] | [b'L', b'L', ..] => 2,
+                    [b'l' | b'L', ..] => 1,
+                    _ => 0,
+                };
+                let u = unsigned(s);
+                let l = long(s.get_safe_unchecked(u..));
+                // `u` can be either before or after `l`
+                u + l
+                    + if u == 0 {
+                        unsigned(s.get_safe_unchecked(l..))
+                    } else {
+                        0
+                    }
+            }
+            _ => 0,
+        }
+    }
+}
+
+#[inline(always)]
+fn is_digit(c: u8, radix: u32) -> bool {
+    (c as char).is_digit(radix)
+}
+
+/// Copies the digits of the number at `start` to `buf`, skipping the separators and the zeroes at the beginning.
+/// Returns the count of the digits (can be more than `BUF_LEN`, they aren't copied) and the end of the number.
+#[cold]
+fn compact(
+    s: &[u8],
+    start: usize,
+    radix: u32,
+    syntax: Syntax,
+    lead_sep: bool,
+    buf: &mut [u8; BUF_LEN + 1],
+) -> (usize, usize) {
+    let sep = syntax.separator();
+    let mut n = 0;
+    let mut end = start;
+    let mut zeroes = false;
+    for (i, &c) in s.iter().enumerate().skip(start) {
+        if is_digit(c, radix) {
+            if n == 0 && c == b'0' {
+                zeroes = true;
+            } else {
+                if n < BUF_LEN {
+                    buf[n] = c;
+                }
+                n += 1;
+            }
+        } else if Some(c) != sep
+            || i == start && !lead_sep
+            || syntax.strict()
+                && !((i == start || s.get_safe_unchecked(i - 1) != &c)
+                    && s.get(i + 1).map_or(false, |&c| is_digit(c, radix)))
+        {
+            break;
+        }
+        end = i + 1;
+    }
+    if n == 0 && zeroes {
+        buf[0] = b'0';
+        n = 1;
+    }
+    (n, end)
+}
+
+#[inline(always)]
+fn parse_digits<T: ParseRadix, const RADIX: u32>(
+    digits: &[u8],
+    neg: bool,
+) -> Result<T, AtoiSimdError<'_>> {
+    T::atoi_simd_parse_radix_digits::<RADIX, false>(digits, neg)
+}
+
+#[inline(always)]
+fn parse_digits_radix<T: ParseRadix>(
+    digits: &[u8],
+    radix: u32,
+    neg: bool,
+) -> Result<T, AtoiSimdError<'_>> {
+    match radix {
+        16 => parse_digits::<T, 16>(digits, neg),
+        8 => parse_digits::<T, 8>(digits, neg),
+        2 => parse_digits::<T, 2>(digits, neg),
+        _ => parse_digits::<T, 10>(digits, neg),
+    }
+}
+
+/// Index of the compacted digit `k` of the number at `start` (it ends at `end`)
+#[inline(always)]
+fn digit_index(s: &[u8], start: usize, end: usize, radix: u32, k: usize) -> usize {
+    s.get_safe_unchecked(..end)
+        .iter()
+        .enumerate()
+        .skip(start)
+        .filter(|&(_, &c)| is_digit(c, radix))
+        .skip_while(|&(_, &c)| c == b'0')
+        .nth(k)
+        .map_or(end, |(i, _)| i)
+}
+
+/// The overflow of the `digits` compacted from the number at `start` (it ends at `end`),
+/// at the first digit which makes the value out of range
+#[cold]
+fn overflow_at<'a, T: ParseRadix>(
+    s: &'a [u8],
+    start: usize,
+    end: usize,
+    radix: u32,
+    neg: bool,
+    digits: &[u8],
+) -> AtoiSimdError<'a> {
+    // the longer runs of the digits are bigger, so it's the shortest one out of range
+    let (mut lo, mut hi) = (0, digits.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if parse_digits_radix::<T>(digits.get_safe_unchecked(..=mid), radix, neg).is_err() {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let sign = if neg { Sign::Neg } else { Sign::Pos };
+    AtoiSimdError::Overflow(sign, digit_index(s, start, end, radix, lo), s)
+}
+
+/// Parses the number with the separators at `start`, returns the value and the end of the number
+#[cold]
+fn parse_run_separated<T: ParseRadix, const RADIX: u32>(
+    s: &[u8],
+    start: usize,
+    neg: bool,
+    syntax: Syntax,
+    lead_sep: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let mut buf = [0; BUF_LEN + 1];
+    let (n, end) = compact(s, start, RADIX, syntax, lead_sep, &mut buf);
+    match n {
+        0 => Err(AtoiSimdError::Empty),
+        n if n > BUF_LEN => Err(overflow_at::<T>(
+            s,
+            start,
+            end,
+            RADIX,
+            neg,
+            buf.get_safe_unchecked(..BUF_LEN),
+        )),
+        // all of the digits are valid, so it's too long
+        n => {
+            let digits = buf.get_safe_unchecked(..n);
+            parse_digits::<T, RADIX>(digits, neg)
+                .map(|v| (v, end))
+                .map_err(|_| overflow_at::<T>(s, start, end, RADIX, neg, digits))
+        }
+    }
+}
+
+/// Parses the number at `start`, returns the value and the end of the number
+#[inline(always)]
+fn parse_run<T: ParseRadix, const RADIX: u32>(
+    s: &[u8],
+    start: usize,
+    neg: bool,
+    syntax: Syntax,
+    lead_sep: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let digits = s.get_safe_unchecked(start..);
+    let res = T::atoi_simd_parse_prefix_radix_digits::<RADIX, true>(digits, neg);
+    match (res, syntax.separator()) {
+        (Ok((_, len)), Some(sep)) if digits.get(len) == Some(&sep) => {
+            parse_run_separated::<T, RADIX>(s, start, neg, syntax, lead_sep)
+        }
+        (Err(AtoiSimdError::Empty), Some(sep)) if lead_sep && digits.first() == Some(&sep) => {
+            parse_run_separated::<T, RADIX>(s, start, neg, syntax, lead_sep)
+        }
+        (Ok((v, len)), _) => Ok((v, start + len)),
+        (Err(e), _) => Err(e.with_offset(start, s)),
+    }
+}
+
+#[inline(always)]
+fn parse_run_radix<T: ParseRadix>(
+    s: &[u8],
+    start: usize,
+    radix: u32,
+    neg: bool,
+    syntax: Syntax,
+    lead_sep: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    match radix {
+        16 => parse_run::<T, 16>(s, start, neg, syntax, lead_sep),
+        8 => parse_run::<T, 8>(s, start, neg, syntax, lead_sep),
+        2 => parse_run::<T, 2>(s, start, neg, syntax, lead_sep),
+        _ => parse_run::<T, 10>(s, start, neg, syntax, lead_sep),
+    }
+}
+
+/// Reports the char at `index` as invalid, with the value of the number at `start` (it ends at `end`)
+#[cold]
+fn invalid_at<T: ParseRadix>(
+    s: &[u8],
+    start: usize,
+    end: usize,
+    radix: u32,
+    neg: bool,
+    index: usize,
+) -> AtoiSimdError<'_> {
+    let mut buf = [0; BUF_LEN + 1];
+    // the number is already checked, any separator in it is fine
+    let (n, _) = compact(
+        s.get_safe_unchecked(..end),
+        start,
+        radix,
+        Syntax::Rust,
+        true,
+        &mut buf,
+    );
+    if n > BUF_LEN {
+        return overflow_at::<T>(s, start, end, radix, neg, buf.get_safe_unchecked(..BUF_LEN));
+    }
+    // the parser will report it with the value
+    buf[n] = b'/';
+    let digits = buf.get_safe_unchecked(..n + 1);
+    match parse_digits_radix::<T>(digits, radix, neg) {
+        Err(AtoiSimdError::Invalid64(v, _, _)) => AtoiSimdError::Invalid64(v, index, s),
+        Err(AtoiSimdError::Invalid128(v, _, _)) => AtoiSimdError::Invalid128(v, index, s),
+        _ => overflow_at::<T>(s, start, end, radix, neg, buf.get_safe_unchecked(..n)),
+    }
+}
+
+/// Checks that the number fits in the type of the suffix
+#[inline(always)]
+fn fits<T: ParseRadix>(
+    s: &[u8],
+    start: usize,
+    radix: u32,
+    neg: bool,
+    lead_sep: bool,
+) -> Result<(), AtoiSimdError<'_>> {
+    parse_run_radix::<T>(s, start, radix, neg, Syntax::Rust, lead_sep).map(|_| ())
+}
+
+/// Checks the value of the number at `start` (it ends at `end`) against the range of the Rust type `suffix`,
+/// like the compiler does: `300u8` is out of range even for a wider integer
+#[cold]
+fn check_suffix<'a>(
+    s: &'a [u8],
+    start: usize,
+    end: usize,
+    radix: u32,
+    neg: bool,
+    lead_sep: bool,
+    suffix: &[u8],
+) -> Result<(), AtoiSimdError<'a>> {
+    let number = s.get_safe_unchecked(..end);
+    match suffix {
+        [b'u', ..] if neg => return Err(AtoiSimdError::OutOfRange(s)),
+        b"u8" => fits::<u8>(number, start, radix, neg, lead_sep),
+        b"i8" => fits::<i8>(number, start, radix, neg, lead_sep),
+        b"u16" => fits::<u16>(number, start, radix, neg, lead_sep),
+        b"i16" => fits::<i16>(number, start, radix, neg, lead_sep),
+        b"u32" => fits::<u32>(number, start, radix, neg, lead_sep),
+        b"i32" => fits::<i32>(number, start, radix, neg, lead_sep),
+        b"u64" => fits::<u64>(number, start, radix, neg, lead_sep),
+        b"i64" => fits::<i64>(number, start, radix, neg, lead_sep),
+        b"usize" => fits::<usize>(number, start, radix, neg, lead_sep),
+        b"isize" => fits::<isize>(number, start, radix, neg, lead_sep),
+        b"u128" => fits::<u128>(number, start, radix, neg, lead_sep),
+        b"i128" => fits::<i128>(number, start, radix, neg, lead_sep),
+        _ => Ok(()),
+    }
+    .map_err(|e| e.with_offset(0, s))
+}
+
+#[inline(always)]
+fn parse_syntax_impl<T: ParseRadix>(
+    s: &[u8],
+    syntax: Syntax,
+    prefix: bool,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let (sign, neg) = if syntax.plus() {
+        T::atoi_simd_radix_sign::<true>(s)?
+    } else {
+        T::atoi_simd_radix_sign::<false>(s)?
+    };
+    let rest = s.get_safe_unchecked(sign..);
+    let (mut radix, len_prefix) = syntax.radix_prefix(rest, sign != 0);
+    let mut start = sign + len_prefix;
+    let mut lead_sep = len_prefix != 0 && syntax.separator_after_prefix();
+    let (v, end) = match parse_run_radix::<T>(s, start, radix, neg, syntax, lead_sep) {
+        // no digits after the prefix, so it's just a zero followed by a letter
+        Err(AtoiSimdError::Empty) if len_prefix != 0 => {
+            radix = syntax.zero_radix(rest);
+            start = sign;
+            lead_sep = false;
+            parse_run_radix::<T>(s, start, radix, neg, syntax, lead_sep)?
+        }
+        res => res?,
+    };
+    if radix == 10 && !syntax.check_zeroes(s.get_safe_unchecked(start..end)) {
+        return Err(AtoiSimdError::NonCanonical(CanonicalRule::LeadingZero, s));
+    }
+    let len = end + syntax.suffix_len(s.get_safe_unchecked(end..));
+    // the C suffixes only make the type wider
+    if syntax == Syntax::Rust && len != end {
+        let suffix = s.get_safe_unchecked(end..len);
+        check_suffix(s, start, end, radix, neg, lead_sep, suffix)?;
+    }
+    if !prefix && len != s.len() {
+        return Err(invalid_at::<T>(s, start, end, radix, neg, len));
+    }
+    Ok((v, len))
+}
+
+#[inline(always)]
+pub(crate) fn parse_syntax<T: ParseRadix>(
+    s: &[u8],
+    syntax: Syntax,
+) -> Result<T, AtoiSimdError<'_>> {
+    parse_syntax_impl::<T>(s, syntax, false).map(|(v, _)| v)
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_syntax<T: ParseRadix>(
+    s: &[u8],
+    syntax: Syntax,
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    parse_syntax_impl::<T>(s, syntax, true)
+}
@@ -0,0 +1,294 @@
+use crate::{
+    linker::{ParseNeg, ParsePos},
+    AtoiSimdError, Sign,
+};
+use debug_unsafe::slice::SliceGetter;
+
+/// Digits parsed at once by the `u128` parser (the length of its SIMD kernel)
+const CHUNK_LEN: usize = 32;
+/// Max count of the digits (with the zeroes at the beginning) without SKIP_ZEROES, the length of `U256::MAX`
+const MAX_LEN: usize = 78;
+
+const POW10: [u128; CHUNK_LEN + 1] = {
+    let mut pow = [1; CHUNK_LEN + 1];
+    let mut i = 1;
+    while i < pow.len() {
+        pow[i] = pow[i - 1] * 10;
+        i += 1;
+    }
+    pow
+};
+
+/// 256-bit unsigned integer, `hi * 2^128 + lo`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256 {
+    pub hi: u128,
+    pub lo: u128,
+}
+
+/// 256-bit signed integer (two's complement), `hi * 2^128 + lo`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I256 {
+    pub hi: i128,
+    pub lo: u128,
+}
+
+impl U256 {
+    pub const ZERO: Self = Self { hi: 0, lo: 0 };
+    pub const MAX: Self = Self {
+        hi: u128::MAX,
+        lo: u128::MAX,
+    };
+
+    /// `self * mul + add`, `None` on overflow
+    #[inline(always)]
+    fn checked_mul_add(self, mul: u128, add: u128) -> Option<Self> {
+        let a = [
+            self.lo as u64,
+            (self.lo >> 64) as u64,
+            self.hi as u64,
+            (self.hi >> 64) as u64,
+        ];
+        let m = [mul as u64, (mul >> 64) as u64];
+        let mut r = [0_u64; 6];
+        for (i, &a) in a.iter().enumerate() {
+            let mut carry = 0_u128;
+            for (j, &m) in m.iter().enumerate() {
+                let v = a as u128 * m as u128 + r[i + j] as u128 + carry;
+                r[i + j] = v as u64;
+                carry = v >> 64;
+            }
+            r[i + 2] = carry as u64;
+        }
+        if r[4] | r[5] != 0 {
+            return None;
+        }
+        let (lo, carry) = (r[0] as u128 | (r[1] as u128) << 64).overflowing_add(add);
+        let hi = (r[2] as u128 | (r[3] as u128) << 64).checked_add(carry as u128)?;
+        Some(Self { hi, lo })
+    }
+}
+
+impl I256 {
+    pub const ZERO: Self = Self { hi: 0, lo: 0 };
+    pub const MAX: Self = Self {
+        hi: i128::MAX,
+        lo: u128::MAX,
+    };
+    pub const MIN: Self = Self {
+        hi: i128::MIN,
+        lo: 0,
+    };
+
+    /// The positive value of the magnitude, `None` on overflow
+    #[inline(always)]
+    fn from_pos(v: U256) -> Option<Self> {
+        if v.hi > i128::MAX as u128 {
+            return None;
+        }
+        Some(Self {
+            hi: v.hi as i128,
+            lo: v.lo,
+        })
+    }
+
+    /// The negative value of the magnitude, `None` on overflow
+    #[inline(always)]
+    fn from_neg(v: U256) -> Option<Self> {
+        if v.hi > i128::MIN.unsigned_abs() || (v.hi == i128::MIN.unsigned_abs() && v.lo != 0) {
+            return None;
+        }
+        let (lo, borrow) = 0_u128.overflowing_sub(v.lo);
+        Some(Self {
+            hi: (v.hi as i128).wrapping_neg().wrapping_sub(borrow as i128),
+            lo,
+        })
+    }
+}
+
+impl From<u128> for U256 {
+    #[inline]
+    fn from(lo: u128) -> Self {
+        Self { hi: 0, lo }
+    }
+}
+
+impl From<i128> for I256 {
+    #[inline]
+    fn from(v: i128) -> Self {
+        Self {
+            hi: v >> 127,
+            lo: v as u128,
+        }
+    }
+}
+
+/// Parses up to 78 digits by 32-digit chunks with the `u128` parser,
+/// any amount of zeroes at the beginning is skipped with SKIP_ZEROES.
+/// Returns the value and the count of the parsed digits.
+#[inline(always)]
+fn parse_u256<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<(U256, usize), AtoiSimdError<'_>> {
+    let mut res = U256::ZERO;
+    let mut len = 0;
+    loop {
+        let chunk = s.get_safe_unchecked(len..s.len().min(len + CHUNK_LEN));
+        let (v, chunk_len) = u128::atoi_simd_parse_prefix_pos::<false>(chunk).unwrap_or((0, 0));
+        if chunk_len == 0 {
+            break;
+        }
+        res = res
+            .checked_mul_add(POW10[chunk_len], v)
+            .ok_or_else(|| overflow(s, Sign::Pos, |_| true))?;
+        len += chunk_len;
+        if !SKIP_ZEROES && len > MAX_LEN {
+            return Err(AtoiSimdError::Size(len, s));
+        }
+        if chunk_len < CHUNK_LEN {
+            break;
+        }
+    }
+    if len == 0 {
+        return Err(AtoiSimdError::Empty);
+    }
+    Ok((res, len))
+}
+
+/// The overflow of the number, at the digit which makes the magnitude out of range (`fits` is false)
+#[cold]
+fn overflow(s: &[u8], sign: Sign, fits: impl Fn(U256) -> bool) -> AtoiSimdError<'_> {
+    let mut mag = U256::ZERO;
+    let mut index = 0;
+    for &c in s.iter().take_while(|c| c.is_ascii_digit()) {
+        match mag.checked_mul_add(10, (c & 0xF) as u128) {
+            Some(m) if fits(m) => mag = m,
+            _ => break,
+        }
+        index += 1;
+    }
+    AtoiSimdError::Overflow(sign, index, s)
+}
+
+/// Converts the magnitude to `I256` with `from`, the overflow is reported at the digit
+/// which makes it out of range of `I256`
+#[inline(always)]
+fn to_i256<'a>(
+    res: Result<(U256, usize), AtoiSimdError<'a>>,
+    s: &'a [u8],
+    sign: Sign,
+    from: fn(U256) -> Option<I256>,
+) -> Result<(I256, usize), AtoiSimdError<'a>> {
+    match res {
+        Ok((v, len)) => match from(v) {
+            Some(v) => Ok((v, len)),
+            None => Err(overflow(s, sign, |v| from(v).is_some())),
+        },
+        Err(AtoiSimdError::Overflow(..)) => Err(overflow(s, sign, |v| from(v).is_some())),
+        Err(e) => Err(e),
+    }
+}
+
+#[inline(always)]
+fn parse_u256_checked<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<U256, AtoiSimdError<'_>> {
+    let (res, len) = parse_u256::<SKIP_ZEROES>(s)?;
+    if len != s.len() {
+        return Err(AtoiSimdError::Invalid128(res.lo, len, s));
+    }
+    Ok(res)
+}
+
+impl ParsePos for U256 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<Self, AtoiSimdError<'_>> {
+        parse_u256_checked::<SKIP_ZEROES>(s)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        parse_u256::<SKIP_ZEROES>(s)
+    }
+}
+
+impl ParsePos for I256 {
+    #[inline(always)]
+    fn atoi_simd_parse_pos<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<Self, AtoiSimdError<'_>> {
+        let res = parse_u256_checked::<SKIP_ZEROES>(s).map(|v| (v, s.len()));
+        to_i256(res, s, Sign::Pos, I256::from_pos).map(|(v, _)| v)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_pos<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        to_i256(parse_u256::<SKIP_ZEROES>(s), s, Sign::Pos, I256::from_pos)
+    }
+}
+
+impl ParseNeg for I256 {
+    #[inline(always)]
+    fn atoi_simd_parse_neg<const SKIP_ZEROES: bool>(s: &[u8]) -> Result<Self, AtoiSimdError<'_>> {
+        let res = parse_u256_checked::<SKIP_ZEROES>(s).map(|v| (v, s.len()));
+        to_i256(res, s, Sign::Neg, I256::from_neg).map(|(v, _)| v)
+    }
+
+    #[inline(always)]
+    fn atoi_simd_parse_prefix_neg<const SKIP_ZEROES: bool>(
+        s: &[u8],
+    ) -> Result<(Self, usize), AtoiSimdError<'_>> {
+        to_i256(parse_u256::<SKIP_ZEROES>(s), s, Sign::Neg, I256::from_neg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checked_mul_add() {
+        assert_eq!(
+            U256::from(u128::MAX).checked_mul_add(u128::MAX, u128::MAX),
+            Some(U256 {
+                hi: u128::MAX,
+                lo: 0
+            })
+        );
+        assert_eq!(U256::MAX.checked_mul_add(1, 0), Some(U256::MAX));
+        assert_eq!(U256::MAX.checked_mul_add(1, 1), None);
+        assert_eq!(U256::MAX.checked_mul_add(2, 0), None);
+        assert_eq!(
+            U256 { hi: 1, lo: 0 }.checked_mul_add(1 << 127, 0),
+            Some(U256 {
+                hi: 1 << 127,
+                lo: 0
+            })
+        );
+        assert_eq!(U256 { hi: 2, lo: 0 }.checked_mul_add(1 << 127, 0), None);
+        assert_eq!(POW10[CHUNK_LEN], 10_u128.pow(32));
+    }
+
+    #[test]
+    fn test_i256() {
+        assert_eq!(I256::from_neg(U256::from(1)), Some(I256::from(-1)));
+        assert_eq!(
+            I256::from_neg(U256 { hi: 1, lo: 0 }),
+            Some(I256 { hi: -1, lo: 0 })
+        );
+        assert_eq!(
+            I256::from_neg(U256 {
+                hi: i128::MIN.unsigned_abs(),
+                lo: 0
+            }),
+            Some(I256::MIN)
+        );
+        assert_eq!(
+            I256::from_neg(U256 {
+                hi: i128::MIN.unsigned_abs(),
+                lo: 1
+            }),
+            None
+        );
+        assert_eq!(I256::from_neg(U256::ZERO), Some(I256::ZERO));
+        assert!(I256::MIN < I256::from(-1) && I256::from(-1) < I256::ZERO);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    separated::{parse_buf, Buf, BUF_LEN},
+    AtoiSimdError, ParseRadix,
+};
+use debug_unsafe::slice::SliceGetter;
+
+/// Zero digits of all the Unicode `Nd` (decimal digit) blocks, sorted.
+/// Each block has 10 consecutive digits from 0 to 9 (Unicode 16.0).
+const ZEROES: [u32; 76] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450,
+    0x114D0, 0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50,
+    0x11D50, 0x11DA0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0, 0x1D7CE,
+    0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950, 0x1FBF0,
+];
+
+/// Returns the zero of the digit's block, if it's a decimal digit
+#[inline(always)]
+fn digit_zero(c: char) -> Option<u32> {
+    let c = c as u32;
+    let zero = match ZEROES.binary_search(&c) {
+        Ok(_) => return Some(c),
+        Err(0) => return None,
+        Err(i) => *ZEROES.get_safe_unchecked(i - 1),
+    };
+    (c - zero < 10).then(|| zero)
+}
+
+/// Decodes the first UTF-8 char of the slice, and returns it with its length in bytes
+#[inline(always)]
+fn decode(s: &[u8]) -> Option<(char, usize)> {
+    let s = s.get_safe_unchecked(..s.len().min(4));
+    let s = match core::str::from_utf8(s) {
+        Ok(s) => s,
+        Err(e) => core::str::from_utf8(s.get_safe_unchecked(..e.valid_up_to())).ok()?,
+    };
+    s.chars().next().map(|c| (c, c.len_utf8()))
+}
+
+/// Converts the digits at the beginning of `s` to ASCII in `buf`.
+/// All of the digits must be from the block of the first one.
+/// Returns the count of the digits (also beyond the buffer) and the length of the number in `s`.
+#[inline(always)]
+fn transcode<const SKIP_ZEROES: bool>(s: &[u8], buf: &mut Buf) -> (usize, usize) {
+    let zero = match decode(s).and_then(|(c, _)| digit_zero(c)) {
+        Some(zero) => zero,
+        None => return (0, 0),
+    };
+    let mut n = 0;
+    let mut len = 0;
+    let mut zeroes = false;
+    while let Some((c, l)) = decode(s.get_safe_unchecked(len..)) {
+        let digit = (c as u32).wrapping_sub(zero);
+        if digit >= 10 {
+            break;
+        }
+        if SKIP_ZEROES && n == 0 && digit == 0 {
+            zeroes = true;
+        } else {
+            if n < BUF_LEN {
+                *buf.get_safe_unchecked_mut(n) = b'0' + digit as u8;
+            }
+            n += 1;
+        }
+        len += l;
+    }
+    if n == 0 && zeroes {
+        buf[0] = b'0';
+        n = 1;
+    }
+    (n, len)
+}
+
+#[inline(always)]
+pub(crate) fn parse_utf8_digits<T: ParseRadix, const SKIP_ZEROES: bool, const SKIP_PLUS: bool>(
+    s: &[u8],
+) -> Result<T, AtoiSimdError<'_>> {
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    if s.get(sign).map_or(true, u8::is_ascii) {
+        return T::atoi_simd_parse_radix::<10, SKIP_ZEROES, SKIP_PLUS>(s);
+    }
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len) = transcode::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), &mut buf);
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, len != s.len())
+}
+
+#[inline(always)]
+pub(crate) fn parse_prefix_utf8_digits<
+    T: ParseRadix,
+    const SKIP_ZEROES: bool,
+    const SKIP_PLUS: bool,
+>(
+    s: &[u8],
+) -> Result<(T, usize), AtoiSimdError<'_>> {
+    let (sign, neg) = T::atoi_simd_radix_sign::<SKIP_PLUS>(s)?;
+    if s.get(sign).map_or(true, u8::is_ascii) {
+        return T::atoi_simd_parse_prefix_radix::<10, SKIP_ZEROES, SKIP_PLUS>(s);
+    }
+    let mut buf = [0; BUF_LEN + 8];
+    let (n, len) = transcode::<SKIP_ZEROES>(s.get_safe_unchecked(sign..), &mut buf);
+    let len = sign + len;
+    parse_buf::<T, SKIP_ZEROES>(s, &buf, n, len, neg, false).map(|v| (v, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zeroes() {
+        assert!(ZEROES.windows(2).all(|w| w[0] + 10 <= w[1]));
+        for zero in ZEROES {
+            for d in 0..10 {
+                let c = char::from_u32(zero + d).unwrap();
+                assert!(c.is_numeric(), "{:X}", zero + d);
+                assert_eq!(digit_zero(c), Some(zero));
+            }
+        }
+        assert_eq!(digit_zero('a'), None);
+        assert_eq!(digit_zero('\u{66A}'), None);
+        assert_eq!(digit_zero('\u{1FBFA}'), None);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::AtoiSimdError;
+use debug_unsafe::slice::SliceGetter;
+
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+))]
+use crate::simd::narrow_16;
+
+#[cfg(not(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+)))]
+use crate::fallback::narrow_16_fb as narrow_16;
+
+/// Max count of the narrowed units of any format
+const BUF_LEN: usize = 160;
+
+/// The zero digit
+const ZERO: u16 = b'0' as u16;
+
+/// The number format of the narrowed parser
+#[derive(Clone, Copy)]
+pub(crate) struct Format {
+    /// Count of the units before the digits (the sign and the radix prefix), extra zeroes are after them
+    lead: fn(&[u16]) -> usize,
+    /// Whether the narrowed char can be in the number
+    part: fn(&u8) -> bool,
+    /// Max count of the narrowed units, more than the byte parsers of the format read
+    /// before they reject a number (without extra zeroes)
+    len: usize,
+    /// Whether the byte parser reads the whole number, so a longer one is too long
+    whole: fn(&[u16]) -> bool,
+}
+
+impl Format {
+    /// The decimal parsers reject a long number after a few chunks of it
+    pub(crate) const DECIMAL: Self = Self {
+        lead: sign_len,
+        part: u8::is_ascii_digit,
+        len: 64,
+        whole: |_| false,
+    };
+
+    #[inline(always)]
+    pub(crate) fn auto<const SKIP_ZEROES: bool>() -> Self {
+        Self {
+            lead: auto_lead_len,
+            part: u8::is_ascii_alphanumeric,
+            len: BUF_LEN,
+            whole: auto_whole::<SKIP_ZEROES>,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn radix<const RADIX: u32, const SKIP_ZEROES: bool>() -> Self {
+        Self {
+            lead: sign_len,
+            part: is_radix_digit::<RADIX>,
+            len: BUF_LEN,
+            whole: |_| RADIX != 10 && !SKIP_ZEROES,
+        }
+    }
+
+    /// The runs of the separators don't make the number too long for the byte parser
+    #[inline(always)]
+    pub(crate) fn separated<const SEP: u8>() -> Self {
+        Self {
+            lead: sign_len,
+            part: is_separated::<SEP>,
+            len: BUF_LEN,
+            whole: |_| true,
+        }
+    }
+}
+
+#[inline(always)]
+fn sign_len(s: &[u16]) -> usize {
+    matches!(s.first(), Some(&c) if c == b'+' as u16 || c == b'-' as u16) as usize
+}
+
+/// The sign and the `0x`, `0o` or `0b` prefix of `parse_auto`
+#[inline(always)]
+fn auto_lead_len(s: &[u16]) -> usize {
+    let sign = sign_len(s);
+    match s.get(sign..sign + 2) {
+        Some(&[z, p]) if z == b'0' as u16 && b"xXoObB".iter().any(|&c| p == c as u16) => sign + 2,
+        _ => sign,
+    }
+}
+
+/// Without SKIP_ZEROES the parsers of the other radixes check the length of the whole number
+#[inline(always)]
+fn auto_whole<const SKIP_ZEROES: bool>(s: &[u16]) -> bool {
+    !SKIP_ZEROES && auto_lead_len(s) != sign_len(s)
+}
+
+#[inline(always)]
+fn is_radix_digit<const RADIX: u32>(c: &u8) -> bool {
+    (*c as char).is_digit(RADIX)
+}
+
+#[inline(always)]
+fn is_separated<const SEP: u8>(c: &u8) -> bool {
+    c.is_ascii_digit() || *c == SEP
+}
+
+/// The ASCII whitespace (`\t`, `\n`, `\x0C`, `\r` and ' ')
+#[inline(always)]
+fn is_whitespace(c: &u16) -> bool {
+    matches!(*c, 0x09 | 0x0A | 0x0C | 0x0D | 0x20)
+}
+
+/// Moves the error of the narrowed number to the units, without the input slice.
+/// `skipped` units (extra zeroes) were skipped after the first `base` units.
+#[inline(always)]
+fn relocate(e: AtoiSimdError<'_>, base: usize, skipped: usize) -> AtoiSimdError<'static> {
+    let index = |i: usize| if i >= base { i + skipped } else { i };
+    match e {
+        AtoiSimdError::Empty => AtoiSimdError::Empty,
+        AtoiSimdError::Size(n, _) => AtoiSimdError::Size(index(n), &[]),
+        AtoiSimdError::Overflow(sign, i, _) => AtoiSimdError::Overflow(sign, index(i), &[]),
+        AtoiSimdError::OutOfRange(_) => AtoiSimdError::OutOfRange(&[]),
+        AtoiSimdError::Zero(_) => AtoiSimdError::Zero(&[]),
+        AtoiSimdError::NotIntegral(_) => AtoiSimdError::NotIntegral(&[]),
+        AtoiSimdError::NonCanonical(rule, _) => AtoiSimdError::NonCanonical(rule, &[]),
+        AtoiSimdError::Invalid64(v, i, _) => AtoiSimdError::Invalid64(v, index(i), &[]),
+        AtoiSimdError::Invalid128(v, i, _) => AtoiSimdError::Invalid128(v, index(i), &[]),
+    }
+}
+
+/// The narrowed number, parsed
+struct Narrowed<R> {
+    value: R,
+    /// Count of the skipped units (extra zeroes), they are after the lead
+    skipped: usize,
+    /// Count of the narrowed units, if the number goes on after them (`parse` hasn't seen its end)
+    cut: Option<usize>,
+}
+
+/// Narrows the lead (the sign and the radix prefix) and the number of `s` to bytes and parses them with `parse`.
+///
+/// With SKIP_ZEROES the extra zeroes that don't fit in the buffer are skipped before the narrowing,
+/// the byte parsers skip them too, and report the indices in the whole input,
+/// so the result is the same as of the bytes. The byte parsers reject a longer number
+/// before the end of the buffer (`Format::len`), unless it's cut or `Format::whole`,
+/// so the rest of it isn't narrowed.
+#[inline(always)]
+fn parse_narrowed<R, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<R, AtoiSimdError<'_>>,
+) -> Result<Narrowed<R>, AtoiSimdError<'static>> {
+    let lead = (format.lead)(s);
+    let mut skipped = 0;
+    if SKIP_ZEROES && s.len() > format.len {
+        // keeps the last two zeroes, so the number can be just zeroes,
+        // and they don't make a radix prefix (`0x`) with the next char
+        let extra = s.len() - format.len;
+        while skipped < extra
+            && matches!(
+                s.get(lead + skipped..lead + skipped + 3),
+                Some(&[ZERO, ZERO, ZERO])
+            )
+        {
+            skipped += 1;
+        }
+    }
+
+    let mut buf = [0; BUF_LEN];
+    let buf = buf.get_safe_unchecked_mut(..format.len);
+    // the lead is ASCII
+    for (b, &c) in buf.iter_mut().zip(s.get_safe_unchecked(..lead)) {
+        *b = c as u8;
+    }
+    let len = lead
+        + narrow_16(
+            s.get_safe_unchecked(lead + skipped..),
+            buf.get_safe_unchecked_mut(lead..),
+        );
+    let next = s.get(len + skipped).and_then(|&c| u8::try_from(c).ok());
+    let cut = next.filter(format.part).map(|_| len);
+    if cut.is_some() && (format.whole)(s) {
+        return Err(too_long(s, format));
+    }
+
+    parse(buf.get_safe_unchecked(..len))
+        .map(|value| Narrowed {
+            value,
+            skipped,
+            cut,
+        })
+        .map_err(|e| relocate(e, lead, skipped))
+}
+
+/// The number doesn't fit in the buffer
+#[cold]
+fn too_long(s: &[u16], format: Format) -> AtoiSimdError<'static> {
+    let lead = (format.lead)(s);
+    let rest = s.get_safe_unchecked(lead..);
+    let len = rest
+        .iter()
+        .position(|&c| u8::try_from(c).map_or(true, |c| !(format.part)(&c)))
+        .unwrap_or(rest.len());
+    AtoiSimdError::Size(lead + len, &[])
+}
+
+/// Parses the narrowed units with `parse`, the number must fit in the buffer
+#[inline(always)]
+fn parse_units<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<T, AtoiSimdError<'_>>,
+) -> Result<T, AtoiSimdError<'static>> {
+    parse_narrowed::<_, SKIP_ZEROES>(s, format, parse).and_then(|n| match n.cut {
+        Some(_) => Err(too_long(s, format)),
+        None => Ok(n.value),
+    })
+}
+
+/// Parses the narrowed units with the prefix parser `parse`, returns the length in units.
+/// The prefix must end before the end of the buffer, if the number goes on.
+#[inline(always)]
+fn parse_prefix_units<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<(T, usize), AtoiSimdError<'_>>,
+) -> Result<(T, usize), AtoiSimdError<'static>> {
+    // the kept zeroes are parsed, so the skipped units are always in the length
+    parse_narrowed::<_, SKIP_ZEROES>(s, format, parse).and_then(|n| match n.value {
+        (_, len) if n.cut == Some(len) => Err(too_long(s, format)),
+        (v, len) => Ok((v, len + n.skipped)),
+    })
+}
+
+/// Parses the narrowed UTF-16 units with the byte parser `parse` of the `format`
+#[inline(always)]
+pub(crate) fn parse_utf16<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<T, AtoiSimdError<'_>>,
+) -> Result<T, AtoiSimdError<'static>> {
+    parse_units::<_, SKIP_ZEROES>(s, format, parse)
+}
+
+/// Parses the narrowed UTF-16 units with the byte prefix parser `parse` of the `format`,
+/// returns the length in units
+#[inline(always)]
+pub(crate) fn parse_prefix_utf16<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<(T, usize), AtoiSimdError<'_>>,
+) -> Result<(T, usize), AtoiSimdError<'static>> {
+    parse_prefix_units::<_, SKIP_ZEROES>(s, format, parse)
+}
+
+/// Skips the ASCII whitespace on both sides of `s` and parses the rest like `parse_utf16`
+#[inline(always)]
+pub(crate) fn parse_trimmed_utf16<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<T, AtoiSimdError<'_>>,
+) -> Result<T, AtoiSimdError<'static>> {
+    let start = s.iter().take_while(|c| is_whitespace(c)).count();
+    let rest = s.get_safe_unchecked(start..);
+    let end = rest.len() - rest.iter().rev().take_while(|c| is_whitespace(c)).count();
+    parse_units::<_, SKIP_ZEROES>(rest.get_safe_unchecked(..end), format, parse)
+        .map_err(|e| e.with_offset(start, &[]))
+}
+
+/// Skips the ASCII whitespace at the beginning of `s` and parses the prefix of the rest like `parse_prefix_utf16`.
+/// The returned length includes the skipped whitespace.
+#[inline(always)]
+pub(crate) fn parse_prefix_trimmed_utf16<T, const SKIP_ZEROES: bool>(
+    s: &[u16],
+    format: Format,
+    parse: impl Fn(&[u8]) -> Result<(T, usize), AtoiSimdError<'_>>,
+) -> Result<(T, usize), AtoiSimdError<'static>> {
+    let start = s.iter().take_while(|c| is_whitespace(c)).count();
+    parse_prefix_units::<_, SKIP_ZEROES>(s.get_safe_unchecked(start..), format, parse)
+        .map(|(v, len)| (v, start + len))
+        .map_err(|e| e.with_offset(start, &[]))
+}
@@ -0,0 +1,60 @@
+use crate::AtoiSimdError;
+use debug_unsafe::slice::SliceGetter;
+
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+))]
+pub(crate) use crate::simd::{whitespace_len, whitespace_len_back};
+
+#[cfg(not(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(
+        target_feature = "sse2",
+        target_feature = "sse3",
+        target_feature = "sse4.1",
+        target_feature = "ssse3"
+    ),
+)))]
+pub(crate) use crate::fallback::{
+    whitespace_len_back_fb as whitespace_len_back, whitespace_len_fb as whitespace_len,
+};
+
+/// Returns the length of the ASCII whitespace at the beginning of `s`, and the rest of it
+/// without the whitespace at the end
+#[inline(always)]
+pub(crate) fn trim(s: &[u8]) -> (usize, &[u8]) {
+    let start = whitespace_len(s);
+    let rest = s.get_safe_unchecked(start..);
+    let end = rest.len() - whitespace_len_back(rest);
+    (start, rest.get_safe_unchecked(..end))
+}
+
+/// Skips the ASCII whitespace on both sides of `s` and parses the rest with `parse`.
+/// The error indices are moved to the indices of `s`.
+#[inline(always)]
+pub(crate) fn parse_trimmed<R>(
+    s: &[u8],
+    parse: impl FnOnce(&[u8]) -> Result<R, AtoiSimdError<'_>>,
+) -> Result<R, AtoiSimdError<'_>> {
+    let (start, rest) = trim(s);
+    parse(rest).map_err(|e| e.with_offset(start, s))
+}
+
+/// Skips the ASCII whitespace at the beginning of `s` and parses the prefix of the rest with `parse`.
+/// The returned length includes the skipped whitespace.
+#[inline(always)]
+pub(crate) fn parse_prefix_trimmed<R>(
+    s: &[u8],
+    parse: impl FnOnce(&[u8]) -> Result<(R, usize), AtoiSimdError<'_>>,
+) -> Result<(R, usize), AtoiSimdError<'_>> {
+    let start = whitespace_len(s);
+    parse(s.get_safe_unchecked(start..))
+        .map(|(v, len)| (v, start + len))
+        .map_err(|e| e.with_offset(start, s))
+}
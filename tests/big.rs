@@ -0,0 +1,172 @@
+#![cfg(feature = "alloc")]
+
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{parse_big, parse_prefix_big, AtoiSimdError, BigLimbs};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+/// Digit by digit reference
+fn limbs(digits: &str) -> Vec<u64> {
+    let mut limbs: Vec<u64> = Vec::new();
+    for c in digits.bytes() {
+        let mut carry = (c - b'0') as u128;
+        for limb in limbs.iter_mut() {
+            let v = *limb as u128 * 10 + carry;
+            *limb = v as u64;
+            carry = v >> 64;
+        }
+        if carry != 0 {
+            limbs.push(carry as u64);
+        }
+    }
+    limbs
+}
+
+#[test]
+fn test_big() {
+    let mut state = 1;
+    for len in 1..200 {
+        for _ in 0..10 {
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (next(&mut state) % 10) as u8) as char)
+                .collect();
+            let expected = BigLimbs {
+                neg: false,
+                limbs: limbs(&digits),
+            };
+            assert_eq!(
+                parse_big::<false>(digits.as_bytes()),
+                Ok(expected.clone()),
+                "input: {}",
+                digits
+            );
+            assert_eq!(
+                parse_prefix_big::<false>(format!("{} ", digits).as_bytes()),
+                Ok((expected.clone(), len)),
+                "input: {}",
+                digits
+            );
+
+            let neg = format!("-{}", digits);
+            let expected = BigLimbs {
+                neg: !expected.limbs.is_empty(),
+                limbs: expected.limbs,
+            };
+            assert_eq!(
+                parse_big::<false>(neg.as_bytes()),
+                Ok(expected.clone()),
+                "input: {}",
+                neg
+            );
+
+            let plus = format!("+{}", digits);
+            assert_eq!(
+                parse_big::<true>(plus.as_bytes()).map(|v| v.limbs),
+                Ok(expected.limbs),
+                "input: {}",
+                plus
+            );
+
+            if len <= 39 {
+                if let Ok(v) = digits.parse::<u128>() {
+                    let limbs = parse_big::<false>(digits.as_bytes()).unwrap().limbs;
+                    let mut big = 0;
+                    for &limb in limbs.iter().rev() {
+                        big = (big << 64) | limb as u128;
+                    }
+                    assert_eq!(big, v, "input: {}", digits);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_big_errors() {
+    assert_eq!(parse_big::<false>(b""), Err(AtoiSimdError::Empty));
+    assert_eq!(parse_big::<false>(b"-"), Err(AtoiSimdError::Empty));
+    assert_eq!(parse_big::<false>(b"+1"), Err(AtoiSimdError::Empty));
+    assert_eq!(parse_big::<true>(b"+"), Err(AtoiSimdError::Empty));
+    assert_eq!(parse_big::<false>(b"x"), Err(AtoiSimdError::Empty));
+    assert_eq!(
+        parse_big::<false>(b"-0"),
+        Ok(BigLimbs {
+            neg: false,
+            limbs: vec![]
+        })
+    );
+    assert_eq!(
+        parse_big::<false>(b"12x"),
+        Err(AtoiSimdError::Invalid64(12, 2, b"12x"))
+    );
+    assert_eq!(
+        parse_big::<false>(b"-12x"),
+        Err(AtoiSimdError::Invalid64(12, 3, b"-12x"))
+    );
+    assert_eq!(parse_prefix_big::<false>(b"x"), Err(AtoiSimdError::Empty));
+
+    let mut state = 1;
+    for len in 1..120 {
+        let digits: String = (0..len)
+            .map(|_| (b'0' + (next(&mut state) % 10) as u8) as char)
+            .collect();
+        let expected = limbs(&digits);
+        for tail in ["x", "-1", " 123", "/", ":"] {
+            let s = format!("-{}{}", digits, tail);
+            let low = expected.first().copied().unwrap_or(0);
+            assert_eq!(
+                parse_big::<false>(s.as_bytes()),
+                Err(AtoiSimdError::Invalid64(low, len + 1, s.as_bytes())),
+                "input: {}",
+                s
+            );
+            assert_eq!(
+                parse_prefix_big::<false>(s.as_bytes()),
+                Ok((
+                    BigLimbs {
+                        neg: !expected.is_empty(),
+                        limbs: expected.clone()
+                    },
+                    len + 1
+                )),
+                "input: {}",
+                s
+            );
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_big_num_bigint() {
+    use num_bigint::{BigInt, BigUint};
+
+    let mut state = 1;
+    for len in 1..200 {
+        let digits: String = core::iter::once('-')
+            .chain((0..len).map(|_| (b'0' + (next(&mut state) % 10) as u8) as char))
+            .collect();
+        let v = parse_big::<false>(digits.as_bytes()).unwrap();
+        assert_eq!(
+            BigInt::from(v.clone()),
+            BigInt::parse_bytes(digits.as_bytes(), 10).unwrap(),
+            "input: {}",
+            digits
+        );
+        assert_eq!(
+            BigUint::from(v),
+            BigUint::parse_bytes(&digits.as_bytes()[1..], 10).unwrap(),
+            "input: {}",
+            digits
+        );
+    }
+}
@@ -0,0 +1,174 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{parse, parse_bounded, parse_prefix, parse_prefix_bounded, AtoiSimdError};
+use core::fmt::Write;
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+macro_rules! test_bounded_types {
+    ($($name:ident: $t:ty, $min:expr, $max:expr;)*) => {$(
+        #[test]
+        fn $name() {
+            const MIN: i128 = $min;
+            const MAX: i128 = $max;
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..1000).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1]);
+            for v in MIN.saturating_sub(2)..=MIN.saturating_add(2) {
+                values.extend(<$t>::try_from(v));
+            }
+            for v in MAX.saturating_sub(2)..=MAX.saturating_add(2) {
+                values.extend(<$t>::try_from(v));
+            }
+            for v in values {
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", v).unwrap();
+                let in_range = i128::try_from(v).map_or(false, |v| (MIN..=MAX).contains(&v));
+                let res = parse_bounded::<$t, MIN, MAX, false, false>(s.as_bytes());
+                if in_range {
+                    assert_eq!(res, Ok(v), "input: {}", s);
+                } else {
+                    assert_eq!(res, Err(AtoiSimdError::OutOfRange(s.as_bytes())), "input: {}", s);
+                }
+
+                let len = s.len();
+                s.push(' ');
+                let res = parse_prefix_bounded::<$t, MIN, MAX, false, false>(s.as_bytes());
+                if in_range {
+                    assert_eq!(res, Ok((v, len)), "input: {}", s);
+                } else {
+                    assert_eq!(res, Err(AtoiSimdError::OutOfRange(s.as_bytes())), "input: {}", s);
+                }
+            }
+
+            // the overflow of the type
+            let mut s = <$t>::MAX.to_string();
+            let last = s.pop().unwrap();
+            s.push((last as u8 + 1) as char);
+            assert_eq!(
+                parse_bounded::<$t, MIN, MAX, false, false>(s.as_bytes()),
+                Err(AtoiSimdError::OutOfRange(s.as_bytes()))
+            );
+            assert_eq!(
+                parse_prefix_bounded::<$t, MIN, MAX, false, false>(s.as_bytes()),
+                Err(AtoiSimdError::OutOfRange(s.as_bytes()))
+            );
+
+            // other errors are the same, unless a digit before them is out of the range
+            for s in ["", "-", "x", "+1", "1x", "-1x", "1-"] {
+                let s = s.as_bytes();
+                let past_bound = match parse_prefix::<$t, false, false>(s) {
+                    Ok((v, _)) => {
+                        let v = v as i128;
+                        if v < 0 { v < MIN } else { v > MAX.max(0) }
+                    }
+                    Err(_) => false,
+                };
+                let res = parse_bounded::<$t, MIN, MAX, false, false>(s).unwrap_err();
+                if past_bound {
+                    assert_eq!(res, AtoiSimdError::OutOfRange(s));
+                } else {
+                    assert_eq!(res, parse::<$t, false, false>(s).unwrap_err());
+                }
+                if let Err(e) = parse_prefix::<$t, false, false>(s) {
+                    assert_eq!(parse_prefix_bounded::<$t, MIN, MAX, false, false>(s), Err(e));
+                }
+            }
+        }
+    )*};
+}
+
+test_bounded_types!(
+    test_bounded_u8: u8, 1, 12;
+    test_bounded_i8: i8, -100, 100;
+    test_bounded_u16: u16, 1, 65535;
+    test_bounded_i16: i16, 0, 100;
+    test_bounded_u32: u32, 1000, 1_000_000;
+    test_bounded_i32: i32, -1_000_000, -1000;
+    test_bounded_usize: usize, 0, u32::MAX as i128;
+    test_bounded_isize: isize, i32::MIN as i128, 0;
+    test_bounded_u64: u64, 1, u64::MAX as i128;
+    test_bounded_i64: i64, i64::MIN as i128 + 1, i64::MAX as i128 - 1;
+    test_bounded_u128: u128, 1, i128::MAX;
+    test_bounded_i128: i128, i128::MIN + 1, 1 << 100;
+);
+
+#[test]
+fn test_bounded() {
+    assert_eq!(
+        parse_bounded::<u16, 1, 65535, true, true>(b"+00080"),
+        Ok(80)
+    );
+    assert_eq!(
+        parse_bounded::<u16, 1, 65535, true, true>(b"0"),
+        Err(AtoiSimdError::OutOfRange(b"0"))
+    );
+    assert_eq!(
+        parse_bounded::<u8, 0, 100, false, false>(b"256"),
+        Err(AtoiSimdError::OutOfRange(b"256"))
+    );
+    assert_eq!(
+        parse_bounded::<u8, 0, 100, false, false>(b"50%"),
+        Err(AtoiSimdError::Invalid64(50, 2, b"50%"))
+    );
+    assert_eq!(
+        parse_prefix_bounded::<u8, 0, 100, false, false>(b"50%"),
+        Ok((50, 2))
+    );
+    assert_eq!(
+        parse_prefix_bounded::<i32, -12, 12, false, false>(b"-13h"),
+        Err(AtoiSimdError::OutOfRange(b"-13h"))
+    );
+    // stops at the digit out of range
+    assert_eq!(
+        parse_bounded::<u32, 0, 100, false, false>(b"101x"),
+        Err(AtoiSimdError::OutOfRange(b"101x"))
+    );
+    assert_eq!(
+        parse_bounded::<i64, -100, -10, false, false>(b"-1000"),
+        Err(AtoiSimdError::OutOfRange(b"-1000"))
+    );
+    assert_eq!(
+        parse_bounded::<i64, -100, -10, false, false>(b"-9"),
+        Err(AtoiSimdError::OutOfRange(b"-9"))
+    );
+    assert_eq!(
+        parse_bounded::<i8, 1, 1, false, false>(b"-0"),
+        Err(AtoiSimdError::OutOfRange(b"-0"))
+    );
+    assert_eq!(parse_bounded::<i8, 0, 0, false, false>(b"-0"), Ok(0));
+    assert_eq!(
+        parse_prefix_bounded::<u64, 1, { u64::MAX as i128 }, false, true>(
+            b"+18446744073709551615 "
+        ),
+        Ok((u64::MAX, 21))
+    );
+    assert_eq!(
+        parse_prefix_bounded::<i16, -300, 300, false, false>(b"-300-"),
+        Ok((-300, 4))
+    );
+    // longer than the type
+    assert_eq!(
+        parse_bounded::<u8, 1, 12, true, false>(b"0000000000000000000000000000012"),
+        Ok(12)
+    );
+    assert_eq!(
+        parse_bounded::<u8, 1, 12, false, false>(b"0012345"),
+        Err(AtoiSimdError::OutOfRange(b"0012345"))
+    );
+    // u128 values above i128::MAX
+    let s = u128::MAX.to_string();
+    assert_eq!(
+        parse_bounded::<u128, 0, { i128::MAX }, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::OutOfRange(s.as_bytes()))
+    );
+}
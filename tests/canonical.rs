@@ -0,0 +1,94 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{parse, parse_canonical, parse_prefix_canonical, AtoiSimdError, CanonicalRule};
+use core::fmt::Write;
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+fn non_canonical(rule: CanonicalRule, s: &[u8]) -> AtoiSimdError<'_> {
+    AtoiSimdError::NonCanonical(rule, s)
+}
+
+macro_rules! test_canonical_types {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..1000).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1]);
+            for v in values {
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", v).unwrap();
+                let s = s.as_bytes();
+                assert_eq!(parse_canonical::<$t, false>(s), Ok(v), "input: {:X?}", s);
+                assert_eq!(parse_canonical::<$t, true>(s), Ok(v), "input: {:X?}", s);
+                assert_eq!(parse_prefix_canonical::<$t, false>(s), Ok((v, s.len())), "input: {:X?}", s);
+            }
+
+            for s in ["+1", "+0", "+"] {
+                let s = s.as_bytes();
+                assert_eq!(parse_canonical::<$t, false>(s), Err(non_canonical(CanonicalRule::PlusSign, s)));
+                assert_eq!(parse_prefix_canonical::<$t, true>(s), Err(non_canonical(CanonicalRule::PlusSign, s)));
+            }
+            let zeroes = format!("{}1", "0".repeat(100));
+            for s in ["01", "00", "007x", "-01", "-00", zeroes.as_str()] {
+                let s = s.as_bytes();
+                assert_eq!(parse_canonical::<$t, false>(s), Err(non_canonical(CanonicalRule::LeadingZero, s)));
+                assert_eq!(parse_prefix_canonical::<$t, true>(s), Err(non_canonical(CanonicalRule::LeadingZero, s)));
+            }
+
+            // the other errors are the same as of `parse`
+            for s in ["", "-", "x", "1x", "0x", "-0x", "1 "] {
+                let s = s.as_bytes();
+                assert_eq!(parse_canonical::<$t, true>(s), parse::<$t, false, false>(s), "input: {:X?}", s);
+            }
+            assert_eq!(parse_canonical::<$t, false>(b""), Err(AtoiSimdError::Empty));
+            assert_eq!(parse_prefix_canonical::<$t, false>(b"0 1"), Ok((0, 1)));
+            assert_eq!(parse_prefix_canonical::<$t, false>(b"10,"), Ok((10, 2)));
+        }
+    )*};
+}
+
+test_canonical_types!(
+    test_canonical_u8: u8;
+    test_canonical_u16: u16;
+    test_canonical_u32: u32;
+    test_canonical_usize: usize;
+    test_canonical_u64: u64;
+    test_canonical_u128: u128;
+    test_canonical_i8: i8;
+    test_canonical_i16: i16;
+    test_canonical_i32: i32;
+    test_canonical_isize: isize;
+    test_canonical_i64: i64;
+    test_canonical_i128: i128;
+);
+
+#[test]
+fn test_canonical_neg_zero() {
+    let s = b"-0";
+    assert_eq!(
+        parse_canonical::<i32, false>(s),
+        Err(non_canonical(CanonicalRule::NegativeZero, s))
+    );
+    assert_eq!(parse_canonical::<i32, true>(s), Ok(0));
+    assert_eq!(
+        parse_prefix_canonical::<i64, false>(b"-0,"),
+        Err(non_canonical(CanonicalRule::NegativeZero, b"-0,"))
+    );
+    assert_eq!(parse_prefix_canonical::<i64, true>(b"-0,"), Ok((0, 2)));
+    // unsigned integers have no sign at all
+    assert_eq!(
+        parse_canonical::<u32, true>(s),
+        parse::<u32, false, false>(s)
+    );
+}
@@ -0,0 +1,281 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::compat::{strtol, strtoll, strtoul, strtoull, Errno, Strtol};
+
+const RANGE: Option<Errno> = Some(Errno::Range);
+
+/// Known glibc outputs: input, base, value, end, errno
+const STRTOL: &[(&str, u32, i64, usize, Option<Errno>)] = &[
+    ("0", 10, 0, 1, None),
+    ("42", 10, 42, 2, None),
+    ("  \t\n\x0B\x0C\r-42", 10, -42, 10, None),
+    ("+42abc", 10, 42, 3, None),
+    ("-", 10, 0, 0, None),
+    ("+", 10, 0, 0, None),
+    ("  ", 10, 0, 0, None),
+    ("", 10, 0, 0, None),
+    ("abc", 10, 0, 0, None),
+    (" - 1", 10, 0, 0, None),
+    ("--1", 10, 0, 0, None),
+    ("9223372036854775807", 10, i64::MAX, 19, None),
+    ("9223372036854775808", 10, i64::MAX, 19, RANGE),
+    ("-9223372036854775808", 10, i64::MIN, 20, None),
+    ("-9223372036854775809", 10, i64::MIN, 20, RANGE),
+    (
+        "99999999999999999999999999999999999999999x",
+        10,
+        i64::MAX,
+        41,
+        RANGE,
+    ),
+    (
+        "-99999999999999999999999999999999999999999",
+        10,
+        i64::MIN,
+        42,
+        RANGE,
+    ),
+    (
+        "000000000000000000000000000000000000000042",
+        10,
+        42,
+        42,
+        None,
+    ),
+    ("0x1F", 0, 31, 4, None),
+    ("0X1f", 0, 31, 4, None),
+    ("-0x1F", 0, -31, 5, None),
+    ("0x1F", 16, 31, 4, None),
+    ("1F", 16, 31, 2, None),
+    ("0x", 0, 0, 1, None),
+    ("0x", 16, 0, 1, None),
+    ("0xg", 16, 0, 1, None),
+    ("0x1F", 10, 0, 1, None),
+    ("0755", 0, 0o755, 4, None),
+    ("0758", 0, 0o75, 3, None),
+    ("08", 0, 0, 1, None),
+    ("755", 8, 0o755, 3, None),
+    ("0b101", 0, 0, 1, None),
+    ("101", 2, 5, 3, None),
+    ("0b101", 2, 0, 1, None),
+    ("zz", 36, 1295, 2, None),
+    ("ZZ", 36, 1295, 2, None),
+    ("z", 35, 0, 0, None),
+    ("-Yy", 35, -1224, 3, None),
+    ("12", 3, 5, 2, None),
+    ("1234", 3, 5, 2, None),
+    ("7fffffffffffffff", 16, i64::MAX, 16, None),
+    ("8000000000000000", 16, i64::MAX, 16, RANGE),
+    ("-8000000000000000", 16, i64::MIN, 17, None),
+    ("1y2p0ij32e8e7", 36, i64::MAX, 13, None),
+    ("1y2p0ij32e8e8", 36, i64::MAX, 13, RANGE),
+    ("1", 1, 0, 0, Some(Errno::Invalid)),
+    ("1", 37, 0, 0, Some(Errno::Invalid)),
+];
+
+/// Known glibc outputs: input, base, value, end, errno
+const STRTOUL: &[(&str, u32, u64, usize, Option<Errno>)] = &[
+    ("18446744073709551615", 10, u64::MAX, 20, None),
+    ("18446744073709551616", 10, u64::MAX, 20, RANGE),
+    ("-1", 10, u64::MAX, 2, None),
+    ("-18446744073709551615", 10, 1, 21, None),
+    ("-18446744073709551616", 10, u64::MAX, 21, RANGE),
+    ("-9223372036854775808", 10, 1 << 63, 20, None),
+    (" +0xFFFFFFFFFFFFFFFF", 0, u64::MAX, 20, None),
+    ("0x10000000000000000", 0, u64::MAX, 19, RANGE),
+    ("  -0", 0, 0, 4, None),
+    ("-x", 0, 0, 0, None),
+    ("3w5e11264sgsf", 36, u64::MAX, 13, None),
+    ("3w5e11264sgsg", 36, u64::MAX, 13, RANGE),
+    ("1", 0xFFFF_FFFF, 0, 0, Some(Errno::Invalid)),
+];
+
+#[test]
+fn test_strtol_known() {
+    for &(s, base, value, end, errno) in STRTOL {
+        let expected = Strtol { value, end, errno };
+        assert_eq!(
+            strtol(s.as_bytes(), base),
+            expected,
+            "input: {:?} base: {}",
+            s,
+            base
+        );
+        assert_eq!(
+            strtoll(s.as_bytes(), base),
+            expected,
+            "input: {:?} base: {}",
+            s,
+            base
+        );
+    }
+}
+
+#[test]
+fn test_strtoul_known() {
+    for &(s, base, value, end, errno) in STRTOUL {
+        let expected = Strtol { value, end, errno };
+        assert_eq!(
+            strtoul(s.as_bytes(), base),
+            expected,
+            "input: {:?} base: {}",
+            s,
+            base
+        );
+        assert_eq!(
+            strtoull(s.as_bytes(), base),
+            expected,
+            "input: {:?} base: {}",
+            s,
+            base
+        );
+    }
+    // the negation modulo 2^64
+    for &(s, base, value, end, errno) in STRTOL {
+        if errno.is_none() {
+            let expected = Strtol {
+                value: value as u64,
+                end,
+                errno,
+            };
+            assert_eq!(
+                strtoul(s.as_bytes(), base),
+                expected,
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+        }
+    }
+}
+
+/// Compares with the libc of the platform
+#[cfg(all(target_os = "linux", target_env = "gnu", target_pointer_width = "64"))]
+mod libc {
+    use super::*;
+    use std::{
+        ffi::CString,
+        os::raw::{c_char, c_int, c_long, c_ulonglong},
+    };
+
+    extern "C" {
+        #[link_name = "strtol"]
+        fn c_strtol(s: *const c_char, end: *mut *mut c_char, base: c_int) -> c_long;
+        #[link_name = "strtoull"]
+        fn c_strtoull(s: *const c_char, end: *mut *mut c_char, base: c_int) -> c_ulonglong;
+        fn __errno_location() -> *mut c_int;
+    }
+
+    const ERANGE: c_int = 34;
+    const EINVAL: c_int = 22;
+
+    fn errno(e: c_int) -> Option<Errno> {
+        match e {
+            0 => None,
+            ERANGE => Some(Errno::Range),
+            EINVAL => Some(Errno::Invalid),
+            e => panic!("unexpected errno: {}", e),
+        }
+    }
+
+    fn libc_strtol(s: &str, base: u32) -> Strtol<i64> {
+        let c = CString::new(s).unwrap();
+        let mut end = c.as_ptr() as *mut c_char;
+        unsafe {
+            *__errno_location() = 0;
+            let value = c_strtol(c.as_ptr(), &mut end, base as c_int);
+            Strtol {
+                value,
+                end: end.offset_from(c.as_ptr()) as usize,
+                errno: errno(*__errno_location()),
+            }
+        }
+    }
+
+    fn libc_strtoull(s: &str, base: u32) -> Strtol<u64> {
+        let c = CString::new(s).unwrap();
+        let mut end = c.as_ptr() as *mut c_char;
+        unsafe {
+            *__errno_location() = 0;
+            let value = c_strtoull(c.as_ptr(), &mut end, base as c_int);
+            Strtol {
+                value,
+                end: end.offset_from(c.as_ptr()) as usize,
+                errno: errno(*__errno_location()),
+            }
+        }
+    }
+
+    /// Simple LCG to produce reproducible "random" values
+    fn next(state: &mut u128) -> u128 {
+        *state = state
+            .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+            .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+        *state >> (*state as u32 & 127)
+    }
+
+    #[test]
+    fn test_strtol_libc() {
+        for &(s, base, ..) in STRTOL {
+            assert_eq!(
+                strtol(s.as_bytes(), base),
+                libc_strtol(s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+            assert_eq!(
+                strtoull(s.as_bytes(), base),
+                libc_strtoull(s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+        }
+        for &(s, base, ..) in STRTOUL {
+            assert_eq!(
+                strtol(s.as_bytes(), base),
+                libc_strtol(s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+            assert_eq!(
+                strtoull(s.as_bytes(), base),
+                libc_strtoull(s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn test_strtol_libc_random() {
+        const CHARS: &[u8] = b" \t\x0B+-0123456789000xXabcfgzAZ";
+        let mut state = 1;
+        for _ in 0..100_000 {
+            let len = next(&mut state) as usize % 30;
+            let s: String = (0..len)
+                .map(|_| CHARS[next(&mut state) as usize % CHARS.len()] as char)
+                .collect();
+            let base = [0, 2, 8, 10, 16, 36, 3, 20][next(&mut state) as usize % 8];
+            assert_eq!(
+                strtol(s.as_bytes(), base),
+                libc_strtol(&s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+            assert_eq!(
+                strtoull(s.as_bytes(), base),
+                libc_strtoull(&s, base),
+                "input: {:?} base: {}",
+                s,
+                base
+            );
+        }
+    }
+}
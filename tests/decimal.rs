@@ -0,0 +1,263 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{parse_decimal, parse_prefix_decimal, AtoiSimdError, Rounding, Sign};
+
+const ROUNDINGS: [Rounding; 4] = [
+    Rounding::Reject,
+    Rounding::Truncate,
+    Rounding::HalfEven,
+    Rounding::HalfUp,
+];
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+fn digits(state: &mut u64, len: u64) -> String {
+    (0..len)
+        .map(|_| (b'0' + (next(state) % 10) as u8) as char)
+        .collect()
+}
+
+/// Reference: the scaled magnitude, `None` on overflow of `max` or rejected digits
+fn reference(int: &str, frac: &str, scale: usize, rounding: Rounding, max: u128) -> Option<u128> {
+    let mut padded = frac.to_string();
+    while padded.len() < scale {
+        padded.push('0');
+    }
+    let (kept, dropped) = padded.split_at(scale);
+    let mut mag: u128 = 0;
+    for c in int.bytes().chain(kept.bytes()) {
+        mag = mag.checked_mul(10)?.checked_add((c - b'0') as u128)?;
+    }
+    if !dropped.is_empty() {
+        let half = format!("5{}", "0".repeat(dropped.len() - 1));
+        let up = match rounding {
+            Rounding::Reject => return None,
+            Rounding::Truncate => false,
+            Rounding::HalfUp => dropped >= half.as_str(),
+            Rounding::HalfEven => dropped > half.as_str() || (dropped == half && mag % 2 == 1),
+        };
+        if up {
+            mag = mag.checked_add(1)?;
+        }
+    }
+    if mag > max {
+        return None;
+    }
+    Some(mag)
+}
+
+macro_rules! test_decimal_types {
+    ($($name:ident: $t:ty, $scale:literal;)*) => {$(
+        #[test]
+        fn $name() {
+            #[allow(unused_comparisons)]
+            let signed = <$t>::MIN < 0;
+            let mut state = 1;
+            for i in 0..2000 {
+                let len = next(&mut state) % 24;
+                let int = digits(&mut state, len);
+                let len = next(&mut state) % 12;
+                let frac = digits(&mut state, len);
+                if int.is_empty() && frac.is_empty() {
+                    continue;
+                }
+                let neg = signed && i % 2 == 1;
+                let s = format!("{}{}.{}", if neg { "-" } else { "" }, int, frac);
+                let s = if frac.is_empty() { s.trim_end_matches('.').to_string() } else { s };
+                let max = if neg { (<$t>::MIN as i128).unsigned_abs() } else { <$t>::MAX as u128 };
+                for rounding in ROUNDINGS {
+                    let expected = reference(&int, &frac, $scale, rounding, max);
+                    let res = parse_decimal::<$t, $scale, false>(s.as_bytes(), rounding);
+                    match expected {
+                        Some(mag) => {
+                            let v = if neg { (mag as $t).wrapping_neg() } else { mag as $t };
+                            assert_eq!(res, Ok(v), "input: {}, {:?}", s, rounding);
+                            let prefixed = format!("{}e", s);
+                            assert_eq!(
+                                parse_prefix_decimal::<$t, $scale, false>(prefixed.as_bytes(), rounding),
+                                Ok((v, s.len())),
+                                "input: {}, {:?}",
+                                prefixed,
+                                rounding
+                            );
+                        }
+                        None if rounding == Rounding::Reject && frac.len() > $scale && reference(&int, &frac, $scale, Rounding::Truncate, max).is_some() => {
+                            let index = s.len() - frac.len() + $scale;
+                            match res {
+                                Err(AtoiSimdError::Invalid64(_, i, _)) | Err(AtoiSimdError::Invalid128(_, i, _)) => assert_eq!(i, index, "input: {}", s),
+                                e => panic!("input: {}, result: {:?}", s, e),
+                            }
+                        }
+                        None => assert!(matches!(res, Err(AtoiSimdError::Overflow(..)) | Err(AtoiSimdError::Invalid64(..)) | Err(AtoiSimdError::Invalid128(..))), "input: {}, {:?}, result: {:?}", s, rounding, res),
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_decimal_types!(
+    test_decimal_u8: u8, 1;
+    test_decimal_i16: i16, 2;
+    test_decimal_u32: u32, 4;
+    test_decimal_i32: i32, 0;
+    test_decimal_u64: u64, 8;
+    test_decimal_i64: i64, 4;
+    test_decimal_u128: u128, 18;
+    test_decimal_i128: i128, 38;
+);
+
+#[test]
+fn test_decimal() {
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"123.4567", Rounding::Reject),
+        Ok(1_234_567)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"123.45", Rounding::Reject),
+        Ok(1_234_500)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"123", Rounding::Reject),
+        Ok(1_230_000)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"-.5", Rounding::Reject),
+        Ok(-5000)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, true>(b"+0.00005", Rounding::HalfUp),
+        Ok(1)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"-0.00005", Rounding::HalfUp),
+        Ok(-1)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"0.00005", Rounding::HalfEven),
+        Ok(0)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"0.00015", Rounding::HalfEven),
+        Ok(2)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"0.000050001", Rounding::HalfEven),
+        Ok(1)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"0.99999", Rounding::Truncate),
+        Ok(9999)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"-1.23456", Rounding::Reject),
+        Err(AtoiSimdError::Invalid128(12_345, 7, b"-1.23456"))
+    );
+    assert_eq!(
+        parse_decimal::<u8, 2, false>(b"2.55", Rounding::Reject),
+        Ok(255)
+    );
+    assert_eq!(
+        parse_decimal::<u8, 2, false>(b"2.555", Rounding::HalfUp),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 5, b"2.555"))
+    );
+    assert_eq!(
+        parse_decimal::<u8, 2, false>(b"2.56", Rounding::Reject),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 3, b"2.56"))
+    );
+    assert_eq!(
+        parse_decimal::<i8, 0, false>(b"-128.4", Rounding::HalfEven),
+        Ok(-128)
+    );
+    assert_eq!(
+        parse_decimal::<i8, 0, false>(b"-127.5", Rounding::HalfEven),
+        Ok(-128)
+    );
+    assert_eq!(
+        parse_decimal::<i8, 0, false>(b"127.5", Rounding::HalfEven),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 5, b"127.5"))
+    );
+    // an empty fractional part
+    assert_eq!(
+        parse_decimal::<i64, 4, true>(b"3.", Rounding::Reject),
+        Ok(30_000)
+    );
+    assert_eq!(
+        parse_decimal::<i64, 4, false>(b"-3.", Rounding::HalfEven),
+        Ok(-30_000)
+    );
+    assert_eq!(
+        parse_prefix_decimal::<u8, 0, false>(b"7.,", Rounding::Reject),
+        Ok((7, 2))
+    );
+    let zeroes = format!("{}1.5", "0".repeat(100));
+    assert_eq!(
+        parse_decimal::<u32, 1, false>(zeroes.as_bytes(), Rounding::Reject),
+        Ok(15)
+    );
+}
+
+#[test]
+fn test_decimal_errors() {
+    for rounding in ROUNDINGS {
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b"", rounding),
+            Err(AtoiSimdError::Empty)
+        );
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b"-", rounding),
+            Err(AtoiSimdError::Empty)
+        );
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b".", rounding),
+            Err(AtoiSimdError::Empty)
+        );
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b"-.x", rounding),
+            Err(AtoiSimdError::Empty)
+        );
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b"1.x", rounding),
+            Err(AtoiSimdError::Invalid128(100, 2, b"1.x"))
+        );
+        assert_eq!(
+            parse_decimal::<i64, 2, false>(b"-1.2x", rounding),
+            Err(AtoiSimdError::Invalid128(120, 4, b"-1.2x"))
+        );
+        assert_eq!(
+            parse_decimal::<u32, 2, false>(b"1.2.3", rounding),
+            Err(AtoiSimdError::Invalid64(120, 3, b"1.2.3"))
+        );
+        assert_eq!(
+            parse_prefix_decimal::<u32, 2, false>(b"1.2.3", rounding),
+            Ok((120, 3))
+        );
+        assert_eq!(
+            parse_prefix_decimal::<i32, 2, false>(b"-1. ", rounding),
+            Ok((-100, 3))
+        );
+        assert_eq!(
+            parse_decimal::<u64, 2, false>(b"-1", rounding),
+            Err(AtoiSimdError::Empty)
+        );
+        let long = "9".repeat(50);
+        assert_eq!(
+            parse_decimal::<u128, 0, false>(long.as_bytes(), rounding),
+            Err(AtoiSimdError::Overflow(Sign::Pos, 38, long.as_bytes()))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "SCALE must be less than or equal to 38")]
+fn test_decimal_scale() {
+    let _ = parse_decimal::<u128, 39, false>(b"1", Rounding::Reject);
+}
@@ -0,0 +1,363 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{
+    parse, parse_bounded, parse_canonical, parse_hex, parse_integral, parse_prefix,
+    parse_separated, parse_trimmed, parse_with, AtoiSimdError, CanonicalRule, ErrorKind,
+    OwnedError, ParseOptions, Sign,
+};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+fn assert_static<T: 'static + Send + Sync>(_: &T) {}
+
+#[test]
+fn test_kind() {
+    assert_eq!(
+        parse::<u32, false, false>(b"").unwrap_err().kind(),
+        ErrorKind::Empty
+    );
+    assert_eq!(
+        parse::<u32, false, false>(b"12a").unwrap_err().kind(),
+        ErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse::<u64, false, false>(b"12a").unwrap_err().kind(),
+        ErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse::<u8, false, false>(b"256").unwrap_err().kind(),
+        ErrorKind::PosOverflow
+    );
+    assert_eq!(
+        parse::<i8, false, false>(b"128").unwrap_err().kind(),
+        ErrorKind::PosOverflow
+    );
+    assert_eq!(
+        parse::<i8, false, false>(b"-129").unwrap_err().kind(),
+        ErrorKind::NegOverflow
+    );
+    assert_eq!(
+        parse_separated::<i8, b'_', true, false, false>(b"-1_29")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NegOverflow
+    );
+    assert_eq!(
+        parse::<u64, false, false>(&[b'1'; 70]).unwrap_err().kind(),
+        ErrorKind::TooLong
+    );
+    assert_eq!(
+        parse_bounded::<u16, 1, 12, false, false>(b"13")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::OutOfRange
+    );
+    assert_eq!(
+        parse::<core::num::NonZeroU32, false, false>(b"0")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Zero
+    );
+    assert_eq!(
+        parse_integral::<u32, false>(b"1.5").unwrap_err().kind(),
+        ErrorKind::NotIntegral
+    );
+    assert_eq!(
+        parse_canonical::<i32, false>(b"+1").unwrap_err().kind(),
+        ErrorKind::NonCanonical(CanonicalRule::PlusSign)
+    );
+}
+
+#[test]
+fn test_offset() {
+    assert_eq!(parse::<u32, false, false>(b"").unwrap_err().offset(), None);
+    assert_eq!(
+        parse::<u32, false, false>(b"12a4").unwrap_err().offset(),
+        Some(2)
+    );
+    assert_eq!(
+        parse::<u64, false, false>(b"1234567890123x")
+            .unwrap_err()
+            .offset(),
+        Some(13)
+    );
+    assert_eq!(
+        parse::<u8, false, false>(b"256").unwrap_err().offset(),
+        Some(2)
+    );
+    assert!(
+        parse::<u64, false, false>(&[b'1'; 70])
+            .unwrap_err()
+            .offset()
+            .unwrap()
+            > 20
+    );
+    assert_eq!(
+        parse_prefix::<u32, false, false>(b"").unwrap_err().input(),
+        b""
+    );
+    assert_eq!(
+        parse::<u8, false, false>(b"256").unwrap_err().input(),
+        b"256"
+    );
+}
+
+#[test]
+fn test_into_owned() {
+    let owned = {
+        let buf = b"12x4".to_vec();
+        parse::<u32, false, false>(&buf).unwrap_err().into_owned()
+    };
+    assert_static(&owned);
+    assert_eq!(owned.kind(), ErrorKind::InvalidDigit);
+    assert_eq!(owned.offset(), Some(2));
+    assert_eq!(owned.snippet(), b"12x4");
+    assert_eq!(owned.input_len(), 4);
+    assert_eq!(
+        owned.to_string(),
+        "atoi_simd invalid digit at: 2, input: [31, 32, 78, 34]"
+    );
+
+    let long = [b'1'; 70];
+    let err = parse::<u64, false, false>(&long).unwrap_err();
+    let owned: OwnedError = err.into();
+    assert_eq!(owned.kind(), ErrorKind::TooLong);
+    assert_eq!(owned.offset(), err.offset());
+    assert_eq!(owned.snippet(), &long[..48]);
+    assert_eq!(owned.input_len(), 70);
+
+    // the snippet is centered on the offset
+    let mut long = [b'1'; 100];
+    long[60] = b'x';
+    let owned = AtoiSimdError::Invalid64(0, 60, &long).into_owned();
+    assert_eq!(owned.snippet_start(), 36);
+    assert_eq!(owned.snippet(), &long[36..84]);
+    assert_eq!(owned.snippet()[60 - owned.snippet_start()], b'x');
+    assert!(owned.to_string().contains("at: 60, input from 36: [31, "));
+    // but inside the input
+    let owned = AtoiSimdError::Invalid64(0, 90, &long).into_owned();
+    assert_eq!(owned.snippet_start(), 52);
+    assert_eq!(owned.snippet(), &long[52..]);
+    let owned = AtoiSimdError::Invalid64(0, 10, &long).into_owned();
+    assert_eq!(owned.snippet_start(), 0);
+    assert_eq!(owned.snippet(), &long[..48]);
+    // without the offset it's the beginning
+    let owned = AtoiSimdError::OutOfRange(&long).into_owned();
+    assert_eq!((owned.offset(), owned.snippet_start()), (None, 0));
+
+    let owned = AtoiSimdError::Empty.into_owned();
+    assert_eq!(owned.kind(), ErrorKind::Empty);
+    assert_eq!(owned.snippet(), b"");
+    assert_eq!(owned.to_string(), "atoi_simd string is empty");
+
+    let owned = AtoiSimdError::Overflow(Sign::Neg, 3, b"-129").into_owned();
+    assert_eq!(owned.kind(), ErrorKind::NegOverflow);
+    assert_eq!(owned.offset(), Some(3));
+    assert_eq!(
+        owned.to_string(),
+        "atoi_simd negative overflow at: 3, input: [2D, 31, 32, 39]"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_owned_std_error() {
+    let owned = parse::<u8, false, false>(b"256").unwrap_err().into_owned();
+    let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(owned);
+    assert_eq!(
+        boxed.to_string(),
+        "atoi_simd positive overflow at: 2, input: [32, 35, 36]"
+    );
+}
+
+#[test]
+fn test_overflow_display() {
+    assert_eq!(
+        parse::<u8, false, false>(b"256").unwrap_err().to_string(),
+        "atoi_simd positive overflow at index: 2, input: [32, 35, 36]"
+    );
+    assert_eq!(
+        parse::<i8, false, false>(b"-1290").unwrap_err().to_string(),
+        "atoi_simd negative overflow at index: 3, input: [2D, 31, 32, 39, 30]"
+    );
+}
+
+#[test]
+fn test_overflow_index_input() {
+    // every entry point reports the index in its input, with the sign and the whitespace
+    assert_eq!(
+        parse::<i8, false, false>(b"-129"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 3, b"-129"))
+    );
+    assert_eq!(
+        parse::<u8, false, true>(b"+256"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 3, b"+256"))
+    );
+    assert_eq!(
+        parse::<i64, false, false>(b"-9223372036854775809"),
+        Err(AtoiSimdError::Overflow(
+            Sign::Neg,
+            19,
+            b"-9223372036854775809"
+        ))
+    );
+    assert_eq!(
+        parse_hex::<i8, false, false>(b"-81"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 2, b"-81"))
+    );
+    assert_eq!(
+        parse_trimmed::<i8, false, false>(b"  -129"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 5, b"  -129"))
+    );
+    assert_eq!(
+        parse_with::<i8>(&ParseOptions::new().trim(true), b"  -129"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 5, b"  -129"))
+    );
+    assert_eq!(
+        parse_prefix::<i16, false, false>(b"-32769,"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 5, b"-32769,"))
+    );
+    // the same for the invalid chars
+    assert_eq!(
+        parse::<i32, false, false>(b"-12x"),
+        Err(AtoiSimdError::Invalid64(12, 3, b"-12x"))
+    );
+}
+
+macro_rules! test_overflow_index {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            for len in 1..=45 {
+                for _ in 0..20 {
+                    let digits: String = (0..len)
+                        .map(|_| (b'0' + (next(&mut state) % 10) as u8) as char)
+                        .collect();
+                    for neg in [false, true] {
+                        if neg && <$t>::MIN == 0 {
+                            continue;
+                        }
+                        let sign = if neg { "-" } else { "" };
+                        let s = format!("{}{}", sign, digits);
+                        // the length of the shortest run of the digits out of range, by the std parser
+                        let run = (1..=len).find(|&k| format!("{}{}", sign, &digits[..k]).parse::<$t>().is_err());
+                        match parse::<$t, false, false>(s.as_bytes()) {
+                            // the index is in the input, with the sign
+                            Err(AtoiSimdError::Overflow(overflow, i, _)) => {
+                                let expected = if neg { Sign::Neg } else { Sign::Pos };
+                                assert_eq!((overflow, Some(i + 1 - sign.len())), (expected, run), "input: {}", s);
+                            }
+                            // too many zeroes at the beginning
+                            Err(AtoiSimdError::Size(..)) => {}
+                            // the parser of the 32-bit integers stops at 16 digits
+                            Err(AtoiSimdError::Invalid64(_, i, _)) if len > 16 && i == 16 + sign.len() => {}
+                            res => assert!(run.is_none(), "input: {}, result: {:?}", s, res),
+                        }
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_overflow_index!(
+    test_overflow_index_u8: u8;
+    test_overflow_index_u16: u16;
+    test_overflow_index_u32: u32;
+    test_overflow_index_usize: usize;
+    test_overflow_index_u64: u64;
+    test_overflow_index_u128: u128;
+    test_overflow_index_i8: i8;
+    test_overflow_index_i16: i16;
+    test_overflow_index_i32: i32;
+    test_overflow_index_isize: isize;
+    test_overflow_index_i64: i64;
+    test_overflow_index_i128: i128;
+);
+
+macro_rules! test_overflow_index_zeroes {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            for len in 1..=45 {
+                for _ in 0..20 {
+                    // more than 16 (and 32) zeroes, which are skipped before the digits
+                    let zeroes = "0".repeat((next(&mut state) % 60 + 17) as usize);
+                    let digits: String = (0..len)
+                        .map(|_| (b'1' + (next(&mut state) % 9) as u8) as char)
+                        .collect();
+                    for neg in [false, true] {
+                        if neg && <$t>::MIN == 0 {
+                            continue;
+                        }
+                        let sign = if neg { "-" } else { "" };
+                        let s = format!("{}{}{}", sign, zeroes, digits);
+                        let run = (1..=len).find(|&k| format!("{}{}", sign, &digits[..k]).parse::<$t>().is_err());
+                        // the index is in the whole input, with the sign and the zeroes
+                        let expected = run.map(|k| (if neg { Sign::Neg } else { Sign::Pos }, sign.len() + zeroes.len() + k - 1));
+                        for res in [
+                            parse::<$t, true, false>(s.as_bytes()).map(|v| (v, s.len())),
+                            parse_prefix::<$t, true, false>(s.as_bytes()),
+                        ] {
+                            match res {
+                                Err(AtoiSimdError::Overflow(overflow, i, input)) => {
+                                    assert_eq!(Some((overflow, i)), expected, "input: {}", s);
+                                    assert_eq!(input, s.as_bytes());
+                                }
+                                Err(AtoiSimdError::Size(_, input)) => assert_eq!(input, s.as_bytes()),
+                                Err(AtoiSimdError::Invalid64(_, i, input)) if len > 16 => {
+                                    assert_eq!((i, input), (s.len() - len + 16, s.as_bytes()));
+                                }
+                                res => assert!(run.is_none(), "input: {}, result: {:?}", s, res),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_overflow_index_zeroes!(
+    test_overflow_index_zeroes_u8: u8;
+    test_overflow_index_zeroes_u16: u16;
+    test_overflow_index_zeroes_u32: u32;
+    test_overflow_index_zeroes_u64: u64;
+    test_overflow_index_zeroes_u128: u128;
+    test_overflow_index_zeroes_i8: i8;
+    test_overflow_index_zeroes_i32: i32;
+    test_overflow_index_zeroes_i64: i64;
+    test_overflow_index_zeroes_i128: i128;
+);
+
+#[test]
+fn test_overflow_index_zeroes_input() {
+    let s = b"000000000000000277";
+    assert_eq!(
+        parse_prefix::<u8, true, true>(s),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 17, s))
+    );
+    assert_eq!(
+        parse::<u8, true, true>(s),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 17, s))
+    );
+    let s = format!("-{}{}", "0".repeat(22), "9".repeat(39));
+    assert_eq!(
+        parse::<i128, true, true>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 61, s.as_bytes()))
+    );
+    assert_eq!(
+        parse_prefix::<i128, true, true>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 61, s.as_bytes()))
+    );
+}
@@ -0,0 +1,227 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{parse_float, parse_prefix_float, AtoiSimdError};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+fn digits(state: &mut u64, len: u64) -> String {
+    (0..len)
+        .map(|_| (b'0' + (next(state) % 10) as u8) as char)
+        .collect()
+}
+
+/// Random number in the float syntax
+fn number(state: &mut u64) -> String {
+    let mut s = String::new();
+    if next(state) % 2 == 0 {
+        s.push('-');
+    }
+    let len = next(state) % 25;
+    s += &digits(state, len);
+    if next(state) % 2 == 0 || len == 0 {
+        s.push('.');
+        let len = next(state) % 25 + (len == 0) as u64;
+        s += &digits(state, len);
+    }
+    if next(state) % 2 == 0 {
+        s.push(if next(state) % 2 == 0 { 'e' } else { 'E' });
+        match next(state) % 3 {
+            0 => s.push('-'),
+            1 => s.push('+'),
+            _ => {}
+        }
+        let len = next(state) % 4 + 1;
+        s += &digits(state, len);
+    }
+    s
+}
+
+macro_rules! assert_float_eq {
+    ($t:ty, $s:expr) => {{
+        let s: &str = $s;
+        let expected = s.parse::<$t>().unwrap();
+        let res = parse_float::<$t, true, true>(s.as_bytes()).unwrap();
+        assert_eq!(res.to_bits(), expected.to_bits(), "input: {}", s);
+        let prefixed = format!("{}x", s);
+        let (res, len) = parse_prefix_float::<$t, true, true>(prefixed.as_bytes()).unwrap();
+        assert_eq!(
+            (res.to_bits(), len),
+            (expected.to_bits(), s.len()),
+            "input: {}",
+            prefixed
+        );
+    }};
+}
+
+#[test]
+fn test_float_random() {
+    let mut state = 1;
+    for _ in 0..100_000 {
+        let s = number(&mut state);
+        assert_float_eq!(f64, &s);
+        assert_float_eq!(f32, &s);
+    }
+}
+
+#[test]
+fn test_float_roundtrip() {
+    let mut state = 1;
+    for _ in 0..100_000 {
+        let bits = next(&mut state) << 32 | next(&mut state);
+        let v = f64::from_bits(bits);
+        if v.is_finite() {
+            assert_float_eq!(f64, &v.to_string());
+            assert_float_eq!(f64, &format!("{:e}", v));
+        }
+        let v = f32::from_bits(bits as u32);
+        if v.is_finite() {
+            assert_float_eq!(f32, &v.to_string());
+            assert_float_eq!(f32, &format!("{:E}", v));
+        }
+    }
+}
+
+#[test]
+fn test_float_exponents() {
+    let mut state = 1;
+    for _ in 0..100_000 {
+        let len = next(&mut state) % 19 + 1;
+        let exp = (next(&mut state) % 700) as i64 - 365;
+        let s = format!("{}e{}", digits(&mut state, len), exp);
+        assert_float_eq!(f64, &s);
+        assert_float_eq!(f32, &s);
+    }
+}
+
+#[test]
+fn test_float_halfway() {
+    let mut state = 1;
+    for _ in 0..10_000 {
+        let bits = next(&mut state) << 32 | next(&mut state);
+        // odd with one bit more than the mantissa, so it's between two floats
+        let m = (1 << 53) | (bits & ((1 << 53) - 1)) | 1;
+        for e in 0..10 {
+            assert_float_eq!(f64, &(m << e).to_string());
+        }
+        for k in 1..4 {
+            let s = format!("{}e-{}", m * 5u64.pow(k), k);
+            assert_float_eq!(f64, &s);
+        }
+        let m = m >> 29 | 1;
+        for e in 0..39 {
+            assert_float_eq!(f32, &(m << e).to_string());
+        }
+        for k in 1..17 {
+            let s = format!("{}e-{}", m * 5u64.pow(k), k);
+            assert_float_eq!(f32, &s);
+        }
+    }
+}
+
+#[test]
+fn test_float_special() {
+    for s in [
+        "0",
+        "-0",
+        "-0.0e5",
+        "0e99999999999999999999",
+        "1e308",
+        "1.7976931348623157e308",
+        "1.7976931348623159e308",
+        "2e308",
+        "4.9e-324",
+        "2.4e-324",
+        "2.5e-324",
+        "1e-400",
+        "9007199254740993",
+        "9007199254740992e22",
+        "123456789e30",
+        "3.4028235e38",
+        "3.4028236e38",
+        "1.17549435e-38",
+        "1.4e-45",
+        "7.0e-46",
+        "7.1e-46",
+        "2.2250738585072011e-308",
+        "2.2250738585072012e-308",
+        "4.9406564584124654e-324",
+        "1.7976931348623158e308",
+        "9007199254740995",
+        "16777217",
+        "16777219",
+        "1e23",
+        "8.589973e9",
+        "123456789012345678e-20",
+        "0.1",
+        "0.30000000000000004",
+        "00000000000000000000000000000001.5",
+        "1.00000000000000000000000000000000000000000000000000001",
+        "2.225073858507201136057409796709131975934819546351645648e-308",
+        "inf",
+        "-inf",
+        "Infinity",
+        "-INFINITY",
+    ] {
+        assert_float_eq!(f64, s);
+        assert_float_eq!(f32, s);
+    }
+    for s in ["nan", "NaN", "-nan"] {
+        let v = parse_float::<f64, false, true>(s.as_bytes()).unwrap();
+        assert_eq!(v.to_bits(), s.parse::<f64>().unwrap().to_bits());
+        let v = parse_float::<f32, false, true>(s.as_bytes()).unwrap();
+        assert_eq!(v.to_bits(), s.parse::<f32>().unwrap().to_bits());
+    }
+    let long = format!("0.{}1e400", "0".repeat(500));
+    assert_float_eq!(f64, &long);
+}
+
+#[test]
+fn test_float_errors() {
+    for s in [
+        "", "-", "+", ".", "-.", "e5", ".e5", "x", "inf", "nan", "+1",
+    ] {
+        assert_eq!(
+            parse_float::<f64, false, false>(s.as_bytes()),
+            Err(AtoiSimdError::Empty),
+            "input: {}",
+            s
+        );
+    }
+    for (s, int, index) in [
+        ("1e", 1, 1),
+        ("12e+", 12, 2),
+        ("-12.5x", 12, 5),
+        ("1.5.5", 1, 3),
+        ("1e5.5", 1, 3),
+        ("infx", 0, 3),
+        ("1 ", 1, 1),
+        ("-99999999999999999999999e", u64::MAX, 24),
+    ] {
+        assert_eq!(
+            parse_float::<f64, false, true>(s.as_bytes()),
+            Err(AtoiSimdError::Invalid64(int, index, s.as_bytes())),
+            "input: {}",
+            s
+        );
+        assert!(s.parse::<f64>().is_err());
+        assert_eq!(
+            parse_prefix_float::<f64, false, true>(s.as_bytes()).map(|(_, len)| len),
+            Ok(index),
+            "input: {}",
+            s
+        );
+    }
+    assert_eq!(parse_float::<f64, true, false>(b"+1.5"), Ok(1.5));
+    assert_eq!(
+        parse_prefix_float::<f32, false, false>(b"1.5e3,"),
+        Ok((1500.0, 5))
+    );
+}
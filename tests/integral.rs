@@ -0,0 +1,176 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{parse_integral, parse_prefix_integral, AtoiSimdError, Sign};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// Notations of the same integral value with the `digits`
+fn notations(digits: &str) -> Vec<String> {
+    let mut res = vec![
+        digits.to_string(),
+        format!("{}.", digits),
+        format!("{}.000", digits),
+        format!("{}e0", digits),
+        format!("{}0e-1", digits),
+        format!("{}00E-02", digits),
+        format!("000{}.0e+0", digits),
+    ];
+    for k in 1..digits.len() {
+        let (int, frac) = digits.split_at(digits.len() - k);
+        res.push(format!("{}.{}e{}", int, frac, k));
+        res.push(format!("{}.{}0E+{}", int, frac, k));
+        res.push(format!(".{}{}e{}", int, frac, digits.len()));
+    }
+    let trimmed = digits.trim_end_matches('0');
+    if !trimmed.is_empty() {
+        res.push(format!("{}e{}", trimmed, digits.len() - trimmed.len()));
+    }
+    res
+}
+
+macro_rules! test_integral_types {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            #[allow(unused_comparisons)]
+            let signed = <$t>::MIN < 0;
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..300).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1, 10, 100]);
+            for v in values {
+                let s = v.to_string();
+                let (sign, digits) = match s.strip_prefix('-') {
+                    Some(digits) => ("-", digits),
+                    None => ("", s.as_str()),
+                };
+                for n in notations(digits) {
+                    let n = format!("{}{}", sign, n);
+                    assert_eq!(parse_integral::<$t, false>(n.as_bytes()), Ok(v), "input: {}", n);
+                    let prefixed = format!("{},", n);
+                    assert_eq!(
+                        parse_prefix_integral::<$t, false>(prefixed.as_bytes()),
+                        Ok((v, n.len())),
+                        "input: {}",
+                        prefixed
+                    );
+                }
+
+                // fractions
+                for n in [format!("{}.5", s), format!("{}.0001e3", s), format!("{}e-1", s)] {
+                    let expected = if v % 10 == 0 && n.ends_with("e-1") {
+                        Ok(v / 10)
+                    } else {
+                        Err(AtoiSimdError::NotIntegral(n.as_bytes()))
+                    };
+                    assert_eq!(parse_integral::<$t, false>(n.as_bytes()), expected, "input: {}", n);
+                }
+            }
+
+            // overflows
+            let max = <$t>::MAX.to_string();
+            let min = <$t>::MIN.to_string();
+            for n in [format!("{}0", max), format!("{}e1", max), format!("{}.1e1", max), format!("{}e1", min), "1e39".to_string(), "1e99999999999".to_string()] {
+                if !signed && n.starts_with(&min) {
+                    continue;
+                }
+                let sign = if n.starts_with('-') { Sign::Neg } else { Sign::Pos };
+                assert_eq!(parse_integral::<$t, false>(n.as_bytes()), Err(AtoiSimdError::Overflow(sign, n.len(), n.as_bytes())), "input: {}", n);
+            }
+        }
+    )*};
+}
+
+test_integral_types!(
+    test_integral_u8: u8;
+    test_integral_i8: i8;
+    test_integral_u16: u16;
+    test_integral_i16: i16;
+    test_integral_u32: u32;
+    test_integral_i32: i32;
+    test_integral_usize: usize;
+    test_integral_isize: isize;
+    test_integral_u64: u64;
+    test_integral_i64: i64;
+    test_integral_u128: u128;
+    test_integral_i128: i128;
+);
+
+#[test]
+fn test_integral() {
+    assert_eq!(parse_integral::<u64, false>(b"1e6"), Ok(1_000_000));
+    assert_eq!(parse_integral::<i64, false>(b"-42.0"), Ok(-42));
+    assert_eq!(parse_integral::<i64, true>(b"+12.000"), Ok(12));
+    assert_eq!(parse_integral::<i64, false>(b"-0.0"), Ok(0));
+    assert_eq!(parse_integral::<i64, false>(b"0e99999999999"), Ok(0));
+    assert_eq!(parse_integral::<i64, false>(b"0.0e-5"), Ok(0));
+    assert_eq!(parse_integral::<u8, false>(b"25.5e1"), Ok(255));
+    let long = format!("0.{}1e100", "0".repeat(98));
+    assert_eq!(parse_integral::<u8, false>(long.as_bytes()), Ok(10));
+    let long = format!("1{}e-100", "0".repeat(100));
+    assert_eq!(parse_integral::<u8, false>(long.as_bytes()), Ok(1));
+    assert_eq!(
+        parse_integral::<u64, false>(b"1.5"),
+        Err(AtoiSimdError::NotIntegral(b"1.5"))
+    );
+    assert_eq!(
+        parse_integral::<u64, false>(b"1e-1"),
+        Err(AtoiSimdError::NotIntegral(b"1e-1"))
+    );
+    assert_eq!(
+        parse_integral::<u64, false>(b"1e-99999999999"),
+        Err(AtoiSimdError::NotIntegral(b"1e-99999999999"))
+    );
+}
+
+#[test]
+fn test_integral_errors() {
+    for s in ["", "-", ".", "-.", "e5", ".e5", "x", "+1"] {
+        assert_eq!(
+            parse_integral::<i64, false>(s.as_bytes()),
+            Err(AtoiSimdError::Empty),
+            "input: {}",
+            s
+        );
+    }
+    assert_eq!(
+        parse_integral::<u64, false>(b"-1"),
+        Err(AtoiSimdError::Empty)
+    );
+    for (s, v, index) in [
+        ("1e", 1, 1),
+        ("12e+", 12, 2),
+        ("-12.5x", 12, 5),
+        ("1.0.0", 1, 3),
+        ("1e5.5", 1, 3),
+        ("1 ", 1, 1),
+    ] {
+        assert_eq!(
+            parse_integral::<i64, false>(s.as_bytes()),
+            Err(AtoiSimdError::Invalid128(v, index, s.as_bytes())),
+            "input: {}",
+            s
+        );
+        assert_eq!(
+            parse_prefix_integral::<i64, false>(s.as_bytes()).map(|(_, len)| len),
+            if s == "-12.5x" {
+                Err(AtoiSimdError::NotIntegral(s.as_bytes()))
+            } else {
+                Ok(index)
+            },
+            "input: {}",
+            s
+        );
+    }
+    assert_eq!(
+        parse_integral::<u8, false>(b"300x"),
+        Err(AtoiSimdError::Invalid64(255, 3, b"300x"))
+    );
+}
@@ -0,0 +1,109 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{
+    parse, parse_neg, parse_pos, parse_prefix, parse_prefix_neg, parse_prefix_pos, AtoiSimdError,
+};
+use core::{fmt::Write, num::*};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+macro_rules! test_nonzero_types {
+    ($($name:ident: $nz:ident, $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..1000).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1]);
+            for v in values {
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", v).unwrap();
+                let expected = $nz::new(v).ok_or(AtoiSimdError::Zero(s.as_bytes()));
+                assert_eq!(parse::<$nz, false, false>(s.as_bytes()), expected, "input: {}", s);
+                assert_eq!(parse::<$nz, true, true>(s.as_bytes()), expected, "input: {}", s);
+                assert_eq!(
+                    parse_prefix::<$nz, false, false>(s.as_bytes()),
+                    expected.map(|v| (v, s.len())),
+                    "input: {}",
+                    s
+                );
+            }
+
+            // zeroes
+            for s in ["0", "00", "+0", "0x", "-0"] {
+                let s = s.as_bytes();
+                match parse_prefix::<$t, true, true>(s) {
+                    Ok((_, len)) => assert_eq!(parse_prefix::<$nz, true, true>(s), Err(AtoiSimdError::Zero(s)), "len: {}", len),
+                    Err(e) => assert_eq!(parse_prefix::<$nz, true, true>(s), Err(e)),
+                }
+            }
+            let s = "0".repeat(100);
+            assert_eq!(parse::<$nz, true, false>(s.as_bytes()), Err(AtoiSimdError::Zero(s.as_bytes())));
+            assert_eq!(parse_pos::<$nz, true>(s.as_bytes()), Err(AtoiSimdError::Zero(s.as_bytes())));
+
+            // other errors are the same
+            for s in ["", "-", "x", "1x", "+1", "1-"] {
+                let s = s.as_bytes();
+                assert_eq!(parse::<$nz, false, false>(s).unwrap_err(), parse::<$t, false, false>(s).unwrap_err());
+            }
+            let s = format!("{}0", <$t>::MAX);
+            assert_eq!(parse::<$nz, false, false>(s.as_bytes()).unwrap_err(), parse::<$t, false, false>(s.as_bytes()).unwrap_err());
+        }
+    )*};
+}
+
+test_nonzero_types!(
+    test_nonzero_u8: NonZeroU8, u8;
+    test_nonzero_i8: NonZeroI8, i8;
+    test_nonzero_u16: NonZeroU16, u16;
+    test_nonzero_i16: NonZeroI16, i16;
+    test_nonzero_u32: NonZeroU32, u32;
+    test_nonzero_i32: NonZeroI32, i32;
+    test_nonzero_usize: NonZeroUsize, usize;
+    test_nonzero_isize: NonZeroIsize, isize;
+    test_nonzero_u64: NonZeroU64, u64;
+    test_nonzero_i64: NonZeroI64, i64;
+    test_nonzero_u128: NonZeroU128, u128;
+    test_nonzero_i128: NonZeroI128, i128;
+);
+
+#[test]
+fn test_nonzero_neg() {
+    assert_eq!(
+        parse_neg::<NonZeroI32, false>(b"5"),
+        Ok(NonZeroI32::new(-5).unwrap())
+    );
+    assert_eq!(
+        parse_neg::<NonZeroI32, false>(b"0"),
+        Err(AtoiSimdError::Zero(b"0"))
+    );
+    assert_eq!(
+        parse_prefix_neg::<NonZeroI64, false>(b"128 "),
+        Ok((NonZeroI64::new(-128).unwrap(), 3))
+    );
+    assert_eq!(
+        parse_prefix_neg::<NonZeroI64, false>(b"0 "),
+        Err(AtoiSimdError::Zero(b"0 "))
+    );
+    assert_eq!(
+        parse_prefix_pos::<NonZeroU64, false>(b"42,"),
+        Ok((NonZeroU64::new(42).unwrap(), 2))
+    );
+    assert_eq!(
+        parse::<NonZeroI8, false, false>(b"-0"),
+        Err(AtoiSimdError::Zero(b"-0"))
+    );
+    // the variant depends on the kernel
+    assert!(matches!(
+        parse::<NonZeroU64, false, false>(b"0x"),
+        Err(AtoiSimdError::Invalid64(0, 1, b"0x")) | Err(AtoiSimdError::Invalid128(0, 1, b"0x"))
+    ));
+}
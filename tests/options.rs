@@ -0,0 +1,215 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{
+    parse, parse_prefix, parse_prefix_separated, parse_prefix_trimmed, parse_prefix_with,
+    parse_separated, parse_trimmed, parse_with, AtoiSimdError, ParseOptions, Separator,
+};
+
+const SEPARATORS: [Separator; 5] = [
+    Separator::Underscore,
+    Separator::Comma,
+    Separator::Dot,
+    Separator::Apostrophe,
+    Separator::Space,
+];
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// All of the combinations of the options
+fn all_options() -> Vec<ParseOptions> {
+    let mut res = Vec::new();
+    for separator in [None].into_iter().chain(SEPARATORS.map(Some)) {
+        for flags in 0..16 {
+            res.push(
+                ParseOptions::new()
+                    .skip_zeroes(flags & 1 != 0)
+                    .skip_plus(flags & 2 != 0)
+                    .trim(flags & 4 != 0)
+                    .strict(flags & 8 != 0)
+                    .separator(separator),
+            );
+        }
+    }
+    res
+}
+
+/// Formats `num` with everything the options allow: whitespace, the '+' sign, zeroes and separators.
+/// Returns the number, and the length of it before the trailing whitespace.
+fn format(
+    num: &str,
+    skip_zeroes: bool,
+    skip_plus: bool,
+    trim: bool,
+    sep: Option<u8>,
+) -> (Vec<u8>, usize) {
+    let mut s = Vec::new();
+    if trim {
+        s.extend_from_slice(b" \t");
+    }
+    let digits = match num.strip_prefix('-') {
+        Some(digits) => {
+            s.push(b'-');
+            digits
+        }
+        None if skip_plus => {
+            s.push(b'+');
+            num
+        }
+        None => num,
+    };
+    if skip_zeroes {
+        s.extend_from_slice(&[b'0'; 40]);
+    }
+    for (i, c) in digits.bytes().enumerate() {
+        if let Some(sep) = sep {
+            if i != 0 && (digits.len() - i) % 3 == 0 {
+                s.push(sep);
+            }
+        }
+        s.push(c);
+    }
+    let len = s.len();
+    if trim {
+        s.extend_from_slice(b"\r\n");
+    }
+    (s, len)
+}
+
+macro_rules! test_options_types {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..200).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1]);
+            let options = all_options();
+            for v in values {
+                let num = v.to_string();
+                for opts in &options {
+                    let sep = opts.separator.map(Separator::byte);
+                    let (s, len) = format(
+                        &num,
+                        opts.skip_zeroes,
+                        opts.skip_plus,
+                        opts.trim,
+                        sep,
+                    );
+                    assert_eq!(parse_with::<$t>(opts, &s), Ok(v), "input: {:X?}, {:?}", s, opts);
+
+                    let mut prefix = s[..len].to_vec();
+                    prefix.push(b'x');
+                    assert_eq!(
+                        parse_prefix_with::<$t>(opts, &prefix),
+                        Ok((v, len)),
+                        "input: {:X?}, {:?}",
+                        prefix,
+                        opts
+                    );
+                }
+
+                // the same as the const generic functions, including the errors
+                for sep in [None, Some(b',')] {
+                    for (skip_zeroes, skip_plus, trim) in [(false, true, false), (true, true, true)] {
+                        let (s, _) = format(&num, skip_zeroes, skip_plus, trim, sep);
+                        let s = s.as_slice();
+                        let no_opts = ParseOptions::new();
+                        assert_eq!(parse_with::<$t>(&no_opts, s), parse::<$t, false, false>(s));
+                        assert_eq!(
+                            parse_prefix_with::<$t>(&no_opts, s),
+                            parse_prefix::<$t, false, false>(s)
+                        );
+                        let trimmed = ParseOptions::new().trim(true);
+                        assert_eq!(parse_with::<$t>(&trimmed, s), parse_trimmed::<$t, false, false>(s));
+                        assert_eq!(
+                            parse_prefix_with::<$t>(&trimmed, s),
+                            parse_prefix_trimmed::<$t, false, false>(s)
+                        );
+                        let separated = ParseOptions::new().separator(Some(Separator::Comma)).strict(true);
+                        assert_eq!(
+                            parse_with::<$t>(&separated, s),
+                            parse_separated::<$t, b',', true, false, false>(s)
+                        );
+                        assert_eq!(
+                            parse_prefix_with::<$t>(&separated, s),
+                            parse_prefix_separated::<$t, b',', true, false, false>(s)
+                        );
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_options_types!(
+    test_options_u8: u8;
+    test_options_u16: u16;
+    test_options_u32: u32;
+    test_options_usize: usize;
+    test_options_u64: u64;
+    test_options_u128: u128;
+    test_options_i8: i8;
+    test_options_i16: i16;
+    test_options_i32: i32;
+    test_options_isize: isize;
+    test_options_i64: i64;
+    test_options_i128: i128;
+);
+
+#[test]
+fn test_options_builder() {
+    assert_eq!(ParseOptions::default(), ParseOptions::new());
+    let opts = ParseOptions::new()
+        .skip_zeroes(true)
+        .skip_plus(true)
+        .trim(true)
+        .separator(Some(Separator::Underscore))
+        .strict(true);
+    assert!(opts.skip_zeroes);
+    assert!(opts.skip_plus);
+    assert!(opts.trim);
+    assert_eq!(opts.separator, Some(Separator::Underscore));
+    assert!(opts.strict);
+    assert_eq!(opts.skip_plus(false).separator(None).separator, None);
+
+    for sep in SEPARATORS {
+        assert_eq!(Separator::from_byte(sep.byte()), Some(sep));
+    }
+    assert_eq!(Separator::from_byte(b'-'), None);
+    assert_eq!(Separator::from_byte(b'0'), None);
+}
+
+#[test]
+fn test_options_errors() {
+    let opts = ParseOptions::new()
+        .separator(Some(Separator::Underscore))
+        .strict(true);
+    assert_eq!(parse_with::<u32>(&opts, b"1_000"), Ok(1000));
+    assert_eq!(
+        parse_with::<u32>(&opts, b"1__000"),
+        parse_separated::<u32, b'_', true, false, false>(b"1__000")
+    );
+    assert_eq!(parse_prefix_with::<u32>(&opts, b"1__000"), Ok((1, 1)));
+    // not strict, the separators are ignored anywhere after the sign
+    assert_eq!(parse_with::<u32>(&opts.strict(false), b"1__000_"), Ok(1000));
+
+    // the error indices are in the indices of the input with the whitespace
+    let opts = opts.trim(true);
+    assert_eq!(
+        parse_with::<u8>(&opts, b"  2_56 "),
+        Err(AtoiSimdError::Overflow(atoi_simd::Sign::Pos, 5, b"  2_56 "))
+    );
+    assert_eq!(parse_with::<u8>(&opts, b" \n "), Err(AtoiSimdError::Empty));
+    assert!(parse_with::<i64>(&ParseOptions::new(), b"+1").is_err());
+    assert_eq!(
+        parse_with::<i64>(&ParseOptions::new().skip_plus(true), b"+1"),
+        Ok(1)
+    );
+}
@@ -0,0 +1,204 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{
+    parse, parse_neg, parse_pos, parse_prefix, parse_prefix_neg, parse_prefix_pos,
+    parse_prefix_saturating, parse_prefix_wrapping, parse_saturating, parse_wrapping,
+    AtoiSimdError,
+};
+use core::{fmt::Write, num::Wrapping};
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// Magnitude of the digits modulo 2^128, and if it's more than `max`
+fn magnitude(digits: &str, max: u128) -> (u128, bool) {
+    let mut mag: u128 = 0;
+    let mut overflow = false;
+    for c in digits.chars() {
+        let d = c.to_digit(10).unwrap() as u128;
+        overflow |= mag
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(d))
+            .map_or(true, |v| v > max);
+        mag = mag.wrapping_mul(10).wrapping_add(d);
+    }
+    (mag, overflow)
+}
+
+macro_rules! test_overflow_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            #[allow(unused_comparisons)]
+            let signed = <$t>::MIN < 0;
+            let mut state = 1;
+            for i in 0..1000 {
+                let mut s = ArrayString::<128>::new();
+                let neg = signed && i % 2 == 1;
+                if neg {
+                    s.push('-');
+                }
+                match i % 5 {
+                    0 => write!(s, "{}", next(&mut state) as $t as i128 as u128 % (<$t>::MAX as u128)).unwrap(),
+                    1 => write!(s, "{}{}", next(&mut state), next(&mut state)).unwrap(),
+                    2 => write!(s, "{:0>70}", next(&mut state)).unwrap(),
+                    _ => write!(s, "{}", next(&mut state)).unwrap(),
+                }
+                let digits = s.trim_start_matches('-');
+                let max = if neg { (<$t>::MIN as i128).unsigned_abs() } else { <$t>::MAX as u128 };
+                let (mag, overflow) = magnitude(digits, max);
+
+                let saturated = match (overflow, neg) {
+                    (true, true) => <$t>::MIN,
+                    (true, false) => <$t>::MAX,
+                    (false, true) => (mag as $t).wrapping_neg(),
+                    (false, false) => mag as $t,
+                };
+                let wrapped = if neg { (mag as $t).wrapping_neg() } else { mag as $t };
+
+                assert_eq!(parse_saturating::<$t, false>(s.as_bytes()), Ok(saturated), "input: {}", s);
+                assert_eq!(parse_wrapping::<$t, false>(s.as_bytes()), Ok(wrapped), "input: {}", s);
+                assert_eq!(parse_prefix_saturating::<$t, false>(s.as_bytes()), Ok((saturated, s.len())), "input: {}", s);
+                assert_eq!(parse_prefix_wrapping::<$t, false>(s.as_bytes()), Ok((wrapped, s.len())), "input: {}", s);
+                assert_eq!(parse::<Wrapping<$t>, false, false>(s.as_bytes()), Ok(Wrapping(wrapped)), "input: {}", s);
+                if !overflow && digits.len() < 30 {
+                    assert_eq!(parse::<$t, false, false>(s.as_bytes()), Ok(wrapped), "input: {}", s);
+                }
+
+                // the errors with the value before the invalid char
+                let mut invalid = s;
+                invalid.push('x');
+                let mag_sat = if overflow { max } else { mag };
+                let index = s.len();
+                let res = parse_saturating::<$t, false>(invalid.as_bytes());
+                match res {
+                    Err(AtoiSimdError::Invalid64(v, i, _)) => assert_eq!((v as u128, i), (mag_sat, index), "input: {}", invalid),
+                    Err(AtoiSimdError::Invalid128(v, i, _)) => assert_eq!((v, i), (mag_sat, index), "input: {}", invalid),
+                    e => panic!("input: {}, result: {:?}", invalid, e),
+                }
+                assert_eq!(
+                    parse_prefix_saturating::<$t, false>(invalid.as_bytes()),
+                    Ok((saturated, s.len())),
+                    "input: {}",
+                    invalid
+                );
+                assert_eq!(
+                    parse_prefix_wrapping::<$t, false>(invalid.as_bytes()),
+                    Ok((wrapped, s.len())),
+                    "input: {}",
+                    invalid
+                );
+            }
+        }
+    )*};
+}
+
+test_overflow_types!(
+    test_overflow_u8: u8,
+    test_overflow_i8: i8,
+    test_overflow_u16: u16,
+    test_overflow_i16: i16,
+    test_overflow_u32: u32,
+    test_overflow_i32: i32,
+    test_overflow_usize: usize,
+    test_overflow_isize: isize,
+    test_overflow_u64: u64,
+    test_overflow_i64: i64,
+    test_overflow_u128: u128,
+    test_overflow_i128: i128,
+);
+
+#[test]
+fn test_overflow_errors() {
+    for s in ["", "-", "x", "-x", "12x", "+1", "1-", "0x1"] {
+        let s = s.as_bytes();
+        assert_eq!(
+            parse_saturating::<i32, false>(s),
+            parse::<i32, false, false>(s)
+        );
+        assert_eq!(
+            parse_wrapping::<i32, false>(s),
+            parse::<i32, false, false>(s)
+        );
+        assert_eq!(
+            parse_saturating::<u64, true>(s),
+            parse::<u64, false, true>(s)
+        );
+        assert_eq!(
+            parse_prefix_wrapping::<i8, true>(s),
+            parse_prefix::<i8, false, true>(s)
+        );
+    }
+    assert_eq!(parse_saturating::<u8, true>(b"+1000"), Ok(255));
+    assert_eq!(parse_prefix_wrapping::<u8, true>(b"+257 "), Ok((1, 4)));
+    assert_eq!(
+        parse_wrapping::<u8, false>(b"999x"),
+        Err(AtoiSimdError::Invalid64(231, 3, b"999x"))
+    );
+    assert_eq!(
+        parse_saturating::<u32, false>(b"-1"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_prefix_saturating::<i8, false>(b"-300,"),
+        Ok((-128, 4))
+    );
+}
+
+#[test]
+fn test_overflow_wrappers() {
+    assert_eq!(parse::<Wrapping<u8>, false, false>(b"257"), Ok(Wrapping(1)));
+    assert_eq!(
+        parse_prefix::<Wrapping<i8>, false, false>(b"-129 "),
+        Ok((Wrapping(127), 4))
+    );
+    assert_eq!(parse_pos::<Wrapping<u16>, false>(b"65537"), Ok(Wrapping(1)));
+    assert_eq!(
+        parse_neg::<Wrapping<i16>, false>(b"32769"),
+        Ok(Wrapping(32767))
+    );
+    let s = format!("{}257", "0".repeat(100));
+    assert_eq!(
+        parse_prefix_pos::<Wrapping<u8>, false>(s.as_bytes()),
+        Ok((Wrapping(1), 103))
+    );
+    assert_eq!(
+        parse_prefix_neg::<Wrapping<i8>, false>(s.as_bytes()),
+        Ok((Wrapping(-1), 103))
+    );
+    assert_eq!(
+        parse::<Wrapping<u32>, false, false>(b"1x"),
+        Err(AtoiSimdError::Invalid64(1, 1, b"1x"))
+    );
+}
+
+#[rustversion::since(1.74)]
+#[test]
+fn test_overflow_saturating() {
+    use core::num::Saturating;
+
+    assert_eq!(
+        parse::<Saturating<u8>, false, false>(b"257"),
+        Ok(Saturating(255))
+    );
+    assert_eq!(
+        parse_prefix::<Saturating<i8>, false, false>(b"-129 "),
+        Ok((Saturating(-128), 4))
+    );
+    assert_eq!(
+        parse_pos::<Saturating<u64>, false>("9".repeat(100).as_bytes()),
+        Ok(Saturating(u64::MAX))
+    );
+    assert_eq!(
+        parse_neg::<Saturating<i128>, false>("9".repeat(100).as_bytes()),
+        Ok(Saturating(i128::MIN))
+    );
+}
@@ -0,0 +1,667 @@
+#![allow(clippy::from_str_radix_10)]
+
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{
+    parse_auto, parse_bin, parse_hex, parse_neg_radix, parse_oct, parse_pos_bin, parse_pos_hex,
+    parse_pos_oct, parse_pos_radix, parse_prefix_auto, parse_prefix_bin, parse_prefix_hex,
+    parse_prefix_neg_radix, parse_prefix_oct, parse_prefix_pos_bin, parse_prefix_pos_hex,
+    parse_prefix_pos_oct, parse_prefix_pos_radix, parse_prefix_radix, parse_radix, AtoiSimdError,
+    Sign,
+};
+use core::fmt::Write;
+
+const INVALID_HEX: &[char] = &['/', ':', '@', 'G', '`', 'g', '\0', '\x7f'];
+const INVALID_OCT: &[char] = &['/', '8', '9', 'a', '\0', '\x7f'];
+const INVALID_BIN: &[char] = &['/', '2', '9', 'a', '\0', '\x7f'];
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+macro_rules! check_radix {
+    ($s:expr, $t:ty, $radix:literal, $parse:ident, $parse_prefix:ident) => {{
+        let s: &str = $s;
+        let expected = <$t>::from_str_radix(s, $radix).ok();
+        assert_eq!(
+            $parse::<$t, true, true>(s.as_bytes()).ok(),
+            expected,
+            "input: {}",
+            s
+        );
+        assert_eq!(
+            $parse_prefix::<$t, true, true>(s.as_bytes()).ok(),
+            expected.map(|v| (v, s.len())),
+            "input: {}",
+            s
+        );
+        let shift = ($radix as u32).trailing_zeros();
+        if s.len() <= ((<$t>::BITS + shift - 1) / shift) as usize {
+            assert_eq!(
+                $parse::<$t, false, true>(s.as_bytes()).ok(),
+                expected,
+                "input: {}",
+                s
+            );
+        }
+        expected
+    }};
+}
+
+macro_rules! test_radix_types {
+    ($radix:literal, $fmt:literal, $invalid:expr, $parse:ident, $parse_prefix:ident; $($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            let mut s = ArrayString::<160>::new();
+            let mut state = 1;
+            let bits = <$t>::BITS;
+            let mut values = vec![<$t>::MIN, <$t>::MAX, 0, 1, <$t>::MAX - 1, <$t>::MIN + 1];
+            for i in 0..bits {
+                values.push((1 as $t) << i);
+                values.push(((1 as $t) << i).wrapping_sub(1));
+            }
+            for _ in 0..500 {
+                values.push(next(&mut state) as $t);
+            }
+
+            for v in values {
+                // negatives are formatted in two's complement, so write the sign separately
+                let mut digits = ArrayString::<160>::new();
+                #[allow(unused_comparisons)]
+                let sign = if v < 0 {
+                    write!(digits, $fmt, (v as i128).unsigned_abs()).unwrap();
+                    "-"
+                } else {
+                    write!(digits, $fmt, v).unwrap();
+                    ""
+                };
+
+                for plus in ["", "+"] {
+                    if plus == "+" && !sign.is_empty() {
+                        continue;
+                    }
+                    for zeroes in ["", "0000"] {
+                        s.clear();
+                        write!(s, "{}{}{}{}", sign, plus, zeroes, digits).unwrap();
+                        assert_eq!(check_radix!(&s, $t, $radix, $parse, $parse_prefix), Some(v), "input: {}", s);
+                        s.make_ascii_uppercase();
+                        assert_eq!(check_radix!(&s, $t, $radix, $parse, $parse_prefix), Some(v), "input: {}", s);
+                    }
+                }
+
+                // invalid char at each position
+                for j in 0..=digits.len() {
+                    for &ch in $invalid {
+                        s.clear();
+                        s.push_str(sign);
+                        s.push_str(&digits[..j]);
+                        s.push(ch);
+                        s.push_str(&digits[j..]);
+                        assert!(
+                            $parse::<$t, true, true>(s.as_bytes()).is_err(),
+                            "input: {}",
+                            s
+                        );
+                        let prefix = $parse_prefix::<$t, true, true>(s.as_bytes());
+                        if j == 0 {
+                            assert!(prefix.is_err(), "input: {}", s);
+                        } else {
+                            let len = sign.len() + j;
+                            assert_eq!(
+                                prefix,
+                                Ok((<$t>::from_str_radix(&s[..len], $radix).unwrap(), len)),
+                                "input: {}",
+                                s
+                            );
+                        }
+                    }
+                }
+            }
+
+            // overflow
+            s.clear();
+            write!(s, $fmt, <$t>::MAX).unwrap();
+            s.push('0');
+            assert!($parse::<$t, true, true>(s.as_bytes()).is_err(), "input: {}", s);
+            assert_eq!(check_radix!(&s, $t, $radix, $parse, $parse_prefix), None);
+        }
+    )*};
+}
+
+test_radix_types!(16, "{:x}", INVALID_HEX, parse_hex, parse_prefix_hex;
+    test_hex_u8: u8,
+    test_hex_i8: i8,
+    test_hex_u16: u16,
+    test_hex_i16: i16,
+    test_hex_u32: u32,
+    test_hex_i32: i32,
+    test_hex_usize: usize,
+    test_hex_isize: isize,
+    test_hex_u64: u64,
+    test_hex_i64: i64,
+    test_hex_u128: u128,
+    test_hex_i128: i128,
+);
+
+test_radix_types!(8, "{:o}", INVALID_OCT, parse_oct, parse_prefix_oct;
+    test_oct_u8: u8,
+    test_oct_i8: i8,
+    test_oct_u16: u16,
+    test_oct_i16: i16,
+    test_oct_u32: u32,
+    test_oct_i32: i32,
+    test_oct_usize: usize,
+    test_oct_isize: isize,
+    test_oct_u64: u64,
+    test_oct_i64: i64,
+    test_oct_u128: u128,
+    test_oct_i128: i128,
+);
+
+test_radix_types!(2, "{:b}", INVALID_BIN, parse_bin, parse_prefix_bin;
+    test_bin_u8: u8,
+    test_bin_i8: i8,
+    test_bin_u16: u16,
+    test_bin_i16: i16,
+    test_bin_u32: u32,
+    test_bin_i32: i32,
+    test_bin_usize: usize,
+    test_bin_isize: isize,
+    test_bin_u64: u64,
+    test_bin_i64: i64,
+    test_bin_u128: u128,
+    test_bin_i128: i128,
+);
+
+#[test]
+fn test_hex_errors() {
+    assert_eq!(
+        parse_hex::<u64, false, false>(b""),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_hex::<i64, false, true>(b"+"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_hex::<i64, false, true>(b"-"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert!(parse_hex::<u64, false, false>(b"+1").is_err());
+    assert!(parse_hex::<u64, false, true>(b"-1").is_err());
+    assert!(parse_hex::<u32, false, false>(b"0x12").is_err());
+
+    assert_eq!(
+        parse_hex::<u32, false, false>(b"12g4"),
+        Err(AtoiSimdError::Invalid64(0x12, 2, b"12g4"))
+    );
+    assert_eq!(
+        parse_hex::<i32, false, true>(b"-12g4"),
+        Err(AtoiSimdError::Invalid64(0x12, 3, b"-12g4"))
+    );
+    assert_eq!(
+        parse_hex::<u128, false, false>(b"ffffffffffffffffffz"),
+        Err(AtoiSimdError::Invalid128(
+            0xff_ffff_ffff_ffff_ffff,
+            18,
+            b"ffffffffffffffffffz"
+        ))
+    );
+    assert_eq!(
+        parse_hex::<u8, false, false>(b"100"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 2, b"100"))
+    );
+    assert_eq!(
+        parse_hex::<u64, false, false>(b"00000000000000001"),
+        Err(AtoiSimdError::Size(17, b"00000000000000001"))
+    );
+    assert_eq!(parse_hex::<u64, true, false>(b"00000000000000001"), Ok(1));
+    assert_eq!(
+        parse_hex::<u64, true, false>(b"00000000000000000000"),
+        Ok(0)
+    );
+}
+
+#[test]
+fn test_hex_pos_prefix() {
+    assert_eq!(parse_pos_hex::<u16, false>(b"fFfF"), Ok(0xffff));
+    assert!(parse_pos_hex::<i16, false>(b"-1").is_err());
+    assert_eq!(
+        parse_prefix_pos_hex::<u64, false>(b"deadbeef;"),
+        Ok((0xdead_beef, 8))
+    );
+    assert_eq!(parse_prefix_hex::<i64, false, true>(b"+7f "), Ok((0x7f, 3)));
+    assert_eq!(
+        parse_prefix_hex::<i8, false, false>(b"-80,"),
+        Ok((i8::MIN, 3))
+    );
+    assert_eq!(
+        parse_prefix_hex::<u128, true, false>(b"000000000000000000000000000000000000001x"),
+        Ok((1, 39))
+    );
+}
+
+#[test]
+fn test_oct_bin_errors() {
+    assert_eq!(
+        parse_oct::<u64, false, false>(b""),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_bin::<i8, false, true>(b"-"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert!(parse_oct::<u32, false, false>(b"0o17").is_err());
+    assert!(parse_bin::<u32, false, false>(b"0b1").is_err());
+
+    assert_eq!(
+        parse_oct::<u32, false, false>(b"1781"),
+        Err(AtoiSimdError::Invalid64(0o17, 2, b"1781"))
+    );
+    assert_eq!(
+        parse_bin::<i16, false, true>(b"-1012"),
+        Err(AtoiSimdError::Invalid64(0b101, 4, b"-1012"))
+    );
+    assert_eq!(
+        parse_bin::<u128, false, false>(b"111111111111111111112"),
+        Err(AtoiSimdError::Invalid128(
+            0b1111_1111_1111_1111_1111,
+            20,
+            b"111111111111111111112"
+        ))
+    );
+    assert_eq!(
+        parse_oct::<u8, false, false>(b"400"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 2, b"400"))
+    );
+    // 22 digits, but the first one doesn't fit into 64 bits
+    assert_eq!(
+        parse_oct::<u64, false, false>(b"2000000000000000000000"),
+        Err(AtoiSimdError::Overflow(
+            Sign::Pos,
+            21,
+            b"2000000000000000000000"
+        ))
+    );
+    assert_eq!(
+        parse_oct::<u64, false, false>(b"1777777777777777777777"),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        parse_bin::<u8, false, false>(b"100000000"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 8, b"100000000"))
+    );
+    assert_eq!(
+        parse_bin::<u64, false, false>(&[b'0'; 65]),
+        Err(AtoiSimdError::Size(65, &[b'0'; 65]))
+    );
+    assert_eq!(parse_bin::<u64, true, false>(&[b'0'; 65]), Ok(0));
+    assert_eq!(
+        parse_oct::<u128, false, false>(&[b'0'; 44]),
+        Err(AtoiSimdError::Size(44, &[b'0'; 44]))
+    );
+    assert_eq!(parse_oct::<u128, true, false>(&[b'1'; 1][..]), Ok(1));
+}
+
+#[test]
+fn test_oct_bin_pos_prefix() {
+    assert_eq!(parse_pos_oct::<u16, false>(b"644"), Ok(0o644));
+    assert!(parse_pos_oct::<i16, false>(b"-1").is_err());
+    assert_eq!(parse_pos_bin::<u8, false>(b"11111111"), Ok(u8::MAX));
+    assert_eq!(parse_prefix_pos_oct::<u32, false>(b"0755 "), Ok((0o755, 4)));
+    assert_eq!(
+        parse_prefix_pos_bin::<u64, false>(b"10102"),
+        Ok((0b1010, 4))
+    );
+    assert_eq!(parse_prefix_oct::<i64, false, true>(b"+17,"), Ok((0o17, 3)));
+    assert_eq!(
+        parse_prefix_bin::<i8, false, false>(b"-10000000;"),
+        Ok((i8::MIN, 9))
+    );
+}
+
+fn write_radix(s: &mut ArrayString<160>, mut v: u128, radix: u32) {
+    let mut digits = [0u8; 128];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = char::from_digit((v % radix as u128) as u32, radix).unwrap() as u8;
+        v /= radix as u128;
+        if v == 0 {
+            break;
+        }
+    }
+    s.push_str(core::str::from_utf8(&digits[i..]).unwrap());
+}
+
+macro_rules! check_generic {
+    ($t:ty; $($radix:literal)*) => {$({
+        let mut s = ArrayString::<160>::new();
+        let mut state = $radix;
+        let mut values = vec![<$t>::MIN, <$t>::MAX, 0, 1, <$t>::MAX - 1, <$t>::MIN + 1];
+        for _ in 0..100 {
+            values.push(next(&mut state) as $t);
+        }
+        // the first char that is not a digit in this radix
+        let invalid = char::from_digit($radix - 1, 36).unwrap() as u8 + 1;
+
+        for v in values {
+            s.clear();
+            #[allow(unused_comparisons)]
+            if v < 0 {
+                s.push('-');
+                write_radix(&mut s, (v as i128).unsigned_abs(), $radix);
+            } else {
+                write_radix(&mut s, v as u128, $radix);
+            }
+            assert_eq!(
+                parse_radix::<$t, $radix, false, false>(s.as_bytes()),
+                Ok(v),
+                "input: {}, radix: {}",
+                s,
+                $radix
+            );
+            s.make_ascii_uppercase();
+            assert_eq!(
+                parse_radix::<$t, $radix, true, true>(s.as_bytes()),
+                Ok(v),
+                "input: {}, radix: {}",
+                s,
+                $radix
+            );
+
+            let len = s.len();
+            for ch in [invalid, b'/', b' '] {
+                s.push(ch as char);
+                assert_eq!(
+                    parse_prefix_radix::<$t, $radix, false, false>(s.as_bytes()),
+                    Ok((v, len)),
+                    "input: {}, radix: {}",
+                    s,
+                    $radix
+                );
+                assert!(
+                    parse_radix::<$t, $radix, false, false>(s.as_bytes()).is_err(),
+                    "input: {}, radix: {}",
+                    s,
+                    $radix
+                );
+                s.truncate(len);
+            }
+
+            let mut plus = ArrayString::<160>::new();
+            plus.push('+');
+            plus.push_str(&s);
+            #[allow(unused_comparisons)]
+            if v >= 0 {
+                assert_eq!(
+                    parse_prefix_radix::<$t, $radix, false, true>(plus.as_bytes()),
+                    Ok((v, len + 1)),
+                    "input: {}, radix: {}",
+                    s,
+                    $radix
+                );
+            }
+            assert_eq!(
+                parse_radix::<$t, $radix, false, true>(plus.as_bytes()).ok(),
+                <$t>::from_str_radix(&plus, $radix).ok(),
+                "input: {}, radix: {}",
+                plus,
+                $radix
+            );
+        }
+
+        s.clear();
+        write_radix(&mut s, <$t>::MAX as u128, $radix);
+        s.push('0');
+        // the decimal parser has a digits limit, so it can fail before the overflow
+        assert!(matches!(
+            parse_radix::<$t, $radix, false, false>(s.as_bytes()),
+            Err(AtoiSimdError::Overflow(Sign::Pos, i, _)) if i == s.len() - 1
+        ) || matches!(
+            parse_radix::<$t, $radix, false, false>(s.as_bytes()),
+            Err(AtoiSimdError::Size(..))
+        ), "input: {}, radix: {}", s, $radix);
+        assert_eq!(
+            parse_radix::<$t, $radix, false, false>(b""),
+            Err(AtoiSimdError::Empty)
+        );
+    })*};
+}
+
+macro_rules! test_generic_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            check_generic!($t; 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36);
+        }
+    )*};
+}
+
+test_generic_types!(
+    test_generic_u8: u8,
+    test_generic_i8: i8,
+    test_generic_u16: u16,
+    test_generic_i16: i16,
+    test_generic_u32: u32,
+    test_generic_i32: i32,
+    test_generic_usize: usize,
+    test_generic_isize: isize,
+    test_generic_u64: u64,
+    test_generic_i64: i64,
+    test_generic_u128: u128,
+    test_generic_i128: i128,
+);
+
+#[test]
+fn test_generic_errors() {
+    assert_eq!(
+        parse_radix::<u32, 36, false, false>(b"zz!"),
+        Err(AtoiSimdError::Invalid64(1295, 2, b"zz!"))
+    );
+    assert_eq!(
+        parse_radix::<i32, 3, false, true>(b"-1213"),
+        Err(AtoiSimdError::Invalid64(16, 4, b"-1213"))
+    );
+    // radix 10 is the decimal parser, its variant depends on the kernel
+    assert!(matches!(
+        parse_radix::<i64, 10, false, true>(b"-12a"),
+        Err(AtoiSimdError::Invalid64(12, 3, b"-12a"))
+            | Err(AtoiSimdError::Invalid128(12, 3, b"-12a"))
+    ));
+    assert_eq!(
+        parse_prefix_radix::<u64, 10, false, true>(b"+12a"),
+        Ok((12, 3))
+    );
+    assert_eq!(
+        parse_radix::<u8, 7, false, false>(b"514"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 2, b"514"))
+    );
+    assert_eq!(
+        parse_radix::<u64, 36, false, false>(b"00000000000001"),
+        Err(AtoiSimdError::Size(14, b"00000000000001"))
+    );
+    assert_eq!(
+        parse_radix::<u64, 36, true, false>(b"00000000000001"),
+        Ok(1)
+    );
+}
+
+#[test]
+fn test_generic_pos_neg() {
+    assert_eq!(parse_pos_radix::<u8, 36, false>(b"73"), Ok(255));
+    assert_eq!(parse_pos_radix::<i8, 36, false>(b"3j"), Ok(127));
+    assert_eq!(
+        parse_pos_radix::<i8, 36, false>(b"3k"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 1, b"3k"))
+    );
+    assert_eq!(parse_neg_radix::<i8, 36, false>(b"3k"), Ok(-128));
+    assert_eq!(
+        parse_neg_radix::<i8, 36, false>(b"3l"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 1, b"3l"))
+    );
+    assert_eq!(
+        parse_neg_radix::<i128, 16, false>(b"80000000000000000000000000000000"),
+        Ok(i128::MIN)
+    );
+    assert_eq!(parse_neg_radix::<i64, 10, true>(b"0009"), Ok(-9));
+    assert_eq!(
+        parse_prefix_pos_radix::<u32, 20, false>(b"jj "),
+        Ok((399, 2))
+    );
+    assert_eq!(
+        parse_prefix_neg_radix::<i32, 20, false>(b"jj-"),
+        Ok((-399, 2))
+    );
+    assert_eq!(
+        parse_prefix_neg_radix::<i16, 2, false>(b"1000000000000000"),
+        Ok((i16::MIN, 16))
+    );
+}
+
+#[test]
+#[should_panic(expected = "RADIX must be in 2..=36")]
+fn test_generic_invalid_radix() {
+    let _ = parse_radix::<u64, 37, false, false>(b"1");
+}
+
+macro_rules! test_auto_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 3;
+            let mut values = vec![<$t>::MIN, <$t>::MAX, 0, 1, <$t>::MAX - 1, <$t>::MIN + 1];
+            for _ in 0..200 {
+                values.push(next(&mut state) as $t);
+            }
+
+            for v in values {
+                #[allow(unused_comparisons)]
+                let (sign, abs) = if v < 0 {
+                    ("-", (v as i128).unsigned_abs())
+                } else {
+                    ("", v as u128)
+                };
+                for (prefix, digits) in [
+                    ("", format!("{}", abs)),
+                    ("0x", format!("{:x}", abs)),
+                    ("0X", format!("{:X}", abs)),
+                    ("0o", format!("{:o}", abs)),
+                    ("0O", format!("{:o}", abs)),
+                    ("0b", format!("{:b}", abs)),
+                    ("0B", format!("{:b}", abs)),
+                ] {
+                    let s = format!("{}{}{}", sign, prefix, digits);
+                    assert_eq!(parse_auto::<$t, false, false>(s.as_bytes()), Ok(v), "input: {}", s);
+                    assert_eq!(
+                        parse_prefix_auto::<$t, false, false>(format!("{};", s).as_bytes()),
+                        Ok((v, s.len())),
+                        "input: {}",
+                        s
+                    );
+
+                    let err = format!("{}{}{}#", sign, prefix, digits);
+                    let invalid_128 = AtoiSimdError::Invalid128(abs as u128, s.len(), err.as_bytes());
+                    let invalid_64 = AtoiSimdError::Invalid64(abs as u64, s.len(), err.as_bytes());
+                    let res = parse_auto::<$t, false, false>(err.as_bytes());
+                    if prefix.is_empty() && <$t>::BITS == 64 {
+                        // the variant of the 64-bit decimal parser depends on the kernel
+                        assert!(res == Err(invalid_64) || res == Err(invalid_128), "input: {}", err);
+                    } else if <$t>::BITS == 128 {
+                        assert_eq!(res, Err(invalid_128), "input: {}", err);
+                    } else {
+                        assert_eq!(res, Err(invalid_64), "input: {}", err);
+                    }
+
+                    #[allow(unused_comparisons)]
+                    if v >= 0 {
+                        let s = format!("+{}{}", prefix, digits);
+                        assert_eq!(parse_auto::<$t, false, true>(s.as_bytes()), Ok(v), "input: {}", s);
+                        assert!(parse_auto::<$t, false, false>(s.as_bytes()).is_err(), "input: {}", s);
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_auto_types!(
+    test_auto_u8: u8,
+    test_auto_i8: i8,
+    test_auto_u16: u16,
+    test_auto_i16: i16,
+    test_auto_u32: u32,
+    test_auto_i32: i32,
+    test_auto_usize: usize,
+    test_auto_isize: isize,
+    test_auto_u64: u64,
+    test_auto_i64: i64,
+    test_auto_u128: u128,
+    test_auto_i128: i128,
+);
+
+#[test]
+fn test_auto_errors() {
+    assert_eq!(
+        parse_auto::<u32, false, false>(b""),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_auto::<i32, false, false>(b"-"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_auto::<u32, false, false>(b"-0x1"),
+        Err(AtoiSimdError::Empty)
+    );
+    // leading zero is still decimal
+    assert_eq!(parse_auto::<u32, false, false>(b"0755"), Ok(755));
+
+    // a prefix without digits
+    assert_eq!(
+        parse_auto::<u32, false, false>(b"0x"),
+        Err(AtoiSimdError::Invalid64(0, 1, b"0x"))
+    );
+    assert_eq!(
+        parse_auto::<i16, false, false>(b"-0bz"),
+        Err(AtoiSimdError::Invalid64(0, 2, b"-0bz"))
+    );
+    assert_eq!(parse_prefix_auto::<u32, false, false>(b"0xg"), Ok((0, 1)));
+    assert_eq!(parse_prefix_auto::<i32, false, true>(b"+0o8"), Ok((0, 2)));
+
+    // indices are relative to the whole slice
+    assert_eq!(
+        parse_auto::<i32, false, false>(b"-0x1fz"),
+        Err(AtoiSimdError::Invalid64(0x1f, 5, b"-0x1fz"))
+    );
+    assert_eq!(
+        parse_auto::<u8, false, false>(b"0b102"),
+        Err(AtoiSimdError::Invalid64(0b10, 4, b"0b102"))
+    );
+    assert_eq!(
+        parse_auto::<u8, false, false>(b"0x100"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 4, b"0x100"))
+    );
+    assert_eq!(
+        parse_auto::<u64, false, false>(
+            b"0b00000000000000000000000000000000000000000000000000000000000000001"
+        ),
+        Err(AtoiSimdError::Size(
+            67,
+            b"0b00000000000000000000000000000000000000000000000000000000000000001"
+        ))
+    );
+    assert_eq!(
+        parse_auto::<u64, true, false>(
+            b"0b00000000000000000000000000000000000000000000000000000000000000001"
+        ),
+        Ok(1)
+    );
+}
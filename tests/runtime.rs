@@ -0,0 +1,101 @@
+#![cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{set_simd_level, simd_level, SimdLevel};
+
+macro_rules! check_types {
+    ($($t:ty)*) => {$(
+        for v in [<$t>::MIN, <$t>::MIN / 7, 0, 1, 9, 10, <$t>::MAX / 3, <$t>::MAX - 1, <$t>::MAX] {
+            let s = v.to_string();
+            assert_eq!(parse::<$t>(s.as_bytes()), Ok(v), "input: {}", s);
+            assert_eq!(
+                parse_prefix::<$t>(format!("{}s", s).as_bytes()),
+                Ok((v, s.len())),
+                "input: {}",
+                s
+            );
+            assert!(parse::<$t>(format!("{}s", s).as_bytes()).is_err(), "input: {}", s);
+        }
+        assert!(parse::<$t>(b"").is_err());
+        assert!(parse::<$t>(format!("{}0", <$t>::MAX).as_bytes()).is_err());
+        if <$t>::MIN != 0 {
+            assert!(parse::<$t>(format!("{}0", <$t>::MIN).as_bytes()).is_err());
+        }
+        assert_eq!(
+            parse_skipped::<$t>(b"+000000000000000000000000000000000000000000000000000000123"),
+            Ok(123)
+        );
+    )*};
+}
+
+macro_rules! check_neg_types {
+    ($($t:ty)*) => {$(
+        assert_eq!(parse_neg::<$t>(b"123"), Ok(-123));
+        assert_eq!(parse_prefix_neg::<$t>(b"123s"), Ok((-123, 3)));
+        assert!(parse_neg::<$t>(format!("{}0", <$t>::MAX).as_bytes()).is_err());
+    )*};
+}
+
+macro_rules! check_radix_types {
+    ($($t:ty)*) => {$(
+        for v in [<$t>::MIN, 0, 1, <$t>::MAX / 3, <$t>::MAX] {
+            #[allow(unused_comparisons)]
+            let (sign, abs) = if v < 0 {
+                ("-", (v as i128).unsigned_abs())
+            } else {
+                ("", v as u128)
+            };
+            for (s, radix) in [
+                (format!("{}{:x}", sign, abs), 16),
+                (format!("{}{:o}", sign, abs), 8),
+                (format!("{}{:b}", sign, abs), 2),
+            ] {
+                let res = match radix {
+                    16 => atoi_simd::parse_prefix_hex::<$t, false, false>(s.as_bytes()),
+                    8 => atoi_simd::parse_prefix_oct::<$t, false, false>(s.as_bytes()),
+                    _ => atoi_simd::parse_prefix_bin::<$t, false, false>(s.as_bytes()),
+                };
+                assert_eq!(res, Ok((v, s.len())), "input: {}, radix: {}", s, radix);
+            }
+        }
+    )*};
+}
+
+/// Sets the level back when the test ends, even if it fails:
+/// the level is global, it would change the parser of the other tests in this binary
+struct RestoreLevel(SimdLevel);
+
+impl Drop for RestoreLevel {
+    fn drop(&mut self) {
+        assert!(set_simd_level(self.0));
+    }
+}
+
+/// The levels only switch the kernels which aren't enabled at compile time:
+/// with the SSE4.1 target features every level parses the 32-bit types with SSE,
+/// and with AVX2 the feature changes nothing. The fallback and SSE tables are tested
+/// only without the target features (`RUSTFLAGS` without `-C target-feature`, see the CI).
+#[test]
+fn test_each_level() {
+    let detected = simd_level();
+    let restore = RestoreLevel(detected);
+    for level in [SimdLevel::Fallback, SimdLevel::Sse41, SimdLevel::Avx2] {
+        if !set_simd_level(level) {
+            assert!(level > detected);
+            continue;
+        }
+        assert_eq!(simd_level(), level);
+
+        check_types!(u8 i8 u16 i16 u32 i32 usize isize u64 i64 u128 i128);
+        check_neg_types!(i8 i16 i32 isize i64 i128);
+        check_radix_types!(u8 i8 u16 i16 u32 i32 usize isize u64 i64 u128 i128);
+    }
+    drop(restore);
+    assert_eq!(simd_level(), detected);
+}
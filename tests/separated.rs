@@ -0,0 +1,484 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{
+    parse, parse_grouped, parse_prefix_grouped, parse_prefix_separated, parse_separated,
+    AtoiSimdError, Grouping, Sign,
+};
+use core::fmt::Write;
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// Inserts `sep` after every digit marked in `mask`, except the last one
+fn separate(s: &str, sep: char, mut mask: u128) -> ArrayString<200> {
+    let mut res = ArrayString::new();
+    let last = s.len() - 1;
+    for (i, c) in s.chars().enumerate() {
+        res.push(c);
+        if c.is_ascii_digit() && i != last && mask & 1 != 0 {
+            res.push(sep);
+        }
+        mask >>= 1;
+    }
+    res
+}
+
+/// Groups the digits like `Grouping::Western` (`indian == false`) or `Grouping::Indian`
+fn group(s: &str, sep: char, indian: bool) -> ArrayString<200> {
+    let mut res = ArrayString::new();
+    let digits = s.trim_start_matches('-');
+    if digits.len() != s.len() {
+        res.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        let left = digits.len() - i;
+        if i != 0 && (left == 3 || left > 3 && (left - 3) % if indian { 2 } else { 3 } == 0) {
+            res.push(sep);
+        }
+        res.push(c);
+    }
+    res
+}
+
+macro_rules! test_separated_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            for _ in 0..500 {
+                let val = next(&mut state) as $t;
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", val).unwrap();
+
+                // grouped by thousands
+                let mut grouped = ArrayString::<200>::new();
+                let sign = (val < 0 as $t) as usize;
+                for (i, c) in s.chars().enumerate() {
+                    if i > sign && (s.len() - i) % 3 == 0 {
+                        grouped.push('_');
+                    }
+                    grouped.push(c);
+                }
+                assert_eq!(parse_separated::<$t, b'_', true, false, false>(grouped.as_bytes()), Ok(val), "input: {}", grouped);
+                assert_eq!(
+                    parse_prefix_separated::<$t, b'_', true, false, false>(grouped.as_bytes()),
+                    Ok((val, grouped.len())),
+                    "input: {}",
+                    grouped
+                );
+
+                // random separators
+                let mask = next(&mut state);
+                let separated = separate(&s, '\'', mask);
+                assert_eq!(parse_separated::<$t, b'\'', true, false, false>(separated.as_bytes()), Ok(val), "input: {}", separated);
+                assert_eq!(parse_separated::<$t, b'\'', false, false, false>(separated.as_bytes()), Ok(val), "input: {}", separated);
+
+                // doubled separators are only valid in non-strict mode
+                let mut doubled = ArrayString::<200>::new();
+                for c in separated.chars() {
+                    doubled.push(c);
+                    if c == '\'' {
+                        doubled.push(c);
+                    }
+                }
+                assert_eq!(parse_separated::<$t, b'\'', false, false, false>(doubled.as_bytes()), Ok(val), "input: {}", doubled);
+                if doubled.len() != separated.len() {
+                    assert!(parse_separated::<$t, b'\'', true, false, false>(doubled.as_bytes()).is_err(), "input: {}", doubled);
+                    let (v, len) = parse_prefix_separated::<$t, b'\'', true, false, false>(doubled.as_bytes()).unwrap();
+                    assert_eq!(doubled.as_bytes()[len], b'\'');
+                    assert_eq!(parse::<$t, false, false>(doubled[..len].replace('\'', "").as_bytes()), Ok(v));
+                }
+
+                let western = group(&s, ',', false);
+                assert_eq!(parse_grouped::<$t, b',', false, false>(western.as_bytes(), Grouping::Western), Ok(val), "input: {}", western);
+                assert_eq!(parse_grouped::<$t, b',', false, false>(western.as_bytes(), Grouping::Lenient), Ok(val), "input: {}", western);
+                assert_eq!(
+                    parse_prefix_grouped::<$t, b',', false, false>(western.as_bytes(), Grouping::Western),
+                    Ok((val, western.len())),
+                    "input: {}",
+                    western
+                );
+                let indian = group(&s, '.', true);
+                assert_eq!(parse_grouped::<$t, b'.', false, false>(indian.as_bytes(), Grouping::Indian), Ok(val), "input: {}", indian);
+                assert_eq!(parse_grouped::<$t, b'.', false, false>(indian.as_bytes(), Grouping::Lenient), Ok(val), "input: {}", indian);
+                assert_eq!(parse_grouped::<$t, b'.', false, false>(s.as_bytes(), Grouping::Indian), Ok(val), "input: {}", s);
+            }
+        }
+    )*};
+}
+
+test_separated_types!(
+    test_separated_u8: u8,
+    test_separated_i8: i8,
+    test_separated_u16: u16,
+    test_separated_i16: i16,
+    test_separated_u32: u32,
+    test_separated_i32: i32,
+    test_separated_usize: usize,
+    test_separated_isize: isize,
+    test_separated_u64: u64,
+    test_separated_i64: i64,
+    test_separated_u128: u128,
+    test_separated_i128: i128,
+);
+
+#[test]
+fn test_separated_strict() {
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"1_000_000"),
+        Ok(1_000_000)
+    );
+    assert_eq!(
+        parse_separated::<i32, b',', true, false, false>(b"-1,000"),
+        Ok(-1000)
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, true>(b"+1_0"),
+        Ok(10)
+    );
+
+    // the separator at the beginning is invalid, not an empty number
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"_1"),
+        Err(AtoiSimdError::Invalid64(0, 0, b"_1"))
+    );
+    assert_eq!(
+        parse_separated::<i32, b'_', true, false, false>(b"-_1"),
+        Err(AtoiSimdError::Invalid64(0, 1, b"-_1"))
+    );
+    assert_eq!(
+        parse_separated::<u128, b'_', true, true, true>(b"+_1"),
+        Err(AtoiSimdError::Invalid128(0, 1, b"+_1"))
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"_"),
+        Err(AtoiSimdError::Invalid64(0, 0, b"_"))
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"x"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"1_"),
+        Err(AtoiSimdError::Invalid64(1, 1, b"1_"))
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"1__2"),
+        Err(AtoiSimdError::Invalid64(1, 1, b"1__2"))
+    );
+    assert_eq!(
+        parse_separated::<i32, b'_', true, false, false>(b"-1_2_x"),
+        Err(AtoiSimdError::Invalid64(12, 4, b"-1_2_x"))
+    );
+    // the variant of the 64-bit decimal parser depends on the kernel
+    assert!(matches!(
+        parse_separated::<u64, b'_', true, false, false>(b"1_23a"),
+        Err(AtoiSimdError::Invalid64(123, 4, b"1_23a"))
+            | Err(AtoiSimdError::Invalid128(123, 4, b"1_23a"))
+    ));
+
+    assert_eq!(
+        parse_prefix_separated::<u32, b'_', true, false, false>(b"1__2"),
+        Ok((1, 1))
+    );
+    assert_eq!(
+        parse_prefix_separated::<u32, b'_', true, false, false>(b"12_"),
+        Ok((12, 2))
+    );
+    assert_eq!(
+        parse_prefix_separated::<i32, b'_', true, false, false>(b"-1_2 3"),
+        Ok((-12, 4))
+    );
+    // no number at the beginning
+    assert_eq!(
+        parse_prefix_separated::<u32, b'_', true, false, false>(b"_1"),
+        Err(AtoiSimdError::Empty)
+    );
+}
+
+#[test]
+fn test_separated_loose() {
+    assert_eq!(
+        parse_separated::<u32, b'_', false, false, false>(b"_1__0_"),
+        Ok(10)
+    );
+    assert_eq!(
+        parse_separated::<i32, b' ', false, false, false>(b"- 1 000"),
+        Ok(-1000)
+    );
+    assert_eq!(
+        parse_prefix_separated::<u32, b'_', false, false, false>(b"1__2_x"),
+        Ok((12, 5))
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', false, false, false>(b"___"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', false, false, false>(b"1_2_x"),
+        Err(AtoiSimdError::Invalid64(12, 4, b"1_2_x"))
+    );
+}
+
+#[test]
+fn test_separated_overflow() {
+    assert_eq!(
+        parse_separated::<u8, b'_', true, false, false>(b"2_55"),
+        Ok(255)
+    );
+    assert_eq!(
+        parse_separated::<u8, b'_', true, false, false>(b"2_56"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 3, b"2_56"))
+    );
+    assert_eq!(
+        parse_separated::<i8, b'_', true, false, false>(b"-1_29"),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 4, b"-1_29"))
+    );
+    assert_eq!(
+        parse_separated::<u128, b'_', false, false, false>(
+            b"340_282_366_920_938_463_463_374_607_431_768_211_456"
+        ),
+        Err(AtoiSimdError::Overflow(
+            Sign::Pos,
+            50,
+            b"340_282_366_920_938_463_463_374_607_431_768_211_456"
+        ))
+    );
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(b"1_000_000_000_0"),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 14, b"1_000_000_000_0"))
+    );
+}
+
+#[test]
+fn test_separated_long() {
+    // longer than 64 bytes
+    let s = "0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_1_2_3";
+    assert_eq!(
+        parse_separated::<u32, b'_', true, true, false>(s.as_bytes()),
+        Ok(123)
+    );
+    assert_eq!(
+        parse_prefix_separated::<u32, b'_', true, true, false>(s.as_bytes()),
+        Ok((123, s.len()))
+    );
+    // without SKIP_ZEROES the zeroes are digits, up to 64 of them
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(s.as_bytes()),
+        Ok(123)
+    );
+    assert_eq!(
+        parse_separated::<i128, b'_', true, false, false>(format!("-{}x", s).as_bytes()),
+        Err(AtoiSimdError::Invalid128(
+            123,
+            78,
+            format!("-{}x", s).as_bytes()
+        ))
+    );
+    let s = format!("{}4294967296x", "0_".repeat(40));
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 89, s.as_bytes()))
+    );
+    let s = format!("{}1", "0_".repeat(64));
+    assert_eq!(
+        parse_separated::<u32, b'_', true, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Size(65, s.as_bytes()))
+    );
+
+    let zeroes = "0".repeat(100);
+    assert_eq!(
+        parse_separated::<u64, b'_', true, true, false>(zeroes.as_bytes()),
+        Ok(0)
+    );
+
+    let s = format!("{}__1_x", "1_".repeat(35));
+    let ones = "1".repeat(36).parse::<u128>().unwrap();
+    assert_eq!(
+        parse_prefix_separated::<u128, b'_', true, false, false>(s.as_bytes()),
+        Ok((ones / 10, 69))
+    );
+    assert_eq!(
+        parse_prefix_separated::<u128, b'_', false, false, false>(s.as_bytes()),
+        Ok((ones, 74))
+    );
+    assert_eq!(
+        parse_separated::<u128, b'_', false, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Invalid128(ones, 74, s.as_bytes()))
+    );
+    let s = "1_".repeat(40);
+    assert_eq!(
+        parse_prefix_separated::<u128, b'_', true, true, false>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 78, s.as_bytes()))
+    );
+
+    // a separator at the end of the first 64 bytes
+    let s = format!("{}_1", "1".repeat(63));
+    assert_eq!(
+        parse_separated::<u128, b'_', true, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 39, s.as_bytes()))
+    );
+    let s = format!("{}_1", "0".repeat(63));
+    assert_eq!(
+        parse_separated::<u8, b'_', true, true, false>(s.as_bytes()),
+        Ok(1)
+    );
+}
+
+#[test]
+#[should_panic(expected = "SEPARATOR must be")]
+fn test_separated_digit_separator() {
+    let _ = parse_separated::<u32, b'1', true, false, false>(b"1");
+}
+
+#[test]
+fn test_grouped_western() {
+    for (s, val) in [
+        ("1,234,567", 1_234_567),
+        ("1 234 567", 1_234_567),
+        ("999", 999),
+        ("1234567", 1_234_567),
+        ("12,345", 12_345),
+        ("123,456", 123_456),
+    ] {
+        let s = s.replace(' ', ",");
+        assert_eq!(
+            parse_grouped::<u32, b',', false, false>(s.as_bytes(), Grouping::Western),
+            Ok(val),
+            "input: {}",
+            s
+        );
+    }
+    assert_eq!(
+        parse_grouped::<u32, b'\'', false, false>(b"1'234'567", Grouping::Western),
+        Ok(1_234_567)
+    );
+
+    for (s, val, index) in [
+        ("1234,567", 123, 3),
+        ("1,2345,678", 1234, 5),
+        ("1,23,456", 123, 4),
+        ("1,234,56", 1234, 5),
+        ("1,234,5678", 1_234_567, 9),
+        ("1,,234", 1, 1),
+        ("1,234,", 1234, 5),
+    ] {
+        assert_eq!(
+            parse_grouped::<u32, b',', false, false>(s.as_bytes(), Grouping::Western),
+            Err(AtoiSimdError::Invalid64(val, index, s.as_bytes())),
+            "input: {}",
+            s
+        );
+    }
+    // the variant of the 64-bit decimal parser depends on the kernel
+    assert!(matches!(
+        parse_grouped::<i64, b'.', false, false>(b"-1.23.456", Grouping::Western),
+        Err(AtoiSimdError::Invalid64(123, 5, b"-1.23.456"))
+            | Err(AtoiSimdError::Invalid128(123, 5, b"-1.23.456"))
+    ));
+    assert_eq!(
+        parse_grouped::<u16, b',', false, false>(b"65,536", Grouping::Western),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 5, b"65,536"))
+    );
+    assert_eq!(
+        parse_grouped::<u16, b',', false, false>(b",1", Grouping::Western),
+        Err(AtoiSimdError::Invalid64(0, 0, b",1"))
+    );
+    assert_eq!(
+        parse_grouped::<i64, b',', false, false>(b"-,123", Grouping::Indian),
+        Err(AtoiSimdError::Invalid64(0, 1, b"-,123"))
+    );
+}
+
+#[test]
+fn test_grouped_indian() {
+    for (s, val) in [
+        ("12,34,567", 1_234_567),
+        ("1,00,000", 100_000),
+        ("1,000", 1000),
+        ("10,000", 10_000),
+        ("1,23,45,67,890", 1_234_567_890),
+    ] {
+        assert_eq!(
+            parse_grouped::<u64, b',', false, false>(s.as_bytes(), Grouping::Indian),
+            Ok(val),
+            "input: {}",
+            s
+        );
+    }
+
+    for (s, val, index) in [
+        ("1,234,567", 1234, 5),
+        ("123,456", 12, 2),
+        ("10,00", 10, 2),
+        ("1,23,4567", 123_456, 8),
+        ("1,2,345", 12, 3),
+    ] {
+        assert_eq!(
+            parse_grouped::<u32, b',', false, false>(s.as_bytes(), Grouping::Indian),
+            Err(AtoiSimdError::Invalid64(val, index, s.as_bytes())),
+            "input: {}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_grouped_lenient() {
+    assert_eq!(
+        parse_grouped::<u32, b',', false, false>(b"1,2345,67", Grouping::Lenient),
+        Ok(1_234_567)
+    );
+    assert_eq!(
+        parse_grouped::<u32, b',', false, false>(b"1,,2", Grouping::Lenient),
+        Err(AtoiSimdError::Invalid64(1, 1, b"1,,2"))
+    );
+}
+
+#[test]
+fn test_grouped_prefix() {
+    assert_eq!(
+        parse_prefix_grouped::<u32, b',', false, false>(b"1,234,567 next", Grouping::Western),
+        Ok((1_234_567, 9))
+    );
+    assert_eq!(
+        parse_prefix_grouped::<i32, b',', false, false>(b"-1,234, 5", Grouping::Western),
+        Ok((-1234, 6))
+    );
+    assert_eq!(
+        parse_prefix_grouped::<u32, b',', false, true>(b"+12,34,567,", Grouping::Indian),
+        Ok((1_234_567, 10))
+    );
+    assert_eq!(
+        parse_prefix_grouped::<u32, b',', false, false>(b"1,23 x", Grouping::Western),
+        Err(AtoiSimdError::Invalid64(1, 1, b"1,23 x"))
+    );
+}
+
+#[test]
+fn test_grouped_long() {
+    // longer than 64 bytes
+    let s = format!("{}001", "000,".repeat(20));
+    assert_eq!(
+        parse_grouped::<u8, b',', true, false>(s.as_bytes(), Grouping::Western),
+        Ok(1)
+    );
+    let s = format!("{}0001", "000,".repeat(20));
+    assert_eq!(
+        parse_grouped::<u8, b',', true, false>(s.as_bytes(), Grouping::Western),
+        Err(AtoiSimdError::Invalid64(0, 83, s.as_bytes()))
+    );
+    let s = format!("{}01", "000,".repeat(20));
+    assert_eq!(
+        parse_grouped::<u8, b',', true, false>(s.as_bytes(), Grouping::Western),
+        Err(AtoiSimdError::Invalid64(0, 79, s.as_bytes()))
+    );
+}
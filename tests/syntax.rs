@@ -0,0 +1,388 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{parse_prefix_syntax, parse_syntax, AtoiSimdError, CanonicalRule, Sign, Syntax};
+
+/// Checks the inputs, `None` is for the invalid literals
+fn check(syntax: Syntax, data: &[(&str, Option<i64>)]) {
+    for &(s, expected) in data {
+        let res = parse_syntax::<i64>(s.as_bytes(), syntax);
+        match expected {
+            Some(v) => {
+                assert_eq!(res, Ok(v), "syntax: {:?} input: {}", syntax, s);
+                assert_eq!(
+                    parse_prefix_syntax::<i64>(s.as_bytes(), syntax),
+                    Ok((v, s.len())),
+                    "syntax: {:?} input: {}",
+                    syntax,
+                    s
+                );
+                // with anything after it
+                let other = format!("{} ", s);
+                assert_eq!(
+                    parse_prefix_syntax::<i64>(other.as_bytes(), syntax),
+                    Ok((v, s.len())),
+                    "syntax: {:?} input: {}",
+                    syntax,
+                    other
+                );
+            }
+            None => assert!(
+                res.is_err(),
+                "syntax: {:?} input: {} res: {:?}",
+                syntax,
+                s,
+                res
+            ),
+        }
+    }
+}
+
+/// Checks the parsed prefixes of the inputs
+fn check_prefix(syntax: Syntax, data: &[(&str, i64, usize)]) {
+    for &(s, v, len) in data {
+        assert_eq!(
+            parse_prefix_syntax::<i64>(s.as_bytes(), syntax),
+            Ok((v, len)),
+            "syntax: {:?} input: {}",
+            syntax,
+            s
+        );
+        assert!(
+            parse_syntax::<i64>(s.as_bytes(), syntax).is_err(),
+            "syntax: {:?} input: {}",
+            syntax,
+            s
+        );
+    }
+}
+
+#[test]
+fn test_syntax_rust() {
+    check(
+        Syntax::Rust,
+        &[
+            ("0", Some(0)),
+            ("42", Some(42)),
+            ("-42", Some(-42)),
+            ("007", Some(7)),
+            ("1_000_000", Some(1_000_000)),
+            ("1__0_", Some(10)),
+            ("0xFF", Some(0xFF)),
+            ("0xdead_BEEF", Some(0xDEAD_BEEF)),
+            ("0x_ff", Some(0xFF)),
+            ("-0x80", Some(-0x80)),
+            ("0o755", Some(0o755)),
+            ("0b1010_1010", Some(0b1010_1010)),
+            ("10u8", Some(10)),
+            ("1_000_i64", Some(1000)),
+            ("0xFFusize", Some(0xFF)),
+            ("-5i128", Some(-5)),
+            ("7u16", Some(7)),
+            ("7i32", Some(7)),
+            ("7u64", Some(7)),
+            ("7isize", Some(7)),
+            // the value must fit in the type of the suffix, like in rustc
+            ("255u8", Some(255)),
+            ("-128i8", Some(-128)),
+            ("0xFF_u8", Some(0xFF)),
+            ("256u8", None),
+            ("-129i8", None),
+            ("0x80i8", None),
+            ("0x1_00_u8", None),
+            ("-1u64", None),
+            ("-0u8", None),
+            ("", None),
+            ("+1", None),
+            ("_1", None),
+            ("0X1", None),
+            ("0O7", None),
+            ("0B1", None),
+            ("0x", None),
+            ("0x_", None),
+            ("0o8", None),
+            ("0b2", None),
+            ("1u7", None),
+            ("1f32", None),
+            ("1e3", None),
+            ("1'000", None),
+        ],
+    );
+    check_prefix(
+        Syntax::Rust,
+        &[
+            ("0x", 0, 1),
+            ("0b2", 0, 1),
+            ("1u7", 1, 1),
+            ("10u8x", 10, 4),
+            ("1_0 _0", 10, 3),
+        ],
+    );
+}
+
+#[test]
+fn test_syntax_c() {
+    check(
+        Syntax::C,
+        &[
+            ("0", Some(0)),
+            ("42", Some(42)),
+            ("-42", Some(-42)),
+            ("0755", Some(0o755)),
+            ("-010", Some(-8)),
+            ("00", Some(0)),
+            ("0x1F", Some(0x1F)),
+            ("0X1f", Some(0x1F)),
+            ("0b101", Some(0b101)),
+            ("0B101", Some(0b101)),
+            ("1'000'000", Some(1_000_000)),
+            ("0x'1", None),
+            ("0'7", Some(7)),
+            ("0xFF'FF", Some(0xFFFF)),
+            ("10u", Some(10)),
+            ("10U", Some(10)),
+            ("10l", Some(10)),
+            ("10LL", Some(10)),
+            ("10ul", Some(10)),
+            ("10LU", Some(10)),
+            ("10ull", Some(10)),
+            ("10llu", Some(10)),
+            ("0x10ULL", Some(16)),
+            ("0u", Some(0)),
+            ("", None),
+            ("+1", None),
+            ("08", None),
+            ("09", None),
+            ("0o7", None),
+            ("1_000", None),
+            ("1''0", None),
+            ("1'", None),
+            ("10lL", None),
+            ("10uu", None),
+            ("10lul", None),
+            ("0x", None),
+        ],
+    );
+    check_prefix(
+        Syntax::C,
+        &[
+            ("08", 0, 1),
+            ("0x", 0, 1),
+            ("0xg", 0, 1),
+            ("1'", 1, 1),
+            ("10lL", 10, 3),
+        ],
+    );
+}
+
+#[test]
+fn test_syntax_python() {
+    check(
+        Syntax::Python,
+        &[
+            ("0", Some(0)),
+            ("00", Some(0)),
+            ("0_0", Some(0)),
+            ("42", Some(42)),
+            ("+42", Some(42)),
+            ("-42", Some(-42)),
+            ("1_000_000", Some(1_000_000)),
+            ("0x_FF", Some(0xFF)),
+            ("0XfF", Some(0xFF)),
+            ("-0x10", Some(-16)),
+            ("0o7_7", Some(0o77)),
+            ("0O77", Some(0o77)),
+            ("0b_1", Some(1)),
+            ("0B1", Some(1)),
+            ("", None),
+            ("01", None),
+            ("007", None),
+            ("1__0", None),
+            ("1_", None),
+            ("_1", None),
+            ("0x__1", None),
+            ("0x", None),
+            ("0o8", None),
+            ("10u8", None),
+            ("10L", None),
+        ],
+    );
+    check_prefix(
+        Syntax::Python,
+        &[("1__0", 1, 1), ("1_", 1, 1), ("0x", 0, 1), ("0x__1", 0, 1)],
+    );
+    assert_eq!(
+        parse_syntax::<i64>(b"0001", Syntax::Python),
+        Err(AtoiSimdError::NonCanonical(
+            CanonicalRule::LeadingZero,
+            b"0001"
+        ))
+    );
+}
+
+#[test]
+fn test_syntax_toml() {
+    check(
+        Syntax::Toml,
+        &[
+            ("0", Some(0)),
+            ("+0", Some(0)),
+            ("-0", Some(0)),
+            ("+99", Some(99)),
+            ("-17", Some(-17)),
+            ("1_000", Some(1000)),
+            ("5_349_221", Some(5_349_221)),
+            ("0xDEADBEEF", Some(0xDEAD_BEEF)),
+            ("0xdead_beef", Some(0xDEAD_BEEF)),
+            ("0o01234567", Some(0o1234567)),
+            ("0o755", Some(0o755)),
+            ("0b11010110", Some(0b1101_0110)),
+            ("0x00ff", Some(0xFF)),
+            ("", None),
+            ("01", None),
+            ("00", None),
+            ("0_0", None),
+            ("1__000", None),
+            ("1_", None),
+            ("0x_ff", None),
+            ("0XFF", None),
+            ("0O7", None),
+            ("0B1", None),
+            ("+0x1", None),
+            ("-0o7", None),
+            ("10u8", None),
+        ],
+    );
+    check_prefix(
+        Syntax::Toml,
+        &[("+0x1", 0, 2), ("1_", 1, 1), ("0x_ff", 0, 1)],
+    );
+    assert_eq!(
+        parse_prefix_syntax::<i64>(b"01,", Syntax::Toml),
+        Err(AtoiSimdError::NonCanonical(
+            CanonicalRule::LeadingZero,
+            b"01,"
+        ))
+    );
+}
+
+#[test]
+fn test_syntax_yaml() {
+    check(
+        Syntax::Yaml,
+        &[
+            ("0", Some(0)),
+            ("12345", Some(12345)),
+            ("+12345", Some(12345)),
+            ("-12345", Some(-12345)),
+            ("007", Some(7)),
+            ("0o14", Some(12)),
+            ("0xC", Some(12)),
+            ("0xc", Some(12)),
+            ("", None),
+            ("0b1", None),
+            ("0O14", None),
+            ("0XC", None),
+            ("+0xC", None),
+            ("1_000", None),
+            ("1,000", None),
+        ],
+    );
+    check_prefix(
+        Syntax::Yaml,
+        &[("0b1", 0, 1), ("1_000", 1, 1), ("-0xC", 0, 2)],
+    );
+}
+
+#[test]
+fn test_syntax_errors() {
+    // the invalid char is reported with the value before it
+    assert_eq!(
+        parse_syntax::<u32>(b"1_000x", Syntax::Rust),
+        Err(AtoiSimdError::Invalid64(1000, 5, b"1_000x"))
+    );
+    assert_eq!(
+        parse_syntax::<u32>(b"10u8x", Syntax::Rust),
+        Err(AtoiSimdError::Invalid64(10, 4, b"10u8x"))
+    );
+    assert_eq!(
+        parse_syntax::<i128>(b"0xff'f", Syntax::Python),
+        Err(AtoiSimdError::Invalid128(0xFF, 4, b"0xff'f"))
+    );
+    assert_eq!(
+        parse_syntax::<u32>(b"", Syntax::C),
+        Err(AtoiSimdError::Empty)
+    );
+
+    // overflows with and without the separators
+    for syntax in [
+        Syntax::Rust,
+        Syntax::C,
+        Syntax::Python,
+        Syntax::Toml,
+        Syntax::Yaml,
+    ] {
+        assert_eq!(
+            parse_syntax::<u8>(b"256", syntax),
+            Err(AtoiSimdError::Overflow(Sign::Pos, 2, b"256"))
+        );
+        assert_eq!(
+            parse_syntax::<i8>(b"-129", syntax),
+            Err(AtoiSimdError::Overflow(Sign::Neg, 3, b"-129"))
+        );
+        assert_eq!(parse_syntax::<i8>(b"-128", syntax), Ok(-128));
+        assert_eq!(parse_syntax::<u8>(b"255", syntax), Ok(255));
+    }
+    assert_eq!(
+        parse_syntax::<u8>(b"2_56", Syntax::Rust),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 3, b"2_56"))
+    );
+    assert_eq!(
+        parse_syntax::<u8>(b"0x1_00", Syntax::Toml),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 5, b"0x1_00"))
+    );
+    assert_eq!(parse_syntax::<u8>(b"2'55", Syntax::C), Ok(255));
+
+    // long numbers
+    let s = format!("0b{}", "1_".repeat(128));
+    assert_eq!(
+        parse_syntax::<u128>(s.as_bytes(), Syntax::Rust),
+        Ok(u128::MAX)
+    );
+    let s = format!("0b{}", "1_".repeat(129));
+    assert_eq!(
+        parse_syntax::<u128>(s.as_bytes(), Syntax::Rust),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 258, s.as_bytes()))
+    );
+    let s = format!("0b{}1", "0_".repeat(200));
+    assert_eq!(parse_syntax::<u8>(s.as_bytes(), Syntax::Rust), Ok(1));
+    assert_eq!(
+        parse_syntax::<u64>("0".repeat(100).as_bytes(), Syntax::Yaml),
+        Ok(0)
+    );
+
+    // the suffix limits the range of a wider integer
+    assert_eq!(
+        parse_syntax::<i64>(b"300u8", Syntax::Rust),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 2, b"300u8"))
+    );
+    assert_eq!(
+        parse_prefix_syntax::<i64>(b"-1_29i8,", Syntax::Rust),
+        Err(AtoiSimdError::Overflow(Sign::Neg, 4, b"-1_29i8,"))
+    );
+    assert_eq!(
+        parse_syntax::<u128>(b"0b1_0000_0000u8", Syntax::Rust),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 12, b"0b1_0000_0000u8"))
+    );
+    assert_eq!(
+        parse_syntax::<i32>(b"-1u16", Syntax::Rust),
+        Err(AtoiSimdError::OutOfRange(b"-1u16"))
+    );
+    assert_eq!(parse_syntax::<i64>(b"65535u16", Syntax::Rust), Ok(65535));
+    // the C suffixes are only skipped
+    assert_eq!(parse_syntax::<i64>(b"-300u", Syntax::C), Ok(-300));
+
+    // unsigned integers have no '-' sign
+    assert!(parse_syntax::<u32>(b"-1", Syntax::Rust).is_err());
+}
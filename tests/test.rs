@@ -1,3 +1,5 @@
+#![allow(clippy::inconsistent_digit_grouping, clippy::type_complexity)]
+
 mod reimpl;
 #[allow(unused_imports)]
 use reimpl::*;
@@ -1117,3 +1119,58 @@ fn test_parse_skipped() {
         Ok(0)
     );
 }
+
+#[test]
+fn test_prefix_plus_len() {
+    // every prefix parser counts the skipped '+' sign, like the '-' sign
+    for s in [&b"+12,"[..], b"+000000000000000000000000000000000012,"] {
+        let len = s.len() - 1;
+        assert_eq!(atoi_simd::parse_prefix::<u32, true, true>(s), Ok((12, len)));
+        assert_eq!(atoi_simd::parse_prefix::<i64, true, true>(s), Ok((12, len)));
+        assert_eq!(
+            atoi_simd::parse_prefix::<u128, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_trimmed::<i32, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_radix::<u32, 10, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_hex::<i32, true, true>(s),
+            Ok((0x12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_auto::<u64, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_separated::<i16, b'_', false, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_bounded::<u8, 0, 100, true, true>(s),
+            Ok((12, len))
+        );
+        assert_eq!(
+            atoi_simd::parse_prefix_with::<i8>(
+                &atoi_simd::ParseOptions::new()
+                    .skip_zeroes(true)
+                    .skip_plus(true),
+                s
+            ),
+            Ok((12, len))
+        );
+    }
+    assert_eq!(
+        atoi_simd::parse_prefix_saturating::<u8, true>(b"+1000,"),
+        Ok((255, 5))
+    );
+    assert_eq!(
+        atoi_simd::parse_prefix_decimal::<u32, 1, true>(b"+12,", atoi_simd::Rounding::HalfEven),
+        Ok((120, 3))
+    );
+}
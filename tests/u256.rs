@@ -0,0 +1,303 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use atoi_simd::{
+    parse, parse_neg, parse_pos, parse_prefix, parse_prefix_neg, AtoiSimdError, Sign, I256, U256,
+};
+
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+/// Digit by digit reference, `None` on overflow
+fn reference(digits: &str) -> Option<U256> {
+    let mut limbs = [0_u64; 4];
+    for c in digits.bytes() {
+        let mut carry = (c - b'0') as u128;
+        for limb in limbs.iter_mut() {
+            let v = *limb as u128 * 10 + carry;
+            *limb = v as u64;
+            carry = v >> 64;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(U256 {
+        hi: limbs[2] as u128 | (limbs[3] as u128) << 64,
+        lo: limbs[0] as u128 | (limbs[1] as u128) << 64,
+    })
+}
+
+/// Index of the digit which makes the value out of range (`fits` is false)
+fn overflow_index(digits: &str, fits: impl Fn(U256) -> bool) -> usize {
+    (1..=digits.len())
+        .find(|&k| !reference(&digits[..k]).map_or(false, &fits))
+        .unwrap()
+        - 1
+}
+
+fn fits_pos(v: U256) -> bool {
+    v.hi <= i128::MAX as u128
+}
+
+fn fits_neg(v: U256) -> bool {
+    v <= U256 {
+        hi: i128::MIN.unsigned_abs(),
+        lo: 0,
+    }
+}
+
+fn negate(v: U256) -> I256 {
+    let (lo, borrow) = 0_u128.overflowing_sub(v.lo);
+    I256 {
+        hi: (v.hi as i128).wrapping_neg().wrapping_sub(borrow as i128),
+        lo,
+    }
+}
+
+#[test]
+fn test_u256() {
+    let mut state = 1;
+    // up to the max length without SKIP_ZEROES
+    for len in 1..=78 {
+        for _ in 0..50 {
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (next(&mut state) % 10) as u8) as char)
+                .collect();
+            let expected = reference(&digits).ok_or_else(|| {
+                AtoiSimdError::Overflow(
+                    Sign::Pos,
+                    overflow_index(&digits, |_| true),
+                    digits.as_bytes(),
+                )
+            });
+            assert_eq!(
+                parse::<U256, false, false>(digits.as_bytes()),
+                expected,
+                "input: {}",
+                digits
+            );
+            assert_eq!(
+                parse::<U256, true, true>(digits.as_bytes()),
+                expected,
+                "input: {}",
+                digits
+            );
+            let prefixed = format!("{}x", digits);
+            assert_eq!(
+                parse_prefix::<U256, false, false>(prefixed.as_bytes()),
+                expected
+                    .map(|v| (v, len))
+                    .map_err(|e| AtoiSimdError::Overflow(
+                        Sign::Pos,
+                        e.offset().unwrap(),
+                        prefixed.as_bytes()
+                    )),
+                "input: {}",
+                prefixed
+            );
+            if let Ok(v) = expected {
+                assert_eq!(
+                    parse::<U256, false, false>(prefixed.as_bytes()),
+                    Err(AtoiSimdError::Invalid128(v.lo, len, prefixed.as_bytes())),
+                    "input: {}",
+                    prefixed
+                );
+            }
+            if len <= 38 {
+                let v: u128 = digits.parse().unwrap();
+                assert_eq!(expected, Ok(U256::from(v)));
+            }
+
+            // signed
+            let pos = expected.ok().filter(|&v| fits_pos(v));
+            assert_eq!(
+                parse::<I256, false, false>(digits.as_bytes()),
+                pos.map(|v| I256 {
+                    hi: v.hi as i128,
+                    lo: v.lo
+                })
+                .ok_or_else(|| AtoiSimdError::Overflow(
+                    Sign::Pos,
+                    overflow_index(&digits, fits_pos),
+                    digits.as_bytes()
+                )),
+                "input: {}",
+                digits
+            );
+            let neg = format!("-{}", digits);
+            let neg_expected = expected.ok().filter(|&v| fits_neg(v)).map(negate);
+            // the index is in the input, with the sign
+            let neg_overflow = || {
+                AtoiSimdError::Overflow(
+                    Sign::Neg,
+                    overflow_index(&digits, fits_neg) + 1,
+                    neg.as_bytes(),
+                )
+            };
+            assert_eq!(
+                parse::<I256, false, false>(neg.as_bytes()),
+                neg_expected.ok_or_else(neg_overflow),
+                "input: {}",
+                neg
+            );
+            let abs_overflow = || {
+                AtoiSimdError::Overflow(
+                    Sign::Neg,
+                    overflow_index(&digits, fits_neg),
+                    digits.as_bytes(),
+                )
+            };
+            assert_eq!(
+                parse_prefix_neg::<I256, false>(digits.as_bytes()),
+                neg_expected.map(|v| (v, len)).ok_or_else(abs_overflow),
+                "input: {}",
+                neg
+            );
+        }
+    }
+
+    // the longer numbers have zeroes at the beginning, or they are out of range
+    for len in 79..=80 {
+        for _ in 0..50 {
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (next(&mut state) % 10) as u8) as char)
+                .collect();
+            let expected = reference(&digits).ok_or_else(|| {
+                AtoiSimdError::Overflow(
+                    Sign::Pos,
+                    overflow_index(&digits, |_| true),
+                    digits.as_bytes(),
+                )
+            });
+            assert_eq!(
+                parse::<U256, true, false>(digits.as_bytes()),
+                expected,
+                "input: {}",
+                digits
+            );
+            assert_eq!(
+                parse::<U256, false, false>(digits.as_bytes()),
+                expected.and(Err(AtoiSimdError::Size(len, digits.as_bytes()))),
+                "input: {}",
+                digits
+            );
+            let pos = expected.ok().filter(|&v| fits_pos(v));
+            assert_eq!(
+                parse::<I256, true, false>(digits.as_bytes()),
+                pos.map(|v| I256 {
+                    hi: v.hi as i128,
+                    lo: v.lo
+                })
+                .ok_or_else(|| AtoiSimdError::Overflow(
+                    Sign::Pos,
+                    overflow_index(&digits, fits_pos),
+                    digits.as_bytes()
+                )),
+                "input: {}",
+                digits
+            );
+        }
+    }
+}
+
+#[test]
+fn test_u256_limits() {
+    assert_eq!(
+        parse::<U256, false, false>(U256_MAX.as_bytes()),
+        Ok(U256::MAX)
+    );
+    let over = format!("{}6", &U256_MAX[..U256_MAX.len() - 1]);
+    assert_eq!(
+        parse::<U256, false, false>(over.as_bytes()),
+        Err(AtoiSimdError::Overflow(
+            Sign::Pos,
+            over.len() - 1,
+            over.as_bytes()
+        ))
+    );
+    assert_eq!(
+        parse::<I256, false, false>(I256_MAX.as_bytes()),
+        Ok(I256::MAX)
+    );
+    assert_eq!(
+        parse::<I256, false, false>(I256_MIN.as_bytes()),
+        Ok(I256::MIN)
+    );
+    assert_eq!(
+        parse_neg::<I256, false>(&I256_MIN.as_bytes()[1..]),
+        Ok(I256::MIN)
+    );
+    assert_eq!(
+        parse_pos::<I256, false>(&I256_MIN.as_bytes()[1..]),
+        Err(AtoiSimdError::Overflow(
+            Sign::Pos,
+            I256_MIN.len() - 2,
+            &I256_MIN.as_bytes()[1..]
+        ))
+    );
+    assert_eq!(parse::<I256, false, false>(b"-1"), Ok(I256::from(-1)));
+    assert_eq!(parse::<I256, false, true>(b"+1"), Ok(I256::from(1)));
+
+    // any amount of zeroes with SKIP_ZEROES
+    let zeroes = format!("{}{}", "0".repeat(100), U256_MAX);
+    assert_eq!(parse::<U256, true, false>(zeroes.as_bytes()), Ok(U256::MAX));
+    assert_eq!(
+        parse::<U256, true, false>("0".repeat(100).as_bytes()),
+        Ok(U256::ZERO)
+    );
+    let zeroes = format!("-{}{}", "0".repeat(100), &I256_MIN[1..]);
+    assert_eq!(
+        parse_prefix::<I256, true, false>(zeroes.as_bytes()),
+        Ok((I256::MIN, zeroes.len()))
+    );
+
+    // up to 78 digits without it
+    let zeroes = format!("{}1", "0".repeat(77));
+    assert_eq!(
+        parse::<U256, false, false>(zeroes.as_bytes()),
+        Ok(U256::from(1))
+    );
+    let zeroes = format!("{}1", "0".repeat(78));
+    assert_eq!(
+        parse::<U256, false, false>(zeroes.as_bytes()),
+        Err(AtoiSimdError::Size(79, zeroes.as_bytes()))
+    );
+    let zeroes = format!("-{}", "0".repeat(100));
+    assert_eq!(
+        parse_prefix::<I256, false, false>(zeroes.as_bytes()),
+        Err(AtoiSimdError::Size(97, zeroes.as_bytes()))
+    );
+}
+
+#[test]
+fn test_u256_errors() {
+    assert_eq!(parse::<U256, false, false>(b""), Err(AtoiSimdError::Empty));
+    assert_eq!(parse::<U256, false, false>(b"x"), Err(AtoiSimdError::Empty));
+    assert_eq!(
+        parse::<U256, false, false>(b"-1"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(parse::<I256, false, false>(b"-"), Err(AtoiSimdError::Empty));
+    assert_eq!(
+        parse::<I256, false, false>(b"-12x"),
+        Err(AtoiSimdError::Invalid128(12, 3, b"-12x"))
+    );
+    assert_eq!(
+        parse::<U256, false, false>(b"12 "),
+        Err(AtoiSimdError::Invalid128(12, 2, b"12 "))
+    );
+}
@@ -0,0 +1,178 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{parse_prefix_utf8_digits, parse_utf8_digits, AtoiSimdError, Sign};
+use core::fmt::Write;
+
+/// Zeroes of some of the digit blocks: ASCII, Arabic-Indic, Extended Arabic-Indic, Devanagari,
+/// Thai, full-width, Mathematical Monospace, Adlam
+const ZEROES: &[char] = &[
+    '0',
+    '\u{660}',
+    '\u{6F0}',
+    '\u{966}',
+    '\u{E50}',
+    '\u{FF10}',
+    '\u{1D7F6}',
+    '\u{1E950}',
+];
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// Replaces the ASCII digits with the digits of the `zero` block
+fn transliterate(s: &str, zero: char) -> ArrayString<200> {
+    let mut res = ArrayString::new();
+    for c in s.chars() {
+        res.push(match c.to_digit(10) {
+            Some(d) => char::from_u32(zero as u32 + d).unwrap(),
+            None => c,
+        });
+    }
+    res
+}
+
+macro_rules! test_utf8_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            for _ in 0..200 {
+                let val = next(&mut state) as $t;
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", val).unwrap();
+                for &zero in ZEROES {
+                    let s = transliterate(&s, zero);
+                    assert_eq!(parse_utf8_digits::<$t, false, false>(s.as_bytes()), Ok(val), "input: {}", s);
+                    assert_eq!(
+                        parse_prefix_utf8_digits::<$t, false, false>(s.as_bytes()),
+                        Ok((val, s.len())),
+                        "input: {}",
+                        s
+                    );
+
+                    let mut suffixed = s;
+                    suffixed.push('x');
+                    assert_eq!(
+                        parse_prefix_utf8_digits::<$t, false, false>(suffixed.as_bytes()),
+                        Ok((val, s.len())),
+                        "input: {}",
+                        suffixed
+                    );
+                    match parse_utf8_digits::<$t, false, false>(suffixed.as_bytes()) {
+                        Err(AtoiSimdError::Invalid64(_, i, _) | AtoiSimdError::Invalid128(_, i, _)) => {
+                            assert_eq!(i, s.len(), "input: {}", suffixed)
+                        }
+                        e => panic!("input: {}, result: {:?}", suffixed, e),
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_utf8_types!(
+    test_utf8_u8: u8,
+    test_utf8_i8: i8,
+    test_utf8_u16: u16,
+    test_utf8_i16: i16,
+    test_utf8_u32: u32,
+    test_utf8_i32: i32,
+    test_utf8_usize: usize,
+    test_utf8_isize: isize,
+    test_utf8_u64: u64,
+    test_utf8_i64: i64,
+    test_utf8_u128: u128,
+    test_utf8_i128: i128,
+);
+
+#[test]
+fn test_utf8_errors() {
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>(b""),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_utf8_digits::<i32, false, false>(b"-"),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>("-١".as_bytes()),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>("é1".as_bytes()),
+        Err(AtoiSimdError::Empty)
+    );
+    assert_eq!(
+        parse_utf8_digits::<u32, false, true>("+١٢٣".as_bytes()),
+        Ok(123)
+    );
+
+    // indices are in bytes
+    let s = "١٢٣x";
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Invalid64(123, 6, s.as_bytes()))
+    );
+    let s = "-１２3";
+    // the variant of the 64-bit decimal parser depends on the kernel
+    assert!(matches!(
+        parse_utf8_digits::<i64, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Invalid64(12, 7, _)) | Err(AtoiSimdError::Invalid128(12, 7, _))
+    ));
+
+    // no mixed scripts
+    let s = "1١";
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Invalid64(1, 1, s.as_bytes()))
+    );
+    assert_eq!(
+        parse_prefix_utf8_digits::<u32, false, false>("١۲".as_bytes()),
+        Ok((1, 2))
+    );
+
+    // invalid UTF-8, an overlong '٠' and a truncated char
+    assert_eq!(
+        parse_prefix_utf8_digits::<u32, false, false>(b"\xD9\xA1\xE0\x99\xA0"),
+        Ok((1, 2))
+    );
+    assert_eq!(
+        parse_prefix_utf8_digits::<u32, false, false>(b"\xD9\xA1\xD9"),
+        Ok((1, 2))
+    );
+    assert_eq!(
+        parse_utf8_digits::<u32, false, false>(b"\xE0\x99\xA0"),
+        Err(AtoiSimdError::Empty)
+    );
+
+    let s = "٢٥٦";
+    assert_eq!(
+        parse_utf8_digits::<u8, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Overflow(Sign::Pos, 4, s.as_bytes()))
+    );
+}
+
+#[test]
+fn test_utf8_long() {
+    let s = format!("{}١", "٠".repeat(100));
+    assert_eq!(parse_utf8_digits::<u8, true, false>(s.as_bytes()), Ok(1));
+    assert_eq!(
+        parse_prefix_utf8_digits::<u8, true, false>(s.as_bytes()),
+        Ok((1, s.len()))
+    );
+    assert_eq!(
+        parse_utf8_digits::<u8, false, false>(s.as_bytes()),
+        Err(AtoiSimdError::Size(101, s.as_bytes()))
+    );
+    let s = "٠".repeat(100);
+    assert_eq!(parse_utf8_digits::<u64, true, false>(s.as_bytes()), Ok(0));
+}
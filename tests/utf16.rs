@@ -0,0 +1,551 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::{ArrayString, ArrayVec};
+use atoi_simd::{
+    parse, parse_auto, parse_auto_utf16, parse_bounded, parse_bounded_utf16, parse_hex,
+    parse_hex_utf16, parse_neg, parse_neg_utf16, parse_pos, parse_pos_utf16, parse_prefix,
+    parse_prefix_auto, parse_prefix_auto_utf16, parse_prefix_bounded, parse_prefix_bounded_utf16,
+    parse_prefix_hex, parse_prefix_hex_utf16, parse_prefix_neg_utf16, parse_prefix_pos_utf16,
+    parse_prefix_radix, parse_prefix_radix_utf16, parse_prefix_separated,
+    parse_prefix_separated_utf16, parse_prefix_trimmed, parse_prefix_trimmed_utf16,
+    parse_prefix_utf16, parse_radix, parse_radix_utf16, parse_separated, parse_separated_utf16,
+    parse_trimmed, parse_trimmed_utf16, parse_utf16, AtoiSimdError, ErrorKind, OwnedError,
+};
+use core::fmt::Write;
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+fn utf16(s: &str) -> ArrayVec<u16, 400> {
+    s.encode_utf16().collect()
+}
+
+/// The kind and the offset of the error
+fn kind<T>(r: Result<T, AtoiSimdError<'_>>) -> Result<T, (ErrorKind, Option<usize>)> {
+    r.map_err(|e| (e.kind(), e.offset()))
+}
+
+/// The error of a UTF-16 parser with the snippet of the units
+fn owned<T>(r: Result<T, AtoiSimdError<'_>>, units: &[u16]) -> Result<T, OwnedError> {
+    r.map_err(|e| OwnedError::from_utf16(e, units))
+}
+
+macro_rules! test_utf16_types {
+    ($($name:ident: $t:ty,)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            for _ in 0..300 {
+                let val = next(&mut state) as $t;
+                let mut s = ArrayString::<64>::new();
+                write!(s, "{}", val).unwrap();
+                let units = utf16(&s);
+                assert_eq!(parse_utf16::<$t, false, false>(&units), Ok(val), "input: {}", s);
+                assert_eq!(parse_prefix_utf16::<$t, false, false>(&units), Ok((val, s.len())), "input: {}", s);
+
+                // the same errors as the byte parser
+                for i in 0..=s.len() {
+                    for c in ['x', '\0', '+', 'Ａ', 'ı', '\u{8030}'] {
+                        let mut invalid = ArrayString::<64>::new();
+                        invalid.push_str(&s[..i]);
+                        invalid.push(c);
+                        invalid.push_str(&s[i..]);
+                        let units = utf16(&invalid);
+                        // the same index in units for ASCII, and the byte parser stops at the char anyway
+                        let bytes = invalid.as_bytes();
+                        let expected = kind(parse::<$t, false, true>(bytes));
+                        assert_eq!(kind(parse_utf16::<$t, false, true>(&units)), expected, "input: {}", invalid);
+                        let expected = kind(parse_prefix::<$t, false, true>(bytes));
+                        assert_eq!(kind(parse_prefix_utf16::<$t, false, true>(&units)), expected, "input: {}", invalid);
+                        // the same snippet for ASCII
+                        if c.is_ascii() {
+                            let expected = parse::<$t, false, true>(bytes).map_err(AtoiSimdError::into_owned);
+                            assert_eq!(owned(parse_utf16::<$t, false, true>(&units), &units), expected, "input: {}", invalid);
+                        }
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+test_utf16_types!(
+    test_utf16_u8: u8,
+    test_utf16_i8: i8,
+    test_utf16_u16: u16,
+    test_utf16_i16: i16,
+    test_utf16_u32: u32,
+    test_utf16_i32: i32,
+    test_utf16_usize: usize,
+    test_utf16_isize: isize,
+    test_utf16_u64: u64,
+    test_utf16_i64: i64,
+    test_utf16_u128: u128,
+    test_utf16_i128: i128,
+);
+
+#[test]
+fn test_utf16_pos_neg() {
+    assert_eq!(parse_pos_utf16::<u64, false>(&utf16("1234")), Ok(1234));
+    assert_eq!(parse_neg_utf16::<i64, false>(&utf16("1234")), Ok(-1234));
+    assert_eq!(
+        parse_prefix_pos_utf16::<u16, false>(&utf16("1234 ")),
+        Ok((1234, 4))
+    );
+    assert_eq!(
+        parse_prefix_neg_utf16::<i16, false>(&utf16("1234 ")),
+        Ok((-1234, 4))
+    );
+    assert_eq!(
+        owned(parse_pos_utf16::<u32, false>(&utf16("-1")), &utf16("-1")),
+        parse_pos::<u32, false>(b"-1").map_err(AtoiSimdError::into_owned)
+    );
+    assert_eq!(
+        owned(parse_neg_utf16::<i32, false>(&utf16("12x")), &utf16("12x")),
+        parse_neg::<i32, false>(b"12x").map_err(AtoiSimdError::into_owned)
+    );
+}
+
+#[test]
+fn test_utf16_errors() {
+    assert_eq!(
+        kind(parse_utf16::<u32, false, false>(&[])),
+        Err((ErrorKind::Empty, None))
+    );
+    assert_eq!(
+        kind(parse_utf16::<i32, false, false>(&utf16("-"))),
+        Err((ErrorKind::Empty, None))
+    );
+    // the units above 0xFF are never digits
+    let units = [b'1' as u16, 0x0132, 0x0130 + 3];
+    let e = owned(parse_utf16::<u32, false, false>(&units), &units).unwrap_err();
+    assert_eq!((e.kind(), e.offset()), (ErrorKind::InvalidDigit, Some(1)));
+    // and they are 0xFF in the snippet
+    assert_eq!(e.snippet(), &[b'1', 0xFF, 0xFF]);
+    assert_eq!(e.input_len(), 3);
+    assert_eq!(
+        parse_prefix_utf16::<u32, false, false>(&[b'1' as u16, 0xFF00 | b'2' as u16]),
+        Ok((1, 1))
+    );
+    assert_eq!(
+        kind(parse_utf16::<u8, false, false>(&utf16("256"))),
+        Err((ErrorKind::PosOverflow, Some(2)))
+    );
+
+    // the snippet is centered on the offset in units
+    let s = format!("{}x{}", "1".repeat(5), "é".repeat(60));
+    let units = utf16(&s);
+    let e = owned(parse_utf16::<u32, false, false>(&units), &units).unwrap_err();
+    assert_eq!(
+        (e.offset(), e.snippet_start(), e.input_len()),
+        (Some(5), 0, 66)
+    );
+    assert_eq!(&e.snippet()[..7], b"11111x\xE9");
+}
+
+#[test]
+fn test_utf16_long() {
+    let s = format!("-{}123", "0".repeat(100));
+    let units = utf16(&s);
+    assert_eq!(parse_utf16::<i8, true, false>(&units), Ok(-123));
+    assert_eq!(
+        parse_prefix_utf16::<i8, true, false>(&units),
+        Ok((-123, s.len()))
+    );
+    // without SKIP_ZEROES the byte parser rejects it before the end of the buffer, or it's too long
+    assert!(parse_utf16::<i8, false, false>(&units).is_err());
+
+    // like the byte parser, it counts the '+' sign
+    let s = format!("+{}x", "0".repeat(100));
+    let units = utf16(&s);
+    assert_eq!(
+        parse_prefix_utf16::<u64, true, true>(&units),
+        Ok((0, s.len() - 1))
+    );
+    assert_eq!(
+        kind(parse_utf16::<u64, true, true>(&units)),
+        kind(parse::<u64, true, true>(s.as_bytes()))
+    );
+
+    let s = format!("{}12x", "0".repeat(70));
+    let units = utf16(&s);
+    assert_eq!(
+        kind(parse_utf16::<u32, true, false>(&units)),
+        Err((ErrorKind::InvalidDigit, Some(72)))
+    );
+
+    let s = format!("{}{}", "0".repeat(10), "1".repeat(70));
+    let units = utf16(&s);
+    assert_eq!(
+        kind(parse_utf16::<u128, true, false>(&units)),
+        kind(parse::<u128, true, false>(s.as_bytes()))
+    );
+    assert_eq!(
+        kind(parse_prefix_utf16::<u128, false, false>(&units)),
+        kind(parse_prefix::<u128, false, false>(s.as_bytes()))
+    );
+
+    // a number that fits, followed by a long input
+    let s = format!("12 {}", "3".repeat(100));
+    let units = utf16(&s);
+    assert_eq!(parse_prefix_utf16::<u32, false, false>(&units), Ok((12, 2)));
+    assert_eq!(
+        kind(parse_utf16::<u32, false, false>(&units)),
+        Err((ErrorKind::InvalidDigit, Some(2)))
+    );
+}
+
+/// The same result as the byte parser for ASCII input, and the same kind and offset for the other chars
+macro_rules! check_same {
+    ($s:expr, $utf16:expr, $bytes:expr) => {{
+        let s: &str = $s;
+        let units = utf16(s);
+        let bytes = s.as_bytes();
+        if s.is_ascii() {
+            assert_eq!(
+                owned($utf16(&units), &units),
+                $bytes(bytes).map_err(AtoiSimdError::into_owned),
+                "input: {}",
+                s
+            );
+        } else {
+            assert_eq!(kind($utf16(&units)), kind($bytes(bytes)), "input: {}", s);
+        }
+    }};
+}
+
+#[test]
+fn test_utf16_radix() {
+    let inputs = [
+        "1F",
+        "-1f",
+        "+ff",
+        "7fffffffffffffff",
+        "-8000000000000000",
+        "8000000000000000",
+        "g",
+        "1g",
+        "",
+        "-",
+        "1 ",
+        "0000000000000000000000000000000000000000000000000000000000000000000000001",
+    ];
+    for s in inputs {
+        check_same!(
+            s,
+            parse_hex_utf16::<i64, false, true>,
+            parse_hex::<i64, false, true>
+        );
+        check_same!(
+            s,
+            parse_hex_utf16::<i64, true, false>,
+            parse_hex::<i64, true, false>
+        );
+        check_same!(
+            s,
+            parse_prefix_hex_utf16::<i64, false, true>,
+            parse_prefix_hex::<i64, false, true>
+        );
+        check_same!(
+            s,
+            parse_hex_utf16::<u32, false, false>,
+            parse_hex::<u32, false, false>
+        );
+        check_same!(
+            s,
+            parse_radix_utf16::<i32, 36, false, true>,
+            parse_radix::<i32, 36, false, true>
+        );
+        check_same!(
+            s,
+            parse_prefix_radix_utf16::<u64, 17, true, false>,
+            parse_prefix_radix::<u64, 17, true, false>
+        );
+    }
+
+    // 128 binary digits fit in the buffer
+    let s = format!("-1{}", "0".repeat(127));
+    check_same!(
+        &s,
+        parse_radix_utf16::<i128, 2, false, false>,
+        parse_radix::<i128, 2, false, false>
+    );
+    assert_eq!(
+        parse_radix_utf16::<i128, 2, false, false>(&utf16(&s)),
+        Ok(i128::MIN)
+    );
+    let s = format!("{}{}", "0".repeat(200), "1".repeat(128));
+    assert_eq!(
+        parse_radix_utf16::<u128, 2, true, false>(&utf16(&s)),
+        Ok(u128::MAX)
+    );
+    let s = "1".repeat(200);
+    assert_eq!(
+        kind(parse_radix_utf16::<u128, 2, true, false>(&utf16(&s))),
+        Err((ErrorKind::PosOverflow, Some(128)))
+    );
+    assert_eq!(
+        kind(parse_hex_utf16::<u64, false, false>(&utf16(&s))),
+        Err((ErrorKind::TooLong, Some(200)))
+    );
+    assert_eq!(
+        kind(parse_hex_utf16::<u64, false, false>(&utf16("1Ｆ"))),
+        Err((ErrorKind::InvalidDigit, Some(1)))
+    );
+}
+
+#[test]
+fn test_utf16_auto() {
+    let inputs = [
+        "0x1F", "-0X1f", "+0o17", "0b101", "0b", "0x", "0y", "017", "-0", "0x-1", "0xg", "1a", "",
+    ];
+    for s in inputs {
+        check_same!(
+            s,
+            parse_auto_utf16::<i32, false, true>,
+            parse_auto::<i32, false, true>
+        );
+        check_same!(
+            s,
+            parse_auto_utf16::<u64, true, false>,
+            parse_auto::<u64, true, false>
+        );
+        check_same!(
+            s,
+            parse_prefix_auto_utf16::<i64, false, true>,
+            parse_prefix_auto::<i64, false, true>
+        );
+    }
+
+    // the extra zeroes are after the prefix
+    let s = format!("-0x{}ff", "0".repeat(300));
+    assert_eq!(parse_auto_utf16::<i16, true, false>(&utf16(&s)), Ok(-255));
+    assert_eq!(
+        parse_prefix_auto_utf16::<i16, true, false>(&utf16(&s)),
+        Ok((-255, s.len()))
+    );
+    let s = format!("0b{}", "1".repeat(200));
+    assert_eq!(
+        kind(parse_auto_utf16::<u8, true, false>(&utf16(&s))),
+        Err((ErrorKind::PosOverflow, Some(10)))
+    );
+}
+
+#[test]
+fn test_utf16_separated() {
+    let inputs = [
+        "1_000",
+        "-1_000_000",
+        "+1__0",
+        "_1",
+        "1_",
+        "1_x",
+        "1_000_",
+        "",
+        "-",
+        "12_34_567",
+        "1_2_3_4_5_6_7_8_9_0_1_2_3_4_5_6_7_8_9_0_1_2_3_4_5_6_7_8_9_0_1_2_3_4_5_6_7_8_9",
+    ];
+    for s in inputs {
+        check_same!(
+            s,
+            parse_separated_utf16::<i128, b'_', true, false, true>,
+            parse_separated::<i128, b'_', true, false, true>
+        );
+        check_same!(
+            s,
+            parse_separated_utf16::<u64, b'_', false, true, false>,
+            parse_separated::<u64, b'_', false, true, false>
+        );
+        check_same!(
+            s,
+            parse_prefix_separated_utf16::<i64, b'_', true, false, true>,
+            parse_prefix_separated::<i64, b'_', true, false, true>
+        );
+    }
+
+    let s = format!("{}1_000", "0".repeat(300));
+    assert_eq!(
+        parse_separated_utf16::<u32, b'_', true, true, false>(&utf16(&s)),
+        Ok(1000)
+    );
+    assert_eq!(
+        parse_prefix_separated_utf16::<u32, b'_', true, true, false>(&utf16(&s)),
+        Ok((1000, s.len()))
+    );
+    // too long without the extra zeroes
+    let s = format!("-{}", "1_".repeat(100));
+    assert_eq!(
+        kind(parse_separated_utf16::<i32, b'_', false, true, false>(
+            &utf16(&s)
+        )),
+        Err((ErrorKind::TooLong, Some(201)))
+    );
+}
+
+#[test]
+fn test_utf16_trimmed() {
+    let inputs = [
+        "  12  ",
+        "\t-12\r\n",
+        "12",
+        " ",
+        "",
+        " 1 2 ",
+        "\x0C+7",
+        " Ｆ",
+        "  12x ",
+    ];
+    for s in inputs {
+        check_same!(
+            s,
+            parse_trimmed_utf16::<i32, false, true>,
+            parse_trimmed::<i32, false, true>
+        );
+        check_same!(
+            s,
+            parse_prefix_trimmed_utf16::<i32, false, true>,
+            parse_prefix_trimmed::<i32, false, true>
+        );
+        check_same!(
+            s,
+            parse_trimmed_utf16::<u8, true, false>,
+            parse_trimmed::<u8, true, false>
+        );
+    }
+
+    // the whitespace is skipped before the narrowing, so it's not in the buffer
+    let s = format!("{}{}1 ", " ".repeat(100), "0".repeat(100));
+    assert_eq!(parse_trimmed_utf16::<u8, true, false>(&utf16(&s)), Ok(1));
+    assert_eq!(
+        parse_prefix_trimmed_utf16::<u8, true, false>(&utf16(&s)),
+        Ok((1, s.len() - 1))
+    );
+    let s = format!("{}12x", " ".repeat(100));
+    assert_eq!(
+        kind(parse_trimmed_utf16::<u8, false, false>(&utf16(&s))),
+        Err((ErrorKind::InvalidDigit, Some(102)))
+    );
+}
+
+#[test]
+fn test_utf16_bounded() {
+    let inputs = [
+        "0", "1", "12", "13", "-1", "+5", "5x", "", "255", "256", "00012",
+    ];
+    for s in inputs {
+        check_same!(
+            s,
+            parse_bounded_utf16::<u8, 1, 12, false, true>,
+            parse_bounded::<u8, 1, 12, false, true>
+        );
+        check_same!(
+            s,
+            parse_prefix_bounded_utf16::<i16, -5, 200, false, false>,
+            parse_prefix_bounded::<i16, -5, 200, false, false>
+        );
+    }
+
+    let s = format!("{}12", "0".repeat(100));
+    assert_eq!(
+        parse_bounded_utf16::<u8, 1, 12, true, false>(&utf16(&s)),
+        Ok(12)
+    );
+    let s = "1".repeat(100);
+    check_same!(
+        &s,
+        parse_bounded_utf16::<u8, 1, 12, true, false>,
+        parse_bounded::<u8, 1, 12, true, false>
+    );
+}
+
+#[test]
+fn test_utf16_zeroes() {
+    // the extra zeroes are skipped like in the byte parser, so the errors are the same
+    let s = format!("{}{}", "0".repeat(29), "7".repeat(44));
+    check_same!(&s, parse_utf16::<u8, true, true>, parse::<u8, true, true>);
+    check_same!(&s, parse_utf16::<u32, true, true>, parse::<u32, true, true>);
+
+    let numbers = [
+        "",
+        "1",
+        "255",
+        "256",
+        "4294967296",
+        "18446744073709551616",
+        "340282366920938463463374607431768211456",
+        "12345678901234567890123456789012345678901234",
+        "x",
+    ];
+    for sign in ["", "-", "+"] {
+        for zeroes in [0, 2, 3, 16, 17, 29, 47, 63, 64, 65, 100] {
+            for number in numbers {
+                for end in ["", "x", "11"] {
+                    let s = format!("{}{}{}{}", sign, "0".repeat(zeroes), number, end);
+                    check_same!(&s, parse_utf16::<u8, true, true>, parse::<u8, true, true>);
+                    check_same!(&s, parse_utf16::<u32, true, true>, parse::<u32, true, true>);
+                    check_same!(
+                        &s,
+                        parse_utf16::<i64, true, false>,
+                        parse::<i64, true, false>
+                    );
+                    check_same!(
+                        &s,
+                        parse_utf16::<u128, true, true>,
+                        parse::<u128, true, true>
+                    );
+                    check_same!(
+                        &s,
+                        parse_utf16::<i128, true, true>,
+                        parse::<i128, true, true>
+                    );
+                    check_same!(
+                        &s,
+                        parse_prefix_utf16::<i32, true, true>,
+                        parse_prefix::<i32, true, true>
+                    );
+                    check_same!(
+                        &s,
+                        parse_prefix_utf16::<u128, true, false>,
+                        parse_prefix::<u128, true, false>
+                    );
+                    check_same!(
+                        &s,
+                        parse_auto_utf16::<i64, true, true>,
+                        parse_auto::<i64, true, true>
+                    );
+                    check_same!(
+                        &s,
+                        parse_bounded_utf16::<u32, 0, 100, true, true>,
+                        parse_bounded::<u32, 0, 100, true, true>
+                    );
+                    // the fallback parser of the 32-bit integers reads the longer zeroes without SKIP_ZEROES
+                    if s.len() <= 64 {
+                        check_same!(
+                            &s,
+                            parse_utf16::<u32, false, true>,
+                            parse::<u32, false, true>
+                        );
+                        check_same!(
+                            &s,
+                            parse_prefix_utf16::<u8, false, false>,
+                            parse_prefix::<u8, false, false>
+                        );
+                    }
+                    check_same!(
+                        &s,
+                        parse_utf16::<u64, false, true>,
+                        parse::<u64, false, true>
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+mod reimpl;
+#[allow(unused_imports)]
+use reimpl::*;
+
+use arrayvec::ArrayString;
+use atoi_simd::{parse, parse_prefix, parse_prefix_trimmed, parse_trimmed, AtoiSimdError};
+use core::{
+    fmt::Write,
+    num::{NonZeroU32, Wrapping},
+};
+
+const WHITESPACE: [u8; 5] = [b' ', b'\t', b'\n', b'\x0C', b'\r'];
+
+/// Simple LCG to produce reproducible "random" values
+fn next(state: &mut u128) -> u128 {
+    *state = state
+        .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+        .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+    *state >> (*state as u32 & 127)
+}
+
+/// Random whitespace of up to 40 chars, to cross the chunk boundaries
+fn pad(state: &mut u128) -> Vec<u8> {
+    let len = next(state) as usize % 41;
+    (0..len)
+        .map(|_| WHITESPACE[next(state) as usize % WHITESPACE.len()])
+        .collect()
+}
+
+macro_rules! test_whitespace_types {
+    ($($name:ident: $t:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let mut state = 1;
+            let mut values: Vec<$t> = (0..1000).map(|_| next(&mut state) as $t).collect();
+            values.extend([<$t>::MIN, <$t>::MAX, 0, 1]);
+            for v in values {
+                let mut num = ArrayString::<64>::new();
+                write!(num, "{}", v).unwrap();
+                let front = pad(&mut state);
+                let back = pad(&mut state);
+                let s = [front.as_slice(), num.as_bytes(), back.as_slice()].concat();
+                assert_eq!(parse_trimmed::<$t, false, false>(&s), Ok(v), "input: {:X?}", s);
+                assert_eq!(parse_trimmed::<$t, true, true>(&s), Ok(v), "input: {:X?}", s);
+                assert_eq!(
+                    parse_prefix_trimmed::<$t, false, false>(&s),
+                    Ok((v, front.len() + num.len())),
+                    "input: {:X?}",
+                    s
+                );
+
+                // without whitespace it's the same as `parse`
+                assert_eq!(parse_trimmed::<$t, false, false>(num.as_bytes()), Ok(v));
+                assert_eq!(
+                    parse_prefix_trimmed::<$t, false, false>(num.as_bytes()),
+                    parse_prefix::<$t, false, false>(num.as_bytes())
+                );
+            }
+
+            // errors are moved by the leading whitespace
+            for num in ["", "-", "x", "1x", "1 2", "+1", "1\x0B", "\x0B1"] {
+                for front in ["", " ", "\t\r\n", "                   "] {
+                    let s = format!("{}{}  \r\n", front, num);
+                    let s = s.as_bytes();
+                    let expected = match parse::<$t, false, false>(num.as_bytes()) {
+                        Err(AtoiSimdError::Size(len, _)) => Err(AtoiSimdError::Size(len + front.len(), s)),
+                        Err(AtoiSimdError::Invalid64(v, i, _)) => Err(AtoiSimdError::Invalid64(v, i + front.len(), s)),
+                        Err(AtoiSimdError::Invalid128(v, i, _)) => Err(AtoiSimdError::Invalid128(v, i + front.len(), s)),
+                        res => res,
+                    };
+                    assert_eq!(parse_trimmed::<$t, false, false>(s), expected, "input: {:X?}", s);
+                }
+            }
+        }
+    )*};
+}
+
+test_whitespace_types!(
+    test_whitespace_u8: u8;
+    test_whitespace_u16: u16;
+    test_whitespace_u32: u32;
+    test_whitespace_usize: usize;
+    test_whitespace_u64: u64;
+    test_whitespace_u128: u128;
+    test_whitespace_i8: i8;
+    test_whitespace_i16: i16;
+    test_whitespace_i32: i32;
+    test_whitespace_isize: isize;
+    test_whitespace_i64: i64;
+    test_whitespace_i128: i128;
+);
+
+#[test]
+fn test_whitespace_only() {
+    for len in 0..40 {
+        let s = " ".repeat(len);
+        assert_eq!(
+            parse_trimmed::<u64, false, false>(s.as_bytes()),
+            Err(AtoiSimdError::Empty)
+        );
+        assert_eq!(
+            parse_prefix_trimmed::<i64, false, false>(s.as_bytes()),
+            Err(AtoiSimdError::Empty)
+        );
+    }
+}
+
+#[test]
+fn test_whitespace_prefix() {
+    assert_eq!(
+        parse_prefix_trimmed::<i32, false, false>(b"  -42\r\n"),
+        Ok((-42, 5))
+    );
+    // the length is the same as of `parse_prefix` plus the whitespace
+    assert_eq!(
+        parse_prefix_trimmed::<u32, false, true>(b"\t+7 8"),
+        parse_prefix::<u32, false, true>(b"+7 8").map(|(v, len)| (v, len + 1))
+    );
+    assert_eq!(
+        parse_prefix_trimmed::<u32, false, true>(b"\t+7 8"),
+        Ok((7, 3))
+    );
+    assert_eq!(
+        parse_prefix_trimmed::<u64, true, false>(b" 123abc"),
+        Ok((123, 4))
+    );
+}
+
+#[test]
+fn test_whitespace_wrappers() {
+    assert_eq!(parse_trimmed::<i32, false, false>(b"  -42\r\n"), Ok(-42));
+    assert_eq!(
+        parse_trimmed::<Wrapping<u8>, false, false>(b" 257\r\n"),
+        Ok(Wrapping(1))
+    );
+    assert_eq!(
+        parse_prefix_trimmed::<Wrapping<i8>, false, false>(b"\t-129 "),
+        Ok((Wrapping(127), 5))
+    );
+    assert_eq!(
+        parse_trimmed::<NonZeroU32, false, false>(b" 5 "),
+        Ok(NonZeroU32::new(5).unwrap())
+    );
+    assert!(matches!(
+        parse_trimmed::<NonZeroU32, false, false>(b" 0 "),
+        Err(AtoiSimdError::Zero(_))
+    ));
+}
+
+#[test]
+fn test_whitespace_trait() {
+    use atoi_simd::Parse;
+
+    // the trimmed parsers are separate methods, `atoi_simd_parse` keeps its two flags
+    assert_eq!(i32::atoi_simd_parse::<false, true>(b"+42"), Ok(42));
+    assert_eq!(
+        i32::atoi_simd_parse_trimmed::<false, true>(b" +42\n"),
+        Ok(42)
+    );
+    assert_eq!(
+        u64::atoi_simd_parse_prefix_trimmed::<true, false>(b"\t007 "),
+        Ok((7, 4))
+    );
+    assert!(u64::atoi_simd_parse::<false, false>(b" 7").is_err());
+}